@@ -0,0 +1,14 @@
+-- 문서 간 위키 링크([[문서 제목]]) 저장
+-- 내용 저장 시마다 본문을 파싱하여 링크 목록을 통째로 교체합니다.
+-- 링크 대상은 문서 ID가 아닌 slug로 저장하고 조회 시점에 해석합니다.
+-- (아직 존재하지 않는 문서로의 링크도 보관했다가, 해당 문서가 생기면 자동으로 연결됨)
+CREATE TABLE document_links (
+    source_id TEXT NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    target_title TEXT NOT NULL,              -- 본문에 적힌 대상 제목 (#헤딩, |별칭 제외)
+    target_slug TEXT NOT NULL,               -- slugify(target_title): 문서 slug와 비교
+    link_count INTEGER NOT NULL DEFAULT 1,   -- 같은 대상을 가리키는 링크 개수
+    PRIMARY KEY (source_id, target_slug)
+);
+
+CREATE INDEX idx_document_links_target_slug ON document_links(target_slug);
+CREATE INDEX idx_documents_user_slug ON documents(user_id, slug);
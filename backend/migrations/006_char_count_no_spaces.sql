@@ -0,0 +1,3 @@
+-- 공백 제외 글자 수 (마크다운 인식 카운터가 계산)
+ALTER TABLE documents ADD COLUMN char_count_no_spaces INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE document_versions ADD COLUMN char_count_no_spaces INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,3 @@
+-- 200자 원고지 매수
+ALTER TABLE documents ADD COLUMN manuscript_pages INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE document_versions ADD COLUMN manuscript_pages INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,20 @@
+-- ── 전문검색 인덱스 재구성: trigram 토크나이저 ──
+-- 기존 `porter unicode61`은 공백 단위로 토큰을 나누므로 "소설에서"가 하나의 토큰이 되어
+-- "소설"로 검색해도 찾을 수 없었습니다. trigram 토크나이저는 텍스트를 3글자 단위로 잘라
+-- 색인하므로 조사가 붙은 어절이나 복합명사 안의 부분 문자열도 검색할 수 있습니다.
+--
+-- 외부 콘텐츠(content='documents') 대신 내용을 FTS 테이블에 직접 저장합니다.
+-- - documents 테이블에는 본문이 없어 snippet()/highlight()를 쓸 수 없었고,
+-- - 'delete' 명령에 이전 내용을 정확히 넘기지 못하면 인덱스가 손상되었기 때문입니다.
+-- 이제 rowid로 바로 삭제할 수 있습니다.
+--
+-- 본문은 .md 파일에 있어 SQL만으로 다시 채울 수 없으므로, 서버 시작 시
+-- 인덱스에 없는 문서를 찾아 파일을 읽어 색인합니다 (db::index_missing_documents).
+DROP TABLE IF EXISTS documents_fts;
+
+CREATE VIRTUAL TABLE documents_fts USING fts5(
+    title,                  -- 검색 대상 컬럼: 제목
+    content,                -- 검색 대상 컬럼: 내용 (FTS 테이블에 직접 저장)
+    tokenize='trigram'      -- 3글자 단위 토큰 (대소문자 구분 없음, LIKE 검색도 가속)
+);
+-- rowid는 documents 테이블의 rowid와 같은 값을 사용합니다.
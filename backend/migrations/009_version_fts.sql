@@ -0,0 +1,14 @@
+-- ── 버전 히스토리 전문검색 인덱스 ──
+-- 지운 문단처럼 "언제 썼는지 모르는 옛 내용"을 찾을 수 있도록 버전 본문을 색인합니다.
+-- documents_fts와 같은 방식: trigram 토크나이저, 내용을 FTS 테이블에 직접 저장,
+-- rowid는 document_versions 테이블의 rowid와 같은 값을 사용합니다.
+--
+-- 색인은 db::create_version / db::prune_versions / db::delete_document가 함께 관리합니다.
+CREATE VIRTUAL TABLE document_versions_fts USING fts5(
+    content,                -- 검색 대상 컬럼: 버전 본문
+    tokenize='trigram'
+);
+
+-- 버전 본문은 DB에 있으므로 기존 버전은 SQL만으로 색인합니다.
+INSERT INTO document_versions_fts(rowid, content)
+SELECT rowid, content FROM document_versions;
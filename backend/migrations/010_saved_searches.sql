@@ -0,0 +1,14 @@
+-- 저장된 검색 (스마트 폴더)
+-- 검색어와 필터를 이름과 함께 저장해 두고, 열 때마다 다시 검색하여 결과를 보여줍니다.
+-- 결과 문서 목록은 저장하지 않으므로 문서가 바뀌면 내용도 자동으로 바뀝니다.
+CREATE TABLE saved_searches (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    query TEXT NOT NULL DEFAULT '',          -- 검색어 (GET /search의 q와 같은 문법)
+    filters TEXT NOT NULL DEFAULT '{}',      -- SearchFilters JSON (폴더, 태그, 상태, 날짜, 정렬 등)
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE UNIQUE INDEX idx_saved_searches_user_name ON saved_searches(user_id, name);
@@ -0,0 +1,10 @@
+-- ── 태그 사용 기록 ──
+-- 태그를 문서에 붙인 시각을 기록하여 "마지막으로 사용한 시각"을 계산합니다.
+-- ALTER TABLE ADD COLUMN은 strftime(...) 같은 식을 기본값으로 쓸 수 없으므로,
+-- 값은 db::add_tag_to_document가 INSERT할 때 직접 넣습니다.
+-- 이 마이그레이션 이전에 붙인 태그는 NULL이며, 이 경우 문서의 수정 시각으로 대신합니다.
+ALTER TABLE document_tags ADD COLUMN created_at TEXT;
+
+-- 복합 기본키 (document_id, tag_id)는 tag_id만으로 찾을 때 쓰이지 않으므로
+-- 태그별 문서 수/통계 집계를 위한 인덱스를 따로 만듭니다.
+CREATE INDEX idx_document_tags_tag_id ON document_tags(tag_id);
@@ -0,0 +1,9 @@
+-- ── 태그 계층 구조 ──
+-- parent_id로 태그 안에 태그를 넣어 "소설/판타지/세계관"처럼 트리를 만듭니다 (폴더와 같은 방식).
+-- 부모 태그로 필터링하면 모든 하위 태그가 붙은 문서까지 함께 찾습니다.
+--
+-- ON DELETE SET NULL: 부모 태그를 삭제하면 자식 태그는 지워지지 않고 최상위 태그가 됩니다.
+-- 태그 이름은 여전히 사용자별로 유일하므로(idx_tags_user_name) 최상위로 올라가도 충돌하지 않습니다.
+ALTER TABLE tags ADD COLUMN parent_id TEXT REFERENCES tags(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tags_parent_id ON tags(parent_id);
@@ -0,0 +1,8 @@
+-- ── 문서-태그 연결의 출처 ──
+-- 태그는 두 가지 방법으로 문서에 붙습니다.
+--   'manual':  사용자가 태그 UI/API로 직접 붙임 (POST /documents/:id/tags, 태그 합치기)
+--   'hashtag': 본문에 쓴 #해시태그에서 자동으로 붙임 (내용 저장 시마다 다시 계산)
+-- 본문에서 해시태그를 지우면 'hashtag' 연결만 함께 지우고, 직접 붙인 태그는 그대로 둡니다.
+-- 기존 연결은 모두 사용자가 직접 붙인 것이므로 'manual'입니다.
+ALTER TABLE document_tags ADD COLUMN source TEXT NOT NULL DEFAULT 'manual'
+    CHECK (source IN ('manual', 'hashtag'));
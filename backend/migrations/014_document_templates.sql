@@ -0,0 +1,4 @@
+-- ── 문서 템플릿 ──
+-- 템플릿으로 표시한 문서는 새 문서를 만들 때(POST /documents의 template_id) 본문의 틀로 쓰입니다.
+-- 템플릿도 일반 문서와 같은 .md 파일이므로 평소처럼 편집할 수 있습니다.
+ALTER TABLE documents ADD COLUMN is_template INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,22 @@
+-- ── 일지(데일리 노트) ──
+-- 날짜마다 문서 하나를 일지로 씁니다. 일지 문서도 일반 문서이므로 편집/검색/태그가 모두 같습니다.
+
+-- 사용자별 일지 설정: 일지를 만들 폴더, 본문 틀로 쓸 템플릿, 제목 형식
+-- 폴더나 템플릿을 삭제하면 설정에서만 빠집니다 (루트에 빈 일지를 만듦).
+CREATE TABLE journal_settings (
+    user_id TEXT PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    folder_id TEXT REFERENCES folders(id) ON DELETE SET NULL,
+    template_id TEXT REFERENCES documents(id) ON DELETE SET NULL,
+    title_format TEXT NOT NULL DEFAULT '{{date}}',   -- 템플릿 자리표시자 사용 가능
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+-- 날짜 → 일지 문서 연결
+-- 제목으로 찾지 않으므로 일지 문서의 제목을 바꾸거나 다른 폴더로 옮겨도 그날의 일지로 남습니다.
+-- 문서를 삭제하면 연결도 지워지고, 그 날짜를 다시 열면 새 일지를 만듭니다.
+CREATE TABLE journal_entries (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    date TEXT NOT NULL,                                        -- YYYY-MM-DD
+    document_id TEXT NOT NULL UNIQUE REFERENCES documents(id) ON DELETE CASCADE,
+    PRIMARY KEY (user_id, date)
+);
@@ -0,0 +1,17 @@
+-- ── 문서 메타데이터 (YAML front matter) ──
+-- .md 파일 맨 앞의 front matter(status, pov, due, 사용자 정의 필드 ...)를 키/값으로 저장합니다.
+-- 파일이 원본이며, 내용을 저장할 때마다 front matter를 다시 읽어 이 테이블을 맞춥니다.
+-- API로 메타데이터를 고치면 front matter를 다시 쓴 뒤 같은 경로로 저장합니다.
+--
+-- value:      값을 JSON으로 저장 (문자열, 숫자, 불리언, 목록, 객체)
+-- value_text: 필터 비교용 문자열 (문자열/숫자/불리언만, 목록/객체/null은 NULL)
+CREATE TABLE document_metadata (
+    document_id TEXT NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    value_text TEXT,
+    PRIMARY KEY (document_id, key)
+);
+
+-- `meta=status:초고` 필터: 키와 값으로 문서를 찾습니다.
+CREATE INDEX idx_document_metadata_key ON document_metadata(key, value_text);
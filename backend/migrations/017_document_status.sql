@@ -0,0 +1,32 @@
+-- ── 문서 상태 워크플로 ──
+-- 초고 → 퇴고 → 완성 → 발행처럼 문서가 어느 단계에 있는지 표시합니다.
+
+-- 문서의 현재 상태 (사용자 상태 목록의 key). NULL이면 상태 없음
+ALTER TABLE documents ADD COLUMN status TEXT;
+
+-- `status=draft` 필터와 상태별 통계용
+CREATE INDEX idx_documents_status ON documents(user_id, status);
+
+-- 사용자별 상태 목록 (순서 있음)
+-- 행이 하나도 없으면 기본 목록(draft, revising, final, published)을 씁니다.
+-- key는 문서에 저장되는 값이므로 바꾸지 않고, 화면에 보이는 이름(name)만 바꿉니다.
+CREATE TABLE document_statuses (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    color TEXT,
+    sort_order INTEGER NOT NULL,
+    PRIMARY KEY (user_id, key)
+);
+
+-- 상태 변경 이력: 문서가 언제 어떤 상태에서 어떤 상태로 바뀌었는지
+-- 상태 목록에서 빠진 key도 이력에는 그대로 남습니다.
+CREATE TABLE document_status_history (
+    id TEXT PRIMARY KEY,                       -- UUIDv7
+    document_id TEXT NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    from_status TEXT,                          -- NULL: 상태 없음에서
+    to_status TEXT,                            -- NULL: 상태를 지움
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE INDEX idx_document_status_history_document ON document_status_history(document_id, changed_at);
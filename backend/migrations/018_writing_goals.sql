@@ -0,0 +1,27 @@
+-- ── 글쓰기 목표와 마감 ──
+-- 하루/한 주에 쓸 단어 수 목표와, 폴더(프로젝트)별 목표 분량과 마감일을 저장합니다.
+-- 진행률은 저장하지 않고 writing_sessions와 documents에서 조회할 때마다 계산합니다.
+
+-- 사용자별 하루/주간 목표
+-- 목표를 바꾸면 그날부터 적용되는 행을 새로 추가하여, 지난 날의 달성 여부는 그때의 목표로 판단합니다.
+-- 같은 날 여러 번 바꾸면 그날의 행을 덮어씁니다.
+CREATE TABLE writing_goals (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    effective_from TEXT NOT NULL,        -- YYYY-MM-DD (UTC), 이날부터 적용
+    daily_words INTEGER,                 -- NULL: 하루 목표 없음
+    weekly_words INTEGER,                -- NULL: 주간 목표 없음 (주는 월요일 시작)
+    PRIMARY KEY (user_id, effective_from)
+);
+
+-- 프로젝트(폴더)별 목표: "이 폴더가 마감일까지 80,000단어"
+-- 하위 폴더의 문서까지 합산합니다. 폴더를 삭제하면 목표도 삭제됩니다.
+CREATE TABLE project_goals (
+    id TEXT PRIMARY KEY,                 -- UUIDv7
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    folder_id TEXT NOT NULL REFERENCES folders(id) ON DELETE CASCADE,
+    target_words INTEGER NOT NULL,
+    deadline TEXT,                       -- YYYY-MM-DD, NULL이면 마감 없음
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    UNIQUE (user_id, folder_id)
+);
@@ -0,0 +1,10 @@
+-- ── 휴지통 ──
+-- 문서를 삭제하면 행을 지우지 않고 삭제 시각만 기록합니다 (휴지통으로 이동).
+-- 휴지통의 문서는 목록/검색/그래프/통계에서 빠지며, 복원하면 그대로 돌아옵니다.
+-- 휴지통을 비울 때(영구 삭제) 행을 지우고, 커밋이 끝난 뒤에 디스크의 .md 파일을 지웁니다.
+
+-- 휴지통으로 옮긴 시각. NULL이면 휴지통에 없는 문서
+ALTER TABLE documents ADD COLUMN deleted_at TEXT;
+
+-- 휴지통 목록 조회용
+CREATE INDEX idx_documents_deleted_at ON documents(user_id, deleted_at);
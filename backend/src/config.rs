@@ -10,6 +10,7 @@
 //! - `UPLOADS_PATH`: 업로드 파일 저장 디렉토리
 //! - `HOST`: 서버 바인딩 주소
 //! - `PORT`: 서버 포트 번호
+//! - `ADMIN_USERNAMES`: 관리자 사용자 이름 목록 (쉼표로 구분)
 
 // std::env: Rust 표준 라이브러리의 환경변수 모듈
 use std::env;
@@ -43,6 +44,8 @@ pub struct Config {
     pub max_document_versions: u32,
     /// 버전 생성 최소 간격 (분, 기본값: 60)
     pub version_interval_minutes: u32,
+    /// 관리자 사용자 이름 목록 (기본값: 없음). 검색 인덱스 재구성/검사 API는 관리자만 쓸 수 있습니다.
+    pub admin_usernames: Vec<String>,
 }
 
 // impl: 구조체에 메서드를 추가하는 블록
@@ -91,6 +94,13 @@ impl Config {
                 .unwrap_or_else(|_| "60".to_string())
                 .parse()
                 .unwrap_or(60),
+            // "alice, bob" → ["alice", "bob"] (빈 항목은 버립니다)
+            admin_usernames: env::var("ADMIN_USERNAMES")
+                .unwrap_or_default()
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect(),
         })
     }
 }
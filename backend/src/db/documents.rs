@@ -12,34 +12,130 @@ use crate::models::*;
 // &SqlitePool로 받으면 소유권을 가져가지 않고 빌려서(borrow) 사용합니다.
 use sqlx::SqlitePool;
 
-/// 모든 문서를 조회합니다.
+/// 조건에 맞는 문서 목록과 전체 결과 수를 조회합니다.
 ///
-/// 고정(pinned)된 문서가 먼저 표시되고, 그 다음 수정일 기준 내림차순 정렬합니다.
+/// 정렬 기준을 지정하지 않으면 고정(pinned)된 문서가 먼저 표시되고, 그 다음 수정일 기준 내림차순 정렬합니다.
+/// 같은 값끼리는 ID로 순서를 고정하여, 페이지를 넘겨도 문서가 빠지거나 겹치지 않게 합니다.
 ///
 /// # 매개변수
 /// - `pool`: SQLite 연결 풀의 참조(&). 소유권을 가져가지 않고 빌려 씁니다.
+/// - `filters`: 필터와 정렬 조건 (폴더/태그는 하위 항목까지 펼친 ID 목록)
+/// - `page`: None이면 모든 문서를 반환합니다 (페이지를 나누지 않던 기존 동작).
 ///
 /// # 반환값
-/// - `Result<Vec<Document>, AppError>`: 성공 시 문서 목록, 실패 시 에러
+/// - `Result<(Vec<Document>, i64), AppError>`: 성공 시 (현재 페이지의 문서 목록, 조건에 맞는 전체 문서 수)
 ///   Vec<T>: 가변 길이 배열 (다른 언어의 ArrayList, List 등에 해당)
-pub async fn list_documents(pool: &SqlitePool, user_id: &str) -> Result<Vec<Document>, AppError> {
-    let docs = sqlx::query_as::<_, Document>(
+pub async fn list_documents(
+    pool: &SqlitePool,
+    filters: &DocumentListFilters,
+    user_id: &str,
+    page: Option<super::SearchPage>,
+) -> Result<(Vec<Document>, i64), AppError> {
+    let mut conditions = String::from(" WHERE d.user_id = ? AND d.deleted_at IS NULL");
+    let mut bindings: Vec<String> = vec![user_id.to_string()];
+
+    // IN 절의 자리표시자(?)를 값 개수만큼 만듭니다. 예: "?, ?, ?"
+    let placeholders = |n: usize| vec!["?"; n].join(", ");
+
+    if filters.root_only {
+        conditions.push_str(" AND d.folder_id IS NULL");
+    }
+    if let Some(ids) = &filters.folder_ids {
+        if ids.is_empty() {
+            conditions.push_str(" AND 0");
+        } else {
+            conditions.push_str(&format!(" AND d.folder_id IN ({})", placeholders(ids.len())));
+            bindings.extend(ids.iter().cloned());
+        }
+    }
+    if !filters.tag_groups.is_empty() {
+        // 태그 하나(와 그 하위 태그들)마다: d.id IN (그 태그들이 붙은 문서)
+        let groups: Vec<String> = filters
+            .tag_groups
+            .iter()
+            .map(|ids| {
+                if ids.is_empty() {
+                    return "0".to_string();
+                }
+                bindings.extend(ids.iter().cloned());
+                format!(
+                    "d.id IN (SELECT document_id FROM document_tags WHERE tag_id IN ({}))",
+                    placeholders(ids.len())
+                )
+            })
+            .collect();
+        let separator = match filters.tag_mode {
+            TagMatchMode::Any => " OR ",
+            TagMatchMode::All => " AND ",
+        };
+        conditions.push_str(&format!(" AND ({})", groups.join(separator)));
+    }
+    if let Some(archived) = filters.archived {
+        conditions.push_str(" AND d.is_archived = ?");
+        bindings.push(if archived { "1" } else { "0" }.to_string());
+    }
+    if let Some(pinned) = filters.pinned {
+        conditions.push_str(" AND d.is_pinned = ?");
+        bindings.push(if pinned { "1" } else { "0" }.to_string());
+    }
+    if let Some(template) = filters.template {
+        conditions.push_str(" AND d.is_template = ?");
+        bindings.push(if template { "1" } else { "0" }.to_string());
+    }
+    super::push_status_conditions(&mut conditions, &mut bindings, &filters.statuses);
+    super::push_metadata_conditions(&mut conditions, &mut bindings, &filters.metadata);
+
+    // ── 전체 결과 수 ──
+    let count_sql = format!("SELECT COUNT(*) FROM documents d{}", conditions);
+    let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+    for binding in &bindings {
+        count_query = count_query.bind(binding);
+    }
+    let total = count_query.fetch_one(pool).await?;
+
+    // ── 정렬 ──
+    let order_by = match filters.sort {
+        None => "d.is_pinned DESC, d.updated_at DESC, d.id DESC".to_string(),
+        Some(sort) => {
+            let (column, default_order) = match sort {
+                DocumentSort::Title => ("d.title COLLATE NOCASE", SortOrder::Asc),
+                DocumentSort::Created => ("d.created_at", SortOrder::Desc),
+                DocumentSort::Updated => ("d.updated_at", SortOrder::Desc),
+                DocumentSort::Words => ("d.word_count", SortOrder::Desc),
+            };
+            let direction = match filters.order.unwrap_or(default_order) {
+                SortOrder::Asc => "ASC",
+                SortOrder::Desc => "DESC",
+            };
+            format!("{} {}, d.id {}", column, direction, direction)
+        }
+    };
+    // LIMIT/OFFSET은 정수로 검증된 값이므로 SQL에 직접 넣습니다.
+    let limit = match page {
+        Some(page) => format!(" LIMIT {} OFFSET {}", page.limit, page.offset),
+        None => String::new(),
+    };
+
+    let sql = format!(
         r#"
-        SELECT id, folder_id, title, slug, file_path, word_count, char_count,
-               excerpt, is_pinned, is_archived, created_at, updated_at
-        FROM documents
-        WHERE user_id = ?
-        ORDER BY is_pinned DESC, updated_at DESC
+        SELECT d.id, d.folder_id, d.title, d.slug, d.file_path, d.word_count, d.char_count, d.char_count_no_spaces, d.manuscript_pages,
+               d.excerpt, d.is_pinned, d.is_archived, d.is_template, d.status, d.created_at, d.updated_at
+        FROM documents d{}
+        ORDER BY {}{}
         "#,
-    )
-    .bind(user_id)
-    .fetch_all(pool)
-    .await?;
+        conditions, order_by, limit
+    );
 
-    Ok(docs)
+    let mut query = sqlx::query_as::<_, Document>(&sql);
+    for binding in &bindings {
+        query = query.bind(binding);
+    }
+    let docs = query.fetch_all(pool).await?;
+
+    Ok((docs, total))
 }
 
-/// ID로 단일 문서를 조회합니다.
+/// ID로 단일 문서를 조회합니다. 휴지통에 있는 문서는 없는 것으로 봅니다.
 ///
 /// # 매개변수
 /// - `pool`: DB 연결 풀
@@ -52,10 +148,10 @@ pub async fn list_documents(pool: &SqlitePool, user_id: &str) -> Result<Vec<Docu
 pub async fn get_document(pool: &SqlitePool, id: &str, user_id: &str) -> Result<Option<Document>, AppError> {
     let doc = sqlx::query_as::<_, Document>(
         r#"
-        SELECT id, folder_id, title, slug, file_path, word_count, char_count,
-               excerpt, is_pinned, is_archived, created_at, updated_at
+        SELECT id, folder_id, title, slug, file_path, word_count, char_count, char_count_no_spaces, manuscript_pages,
+               excerpt, is_pinned, is_archived, is_template, status, created_at, updated_at
         FROM documents
-        WHERE id = ? AND user_id = ?
+        WHERE id = ? AND user_id = ? AND deleted_at IS NULL
         "#,
     )
     .bind(id)
@@ -67,6 +163,8 @@ pub async fn get_document(pool: &SqlitePool, id: &str, user_id: &str) -> Result<
 }
 
 /// 같은 폴더에서 "Untitled" 접두사를 가진 문서 제목들을 조회합니다.
+///
+/// 휴지통의 문서도 포함하여, 복원했을 때 같은 제목이 둘 생기지 않게 합니다.
 pub async fn list_untitled_titles(
     pool: &SqlitePool,
     folder_id: Option<&str>,
@@ -134,6 +232,7 @@ pub async fn create_document(
 ///
 /// 요청에 포함된 필드만 업데이트하고, 나머지는 그대로 유지합니다.
 /// 동적으로 SQL UPDATE 쿼리를 구성합니다.
+/// 상태(`status`)가 바뀌면 같은 트랜잭션에서 상태 변경 이력도 남깁니다.
 ///
 /// # 매개변수
 /// - `pool`: DB 연결 풀
@@ -157,9 +256,12 @@ pub async fn update_document(
     let mut query = String::from("UPDATE documents SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')");
     let mut bindings = Vec::new();
 
-    if let Some(title) = &req.title {
-        query.push_str(", title = ?");
+    // 제목이 바뀌면 slug도 함께 갱신합니다 (위키 링크가 slug로 문서를 찾기 때문)
+    let new_slug = req.title.as_deref().map(slug::slugify);
+    if let (Some(title), Some(slug)) = (&req.title, &new_slug) {
+        query.push_str(", title = ?, slug = ?");
         bindings.push(title.as_str());
+        bindings.push(slug.as_str());
     }
 
     if let Some(folder_id_opt) = &req.folder_id {
@@ -184,6 +286,11 @@ pub async fn update_document(
         bindings.push(if is_archived { "1" } else { "0" });
     }
 
+    if let Some(is_template) = req.is_template {
+        query.push_str(", is_template = ?");
+        bindings.push(if is_template { "1" } else { "0" });
+    }
+
     query.push_str(" WHERE id = ? AND user_id = ?");
     bindings.push(id);
     bindings.push(user_id);
@@ -193,12 +300,20 @@ pub async fn update_document(
         query_builder = query_builder.bind(binding);
     }
 
-    query_builder.execute(pool).await?;
+    let mut tx = pool.begin().await?;
+    query_builder.execute(&mut *tx).await?;
+    if let Some(status) = &req.status {
+        super::change_document_status(&mut tx, id, status.as_deref(), user_id).await?;
+    }
+    tx.commit().await?;
 
     get_document(pool, id, user_id).await
 }
 
-/// 문서를 삭제합니다.
+/// 문서를 영구 삭제합니다 (휴지통을 거치지 않음).
+///
+/// `DELETE /documents/:id`는 휴지통으로 옮기므로(`trash_document`), 이 함수는 휴지통 비우기와
+/// 방금 만든 문서를 되돌릴 때(일지 동시 생성)만 씁니다. 디스크의 .md 파일은 커밋 후 호출자가 지웁니다.
 ///
 /// # 매개변수
 /// - `pool`: DB 연결 풀
@@ -207,36 +322,200 @@ pub async fn update_document(
 /// # 반환값
 /// - `Ok(true)`: 삭제 성공 (1행 이상 영향)
 /// - `Ok(false)`: 해당 ID의 문서가 없음 (0행 영향)
+///
+/// 전문검색 인덱스(`documents_fts`)의 행도 같은 트랜잭션에서 함께 삭제합니다.
+/// 색인 행은 documents의 rowid로 연결되므로 문서를 먼저 지우면 찾을 수 없고,
+/// 남겨 두면 같은 rowid를 다시 받은 새 문서가 옛 내용으로 검색될 수 있습니다.
 pub async fn delete_document(pool: &SqlitePool, id: &str, user_id: &str) -> Result<bool, AppError> {
+    let mut tx = pool.begin().await?;
+
+    super::unindex_document(&mut tx, id, user_id).await?;
+
     let result = sqlx::query("DELETE FROM documents WHERE id = ? AND user_id = ?")
         .bind(id)
         .bind(user_id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?;
 
+    tx.commit().await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// 문서를 휴지통으로 옮깁니다. 이미 휴지통에 있거나 없는 문서면 false입니다.
+///
+/// 행과 .md 파일, 검색 색인은 그대로 두고 `deleted_at`만 기록하므로 `restore_document`로 되돌릴 수 있습니다.
+/// 휴지통의 문서는 목록/검색/그래프/통계 쿼리에서 `deleted_at IS NULL` 조건으로 빠집니다.
+pub async fn trash_document(pool: &SqlitePool, id: &str, user_id: &str) -> Result<bool, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE documents SET deleted_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+        WHERE id = ? AND user_id = ? AND deleted_at IS NULL
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
     Ok(result.rows_affected() > 0)
 }
 
-/// 특정 태그가 붙은 문서만 조회합니다.
+/// 휴지통의 문서를 되돌립니다. 휴지통에 없는 문서면 false입니다.
 ///
-/// `document_tags` 중간 테이블을 JOIN하여 해당 태그를 가진 문서 목록을 반환합니다.
-pub async fn list_documents_by_tag(pool: &SqlitePool, tag_id: &str, user_id: &str) -> Result<Vec<Document>, AppError> {
-    let docs = sqlx::query_as::<_, Document>(
+/// 휴지통에 있는 동안 원래 폴더가 삭제되었다면(`ON DELETE SET NULL`) 루트로 돌아옵니다.
+pub async fn restore_document(pool: &SqlitePool, id: &str, user_id: &str) -> Result<bool, AppError> {
+    let result = sqlx::query(
+        "UPDATE documents SET deleted_at = NULL WHERE id = ? AND user_id = ? AND deleted_at IS NOT NULL",
+    )
+    .bind(id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// 휴지통의 문서를 최근에 버린 순으로 조회합니다.
+pub async fn list_trashed_documents(pool: &SqlitePool, user_id: &str) -> Result<Vec<TrashedDocument>, AppError> {
+    let documents = sqlx::query_as::<_, TrashedDocument>(
         r#"
-        SELECT d.id, d.folder_id, d.title, d.slug, d.file_path, d.word_count, d.char_count,
-               d.excerpt, d.is_pinned, d.is_archived, d.created_at, d.updated_at
-        FROM documents d
-        JOIN document_tags dt ON dt.document_id = d.id
-        WHERE dt.tag_id = ? AND d.user_id = ?
-        ORDER BY d.is_pinned DESC, d.updated_at DESC
+        SELECT id, folder_id, title, word_count, updated_at, deleted_at
+        FROM documents
+        WHERE user_id = ? AND deleted_at IS NOT NULL
+        ORDER BY deleted_at DESC, id DESC
         "#,
     )
-    .bind(tag_id)
     .bind(user_id)
     .fetch_all(pool)
     .await?;
 
-    Ok(docs)
+    Ok(documents)
+}
+
+/// 휴지통의 문서를 영구 삭제하고, 지운 문서들의 .md 파일 경로를 반환합니다.
+///
+/// `id`가 Some이면 그 문서 하나만, None이면 휴지통 전체를 비웁니다.
+/// 색인 행 삭제와 문서 행 삭제는 한 트랜잭션입니다 (`delete_document`와 같은 순서).
+/// 파일은 커밋이 끝난 뒤 호출자가 지웁니다 — 커밋 전에 지우면 롤백되었을 때 행만 남고 내용이 사라집니다.
+pub async fn purge_trashed_documents(
+    pool: &SqlitePool,
+    id: Option<&str>,
+    user_id: &str,
+) -> Result<Vec<String>, AppError> {
+    let mut tx = pool.begin().await?;
+
+    let trashed: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT id, file_path FROM documents
+        WHERE user_id = ? AND deleted_at IS NOT NULL AND (? IS NULL OR id = ?)
+        "#,
+    )
+    .bind(user_id)
+    .bind(id)
+    .bind(id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    for (id, _) in &trashed {
+        super::unindex_document(&mut tx, id, user_id).await?;
+        sqlx::query("DELETE FROM documents WHERE id = ? AND user_id = ?")
+            .bind(id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(trashed.into_iter().map(|(_, file_path)| file_path).collect())
+}
+
+/// 여러 문서에 일괄 작업을 한 트랜잭션으로 적용합니다 (`POST /documents/bulk`).
+///
+/// `ids`는 호출자가 이미 현재 사용자 소유임을 확인한 문서들이고,
+/// 폴더/태그 소유권 검증도 호출자가 끝낸 상태여야 합니다.
+/// 하나라도 실패하면 전체가 롤백되어, 일부 문서만 바뀐 채로 남지 않습니다.
+///
+/// `Export`는 DB를 바꾸지 않으므로 아무 일도 하지 않습니다.
+/// `Trash`는 휴지통으로 옮길 뿐(`trash_document`와 같음), 행과 .md 파일은 휴지통을 비울 때 지웁니다.
+pub async fn bulk_update_documents(
+    pool: &SqlitePool,
+    ids: &[String],
+    action: &BulkDocumentAction,
+    user_id: &str,
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+    let touch = "updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')";
+
+    for id in ids {
+        match action {
+            BulkDocumentAction::Move { folder_id } => {
+                sqlx::query(&format!("UPDATE documents SET folder_id = ?, {} WHERE id = ? AND user_id = ?", touch))
+                    .bind(folder_id)
+                    .bind(id)
+                    .bind(user_id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+            BulkDocumentAction::Pin
+            | BulkDocumentAction::Unpin
+            | BulkDocumentAction::Archive
+            | BulkDocumentAction::Unarchive => {
+                let (column, value) = match action {
+                    BulkDocumentAction::Pin => ("is_pinned", 1),
+                    BulkDocumentAction::Unpin => ("is_pinned", 0),
+                    BulkDocumentAction::Archive => ("is_archived", 1),
+                    _ => ("is_archived", 0),
+                };
+                sqlx::query(&format!("UPDATE documents SET {} = ?, {} WHERE id = ? AND user_id = ?", column, touch))
+                    .bind(value)
+                    .bind(id)
+                    .bind(user_id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+            BulkDocumentAction::SetStatus { status } => {
+                super::change_document_status(&mut tx, id, status.as_deref(), user_id).await?;
+            }
+            BulkDocumentAction::AddTags { tag_ids } => {
+                // 단건 태그 추가(add_tag_to_document)와 같이, 이미 해시태그로 붙어 있으면 직접 붙인 것으로 바꿉니다.
+                for tag_id in tag_ids {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO document_tags (document_id, tag_id, created_at, source)
+                        VALUES (?, ?, strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), 'manual')
+                        ON CONFLICT (document_id, tag_id) DO UPDATE SET source = 'manual'
+                        "#,
+                    )
+                    .bind(id)
+                    .bind(tag_id)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+            }
+            BulkDocumentAction::RemoveTags { tag_ids } => {
+                for tag_id in tag_ids {
+                    sqlx::query("DELETE FROM document_tags WHERE document_id = ? AND tag_id = ?")
+                        .bind(id)
+                        .bind(tag_id)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+            }
+            BulkDocumentAction::Trash => {
+                sqlx::query(
+                    "UPDATE documents SET deleted_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now') WHERE id = ? AND user_id = ? AND deleted_at IS NULL",
+                )
+                .bind(id)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            BulkDocumentAction::Export => {}
+        }
+    }
+
+    tx.commit().await?;
+    Ok(())
 }
 
 // ── 폴더(Folder) 관련 쿼리 ──
@@ -258,6 +537,77 @@ pub async fn list_folders(pool: &SqlitePool, user_id: &str) -> Result<Vec<Folder
     Ok(folders)
 }
 
+/// 빠른 열기 인덱스에 올릴 사용자의 모든 문서를 조회합니다.
+///
+/// 문서, 폴더, 태그를 각각 한 번씩만 조회한 뒤 메모리에서 합칩니다.
+/// 폴더 경로는 `parent_id`를 따라 올라가며 이름을 이어 붙여 만듭니다 (예: "소설/단편").
+pub async fn list_quick_open_entries(
+    pool: &SqlitePool,
+    user_id: &str,
+) -> Result<Vec<QuickOpenEntry>, AppError> {
+    use std::collections::HashMap;
+
+    let documents: Vec<(String, String, Option<String>, i64, String)> = sqlx::query_as(
+        "SELECT id, title, folder_id, is_archived, updated_at FROM documents WHERE user_id = ? AND deleted_at IS NULL",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    let folders: HashMap<String, (String, Option<String>)> = list_folders(pool, user_id)
+        .await?
+        .into_iter()
+        .map(|f| (f.id, (f.name, f.parent_id)))
+        .collect();
+
+    let tag_rows: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT dt.document_id, t.name
+        FROM document_tags dt
+        JOIN tags t ON t.id = dt.tag_id
+        WHERE t.user_id = ?
+        ORDER BY t.name
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+    for (document_id, name) in tag_rows {
+        tags.entry(document_id).or_default().push(name);
+    }
+
+    // 폴더 경로: 부모를 따라 올라가며 이름을 모은 뒤 뒤집습니다.
+    // 잘못된 데이터로 순환이 생겨도 멈추도록 폴더 수만큼만 올라갑니다.
+    let folder_path = |folder_id: &str| {
+        let mut names = Vec::new();
+        let mut current = Some(folder_id.to_string());
+        while let Some(id) = current {
+            let Some((name, parent_id)) = folders.get(&id) else { break };
+            names.push(name.as_str());
+            if names.len() > folders.len() {
+                break;
+            }
+            current = parent_id.clone();
+        }
+        names.reverse();
+        names.join("/")
+    };
+
+    Ok(documents
+        .into_iter()
+        .map(|(id, title, folder_id, is_archived, updated_at)| QuickOpenEntry {
+            folder_path: folder_id.as_deref().map(folder_path).unwrap_or_default(),
+            tags: tags.remove(&id).unwrap_or_default(),
+            id,
+            title,
+            folder_id,
+            is_archived: is_archived != 0,
+            updated_at,
+        })
+        .collect())
+}
+
 /// 새 폴더를 생성합니다.
 ///
 /// # 매개변수
@@ -309,6 +659,73 @@ pub async fn get_folder(pool: &SqlitePool, id: &str, user_id: &str) -> Result<Op
     Ok(folder)
 }
 
+/// 폴더 자신과 모든 하위 폴더의 ID를 조회합니다.
+///
+/// `WITH RECURSIVE`(재귀 CTE)로 `parent_id`를 따라 트리를 내려갑니다.
+/// 폴더가 없거나 다른 사용자의 폴더면 빈 목록을 반환합니다.
+pub async fn list_descendant_folder_ids(
+    pool: &SqlitePool,
+    folder_id: &str,
+    user_id: &str,
+) -> Result<Vec<String>, AppError> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        r#"
+        WITH RECURSIVE subtree(id) AS (
+            SELECT id FROM folders WHERE id = ? AND user_id = ?
+            UNION
+            SELECT f.id FROM folders f JOIN subtree s ON f.parent_id = s.id
+            WHERE f.user_id = ?
+        )
+        SELECT id FROM subtree
+        "#,
+    )
+    .bind(folder_id)
+    .bind(user_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
+
+/// 주어진 폴더들에 속한 문서들의 통계 합계를 계산합니다.
+///
+/// `folder_ids`가 비어 있으면 모든 값이 0인 결과를 반환합니다.
+/// 원고지 매수는 문서별 매수의 합입니다 (문서마다 새 원고지에서 시작).
+pub async fn get_folder_stats(
+    pool: &SqlitePool,
+    folder_ids: &[String],
+    user_id: &str,
+) -> Result<FolderStats, AppError> {
+    // IN 절의 자리표시자(?)를 폴더 수만큼 만듭니다. 예: "?, ?, ?"
+    // folder_ids가 비어 있으면 `IN (NULL)`이 되어 아무 문서도 일치하지 않습니다.
+    let placeholders = if folder_ids.is_empty() {
+        "NULL".to_string()
+    } else {
+        vec!["?"; folder_ids.len()].join(", ")
+    };
+    let sql = format!(
+        r#"
+        SELECT COUNT(*) AS document_count,
+               COALESCE(SUM(word_count), 0) AS word_count,
+               COALESCE(SUM(char_count), 0) AS char_count,
+               COALESCE(SUM(char_count_no_spaces), 0) AS char_count_no_spaces,
+               COALESCE(SUM(manuscript_pages), 0) AS manuscript_pages
+        FROM documents
+        WHERE user_id = ? AND deleted_at IS NULL AND folder_id IN ({})
+        "#,
+        placeholders
+    );
+
+    let mut query = sqlx::query_as::<_, FolderStats>(&sql).bind(user_id);
+    for id in folder_ids {
+        query = query.bind(id);
+    }
+    let stats = query.fetch_one(pool).await?;
+
+    Ok(stats)
+}
+
 /// 폴더를 수정합니다 (부분 업데이트).
 ///
 /// 동적 쿼리 구성이 복잡하여, 각 필드를 개별 UPDATE 문으로 처리합니다.
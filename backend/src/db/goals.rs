@@ -0,0 +1,220 @@
+//! # 글쓰기 목표 데이터베이스 쿼리 모듈
+//!
+//! `writing_goals`(하루/주간 목표), `project_goals`(폴더별 목표 분량과 마감일) 쿼리와,
+//! 진행률 계산에 쓰는 날짜별 작성량(`writing_sessions` 합계) 쿼리입니다.
+
+use crate::error::AppError;
+use crate::models::*;
+use sqlx::SqlitePool;
+
+/// 사용자의 현재 하루/주간 목표를 조회합니다. 정한 적이 없으면 모두 None입니다.
+pub async fn get_writing_goals(pool: &SqlitePool, user_id: &str) -> Result<WritingGoals, AppError> {
+    let goals = sqlx::query_as::<_, WritingGoals>(
+        r#"
+        SELECT daily_words, weekly_words, effective_from
+        FROM writing_goals
+        WHERE user_id = ?
+        ORDER BY effective_from DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(goals.unwrap_or_default())
+}
+
+/// 사용자의 목표 변경 기록을 적용 시작일 순으로 조회합니다 (지난 날의 달성 여부 판단용).
+pub async fn list_writing_goal_history(pool: &SqlitePool, user_id: &str) -> Result<Vec<WritingGoals>, AppError> {
+    let history = sqlx::query_as::<_, WritingGoals>(
+        r#"
+        SELECT daily_words, weekly_words, effective_from
+        FROM writing_goals
+        WHERE user_id = ?
+        ORDER BY effective_from
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(history)
+}
+
+/// 하루/주간 목표를 `effective_from`부터 적용되도록 저장합니다. 같은 날의 기록이 있으면 덮어씁니다.
+pub async fn save_writing_goals(
+    pool: &SqlitePool,
+    effective_from: &str,
+    daily_words: Option<i64>,
+    weekly_words: Option<i64>,
+    user_id: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO writing_goals (user_id, effective_from, daily_words, weekly_words)
+        VALUES (?, ?, ?, ?)
+        ON CONFLICT (user_id, effective_from) DO UPDATE SET
+            daily_words = excluded.daily_words,
+            weekly_words = excluded.weekly_words
+        "#,
+    )
+    .bind(user_id)
+    .bind(effective_from)
+    .bind(daily_words)
+    .bind(weekly_words)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// 같은 폴더의 프로젝트 목표가 이미 있으면(UNIQUE 위반) 409 Conflict로 바꿉니다.
+fn map_folder_conflict(e: sqlx::Error) -> AppError {
+    match &e {
+        sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+            AppError::Conflict("Folder already has a project goal".to_string())
+        }
+        _ => AppError::Database(e),
+    }
+}
+
+/// 사용자의 프로젝트 목표를 마감일이 가까운 순으로 조회합니다 (마감 없는 목표는 마지막).
+pub async fn list_project_goals(pool: &SqlitePool, user_id: &str) -> Result<Vec<ProjectGoal>, AppError> {
+    let goals = sqlx::query_as::<_, ProjectGoal>(
+        r#"
+        SELECT id, folder_id, target_words, deadline, created_at, updated_at
+        FROM project_goals
+        WHERE user_id = ?
+        ORDER BY deadline IS NULL, deadline, created_at
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(goals)
+}
+
+/// ID로 프로젝트 목표 하나를 조회합니다.
+pub async fn get_project_goal(pool: &SqlitePool, id: &str, user_id: &str) -> Result<Option<ProjectGoal>, AppError> {
+    let goal = sqlx::query_as::<_, ProjectGoal>(
+        r#"
+        SELECT id, folder_id, target_words, deadline, created_at, updated_at
+        FROM project_goals
+        WHERE id = ? AND user_id = ?
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(goal)
+}
+
+/// 프로젝트 목표를 만듭니다. 폴더 소유권과 값 검증은 호출자가 끝낸 상태여야 합니다.
+///
+/// # 에러
+/// 같은 폴더의 목표가 이미 있으면 `AppError::Conflict`
+pub async fn create_project_goal(
+    pool: &SqlitePool,
+    folder_id: &str,
+    target_words: i64,
+    deadline: Option<&str>,
+    user_id: &str,
+) -> Result<ProjectGoal, AppError> {
+    let id = uuid::Uuid::now_v7().to_string();
+
+    sqlx::query(
+        "INSERT INTO project_goals (id, user_id, folder_id, target_words, deadline) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(user_id)
+    .bind(folder_id)
+    .bind(target_words)
+    .bind(deadline)
+    .execute(pool)
+    .await
+    .map_err(map_folder_conflict)?;
+
+    get_project_goal(pool, &id, user_id)
+        .await?
+        .ok_or(AppError::Internal("Failed to retrieve created project goal".to_string()))
+}
+
+/// 프로젝트 목표의 목표 분량과 마감일을 저장합니다.
+pub async fn update_project_goal(
+    pool: &SqlitePool,
+    id: &str,
+    target_words: i64,
+    deadline: Option<&str>,
+    user_id: &str,
+) -> Result<Option<ProjectGoal>, AppError> {
+    sqlx::query(
+        r#"
+        UPDATE project_goals
+        SET target_words = ?, deadline = ?, updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+        WHERE id = ? AND user_id = ?
+        "#,
+    )
+    .bind(target_words)
+    .bind(deadline)
+    .bind(id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    get_project_goal(pool, id, user_id).await
+}
+
+/// 프로젝트 목표를 삭제합니다. 삭제했으면 true입니다.
+pub async fn delete_project_goal(pool: &SqlitePool, id: &str, user_id: &str) -> Result<bool, AppError> {
+    let result = sqlx::query("DELETE FROM project_goals WHERE id = ? AND user_id = ?")
+        .bind(id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// `from` 날짜(`YYYY-MM-DD`, 포함)부터 날짜별로 쓴 단어 수를 날짜순으로 조회합니다.
+///
+/// 종료된 글쓰기 세션의 단어 수 증가분(`word_count_end - word_count_start`)을 세션 시작일(UTC)별로 합칩니다.
+/// 글을 지운 세션(증가분이 음수)은 0으로 셉니다. 진행 중인 세션과 휴지통의 문서는 세지 않습니다.
+/// `folder_ids`가 Some이면 그 폴더들에 속한 문서의 세션만 셉니다.
+/// 작성량이 없는 날은 결과에 없습니다.
+pub async fn list_daily_words(
+    pool: &SqlitePool,
+    from: &str,
+    folder_ids: Option<&[String]>,
+    user_id: &str,
+) -> Result<Vec<DailyWords>, AppError> {
+    let folder_condition = match folder_ids {
+        // 비어 있으면 `IN (NULL)`이 되어 아무 문서도 일치하지 않습니다.
+        Some([]) => " AND d.folder_id IN (NULL)".to_string(),
+        Some(ids) => format!(" AND d.folder_id IN ({})", vec!["?"; ids.len()].join(", ")),
+        None => String::new(),
+    };
+    let sql = format!(
+        r#"
+        SELECT substr(ws.started_at, 1, 10) AS date,
+               SUM(MAX(ws.word_count_end - ws.word_count_start, 0)) AS words
+        FROM writing_sessions ws
+        JOIN documents d ON d.id = ws.document_id
+        WHERE d.user_id = ? AND d.deleted_at IS NULL AND ws.word_count_end IS NOT NULL AND ws.started_at >= ?{}
+        GROUP BY date
+        ORDER BY date
+        "#,
+        folder_condition
+    );
+
+    let mut query = sqlx::query_as::<_, DailyWords>(&sql).bind(user_id).bind(from);
+    for id in folder_ids.unwrap_or_default() {
+        query = query.bind(id);
+    }
+    let days = query.fetch_all(pool).await?;
+
+    Ok(days)
+}
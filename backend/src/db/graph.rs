@@ -0,0 +1,76 @@
+//! # 문서 그래프 데이터베이스 쿼리 모듈
+//!
+//! 지식 그래프(`GET /graph`)를 그리는 데 필요한 관계 데이터를 조회합니다.
+//! 모든 데이터는 SQLite의 메타데이터 테이블에서만 읽으며, .md 파일은 열지 않습니다.
+//! (위키 링크는 내용 저장 시 `document_links`에 미리 파싱되어 있음)
+
+use crate::error::AppError;
+use crate::models::{GraphDocumentRow, GraphLinkRow, GraphTagMembership};
+use sqlx::SqlitePool;
+
+/// 그래프에 표시할 사용자의 문서 목록을 조회합니다.
+///
+/// `include_archived`가 false면 아카이브된 문서는 제외합니다. 휴지통의 문서는 항상 제외합니다.
+pub async fn list_graph_documents(
+    pool: &SqlitePool,
+    user_id: &str,
+    include_archived: bool,
+) -> Result<Vec<GraphDocumentRow>, AppError> {
+    let docs = sqlx::query_as::<_, GraphDocumentRow>(
+        r#"
+        SELECT id, title, folder_id, word_count
+        FROM documents
+        WHERE user_id = ? AND deleted_at IS NULL AND (? OR is_archived = 0)
+        ORDER BY title
+        "#,
+    )
+    .bind(user_id)
+    .bind(include_archived)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(docs)
+}
+
+/// 사용자의 모든 문서-태그 연결을 조회합니다.
+pub async fn list_graph_tag_memberships(
+    pool: &SqlitePool,
+    user_id: &str,
+) -> Result<Vec<GraphTagMembership>, AppError> {
+    let rows = sqlx::query_as::<_, GraphTagMembership>(
+        r#"
+        SELECT dt.document_id, dt.tag_id
+        FROM document_tags dt
+        JOIN documents d ON d.id = dt.document_id
+        WHERE d.user_id = ? AND d.deleted_at IS NULL
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// 대상 문서까지 해석된 위키 링크를 모두 조회합니다.
+///
+/// 대상이 없는 미해결 링크와 자기 자신으로의 링크는 제외합니다.
+pub async fn list_graph_links(
+    pool: &SqlitePool,
+    user_id: &str,
+) -> Result<Vec<GraphLinkRow>, AppError> {
+    let rows = sqlx::query_as::<_, GraphLinkRow>(
+        r#"
+        SELECT l.source_id, t.id AS target_id, l.link_count
+        FROM document_links l
+        JOIN documents s ON s.id = l.source_id
+        JOIN documents t ON t.user_id = s.user_id AND t.slug = l.target_slug AND t.deleted_at IS NULL
+        WHERE s.user_id = ? AND s.deleted_at IS NULL AND t.id != s.id
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
@@ -0,0 +1,124 @@
+//! # 일지(데일리 노트) 데이터베이스 쿼리 모듈
+//!
+//! `journal_settings`(사용자별 설정)와 `journal_entries`(날짜 → 일지 문서) 테이블의 쿼리입니다.
+//! 일지 문서 자체는 일반 문서이므로 `documents` 쿼리로 만들고 읽습니다.
+
+use crate::error::AppError;
+use crate::models::*;
+use sqlx::SqlitePool;
+
+/// 사용자의 일지 설정을 조회합니다. 저장한 적이 없으면 기본값을 반환합니다.
+pub async fn get_journal_settings(pool: &SqlitePool, user_id: &str) -> Result<JournalSettings, AppError> {
+    let settings = sqlx::query_as::<_, JournalSettings>(
+        "SELECT folder_id, template_id, title_format FROM journal_settings WHERE user_id = ?",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(settings.unwrap_or_default())
+}
+
+/// 사용자의 일지 설정을 저장합니다 (없으면 추가, 있으면 교체).
+pub async fn save_journal_settings(
+    pool: &SqlitePool,
+    settings: &JournalSettings,
+    user_id: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO journal_settings (user_id, folder_id, template_id, title_format)
+        VALUES (?, ?, ?, ?)
+        ON CONFLICT (user_id) DO UPDATE SET
+            folder_id = excluded.folder_id,
+            template_id = excluded.template_id,
+            title_format = excluded.title_format,
+            updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+        "#,
+    )
+    .bind(user_id)
+    .bind(&settings.folder_id)
+    .bind(&settings.template_id)
+    .bind(&settings.title_format)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// 날짜의 일지 문서 ID를 조회합니다. `date`는 `YYYY-MM-DD`입니다.
+pub async fn get_journal_document_id(
+    pool: &SqlitePool,
+    date: &str,
+    user_id: &str,
+) -> Result<Option<String>, AppError> {
+    let id = sqlx::query_scalar::<_, String>(
+        "SELECT document_id FROM journal_entries WHERE user_id = ? AND date = ?",
+    )
+    .bind(user_id)
+    .bind(date)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(id)
+}
+
+/// 날짜에 일지 문서를 연결합니다.
+///
+/// 그날의 일지가 휴지통에 있으면 새 문서로 연결을 바꿉니다 (휴지통의 옛 일지는 복원해도 일반 문서로 남음).
+///
+/// # 반환값
+/// - `true`: 연결함
+/// - `false`: 같은 날짜의 일지가 이미 있음 (동시에 두 요청이 같은 날짜의 일지를 만든 경우)
+pub async fn add_journal_entry(
+    pool: &SqlitePool,
+    date: &str,
+    document_id: &str,
+    user_id: &str,
+) -> Result<bool, AppError> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO journal_entries (user_id, date, document_id) VALUES (?, ?, ?)
+        ON CONFLICT (user_id, date) DO UPDATE SET document_id = excluded.document_id
+        WHERE journal_entries.document_id IN (SELECT id FROM documents WHERE deleted_at IS NOT NULL)
+        "#,
+    )
+    .bind(user_id)
+    .bind(date)
+    .bind(document_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// 기간 안에서 일지가 있는 날짜들을 날짜순으로 조회합니다.
+///
+/// `from`/`to`는 `YYYY-MM-DD`이며 둘 다 포함합니다. None이면 그쪽으로 제한이 없습니다.
+pub async fn list_journal_days(
+    pool: &SqlitePool,
+    from: Option<&str>,
+    to: Option<&str>,
+    user_id: &str,
+) -> Result<Vec<JournalDay>, AppError> {
+    let days = sqlx::query_as::<_, JournalDay>(
+        r#"
+        SELECT je.date, d.id AS document_id, d.title, d.word_count, d.char_count, d.updated_at
+        FROM journal_entries je
+        JOIN documents d ON d.id = je.document_id AND d.deleted_at IS NULL
+        WHERE je.user_id = ?
+          AND (? IS NULL OR je.date >= ?)
+          AND (? IS NULL OR je.date <= ?)
+        ORDER BY je.date
+        "#,
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(from)
+    .bind(to)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(days)
+}
@@ -0,0 +1,127 @@
+//! # 위키 링크 데이터베이스 쿼리 모듈
+//!
+//! `document_links` 테이블을 관리하고, 백링크/나가는 링크를 조회합니다.
+//!
+//! ## 링크 해석 방식
+//! 링크는 대상 문서 ID가 아닌 `target_slug`로 저장됩니다.
+//! 조회 시 같은 사용자의 `documents.slug`와 비교하여 대상 문서를 찾으므로,
+//! 링크를 먼저 쓰고 나중에 문서를 만들어도 자동으로 연결됩니다.
+
+use crate::error::AppError;
+use crate::models::{Backlink, OutgoingLink};
+use crate::services::LinkTarget;
+use sqlx::SqlitePool;
+
+/// 문서의 나가는 링크 목록을 통째로 교체합니다.
+///
+/// 내용 저장 시마다 호출되며, 기존 링크를 모두 지우고 새로 파싱한 링크를 넣습니다.
+/// 삭제와 삽입이 한 트랜잭션으로 묶여 있어 중간 상태가 보이지 않습니다.
+pub async fn replace_document_links(
+    pool: &SqlitePool,
+    source_id: &str,
+    targets: &[LinkTarget],
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM document_links WHERE source_id = ?")
+        .bind(source_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for target in targets {
+        sqlx::query(
+            "INSERT INTO document_links (source_id, target_title, target_slug, link_count) VALUES (?, ?, ?, ?)",
+        )
+        .bind(source_id)
+        .bind(&target.title)
+        .bind(&target.slug)
+        .bind(target.count)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 특정 문서를 가리키는 다른 문서 목록(백링크)을 조회합니다.
+///
+/// 대상 문서의 현재 slug와 `target_slug`가 같은 링크를 찾습니다.
+/// 자기 자신으로의 링크와 휴지통의 문서에서 오는 링크는 제외합니다.
+pub async fn list_backlinks(
+    pool: &SqlitePool,
+    document_id: &str,
+    user_id: &str,
+) -> Result<Vec<Backlink>, AppError> {
+    let backlinks = sqlx::query_as::<_, Backlink>(
+        r#"
+        SELECT s.id, s.title, s.slug, s.folder_id, s.updated_at, l.link_count
+        FROM documents t
+        JOIN document_links l ON l.target_slug = t.slug
+        JOIN documents s ON s.id = l.source_id AND s.user_id = t.user_id
+        WHERE t.id = ? AND t.user_id = ? AND s.id != t.id AND s.deleted_at IS NULL
+        ORDER BY s.updated_at DESC
+        "#,
+    )
+    .bind(document_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(backlinks)
+}
+
+/// 특정 문서에서 나가는 링크 목록을 조회합니다.
+///
+/// 같은 slug의 문서가 여러 개면 가장 먼저 만들어진 문서(UUIDv7 최솟값)로 해석합니다.
+/// 대상 문서가 없거나 휴지통에 있으면 `document_id`가 NULL인 미해결 링크로 반환됩니다.
+pub async fn list_outgoing_links(
+    pool: &SqlitePool,
+    document_id: &str,
+    user_id: &str,
+) -> Result<Vec<OutgoingLink>, AppError> {
+    let links = sqlx::query_as::<_, OutgoingLink>(
+        r#"
+        SELECT l.target_title, l.target_slug, l.link_count,
+               t.id AS document_id, t.title AS document_title
+        FROM document_links l
+        JOIN documents s ON s.id = l.source_id
+        LEFT JOIN documents t ON t.id = (
+            SELECT MIN(d.id) FROM documents d
+            WHERE d.user_id = s.user_id AND d.slug = l.target_slug AND d.deleted_at IS NULL
+        )
+        WHERE l.source_id = ? AND s.user_id = ?
+        ORDER BY l.target_title
+        "#,
+    )
+    .bind(document_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(links)
+}
+
+/// 주어진 slug를 가리키는 링크를 가진 문서 ID 목록을 조회합니다.
+///
+/// 문서 이름 변경 시 링크를 다시 쓸 대상 문서를 찾는 데 사용합니다. 휴지통의 문서는 건드리지 않습니다.
+pub async fn list_link_source_ids(
+    pool: &SqlitePool,
+    target_slug: &str,
+    user_id: &str,
+) -> Result<Vec<String>, AppError> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        r#"
+        SELECT DISTINCT l.source_id
+        FROM document_links l
+        JOIN documents s ON s.id = l.source_id
+        WHERE l.target_slug = ? AND s.user_id = ? AND s.deleted_at IS NULL
+        "#,
+    )
+    .bind(target_slug)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
@@ -0,0 +1,132 @@
+//! # 문서 메타데이터(YAML front matter) 데이터베이스 쿼리 모듈
+//!
+//! `document_metadata` 테이블의 쿼리입니다.
+//! 파일의 front matter가 원본이므로, 쓰기는 front matter를 읽은 결과로 통째로 교체하는 것뿐입니다.
+
+use crate::error::AppError;
+use crate::models::*;
+use crate::services;
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+
+/// 문서의 메타데이터를 front matter에서 읽은 값으로 교체합니다.
+///
+/// 한 트랜잭션에서 기존 키를 모두 지우고 새 키/값을 넣습니다.
+/// 빈 맵을 넘기면 메타데이터가 모두 지워집니다 (front matter를 지운 경우).
+pub async fn replace_document_metadata(
+    pool: &SqlitePool,
+    document_id: &str,
+    metadata: &Map<String, Value>,
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM document_metadata WHERE document_id = ?")
+        .bind(document_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for (key, value) in metadata {
+        sqlx::query("INSERT INTO document_metadata (document_id, key, value, value_text) VALUES (?, ?, ?, ?)")
+            .bind(document_id)
+            .bind(key)
+            .bind(value.to_string())
+            .bind(services::metadata_value_text(value))
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 문서의 메타데이터를 키 순서대로 조회합니다.
+pub async fn get_document_metadata(pool: &SqlitePool, document_id: &str) -> Result<Map<String, Value>, AppError> {
+    let rows = sqlx::query_as::<_, (String, String)>(
+        "SELECT key, value FROM document_metadata WHERE document_id = ? ORDER BY key",
+    )
+    .bind(document_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut metadata = Map::new();
+    for (key, value) in rows {
+        let value = serde_json::from_str(&value)
+            .map_err(|e| AppError::Internal(format!("Invalid metadata value for {}: {}", key, e)))?;
+        metadata.insert(key, value);
+    }
+    Ok(metadata)
+}
+
+/// 모든 문서의 .md 파일을 다시 읽어 메타데이터를 맞춥니다 (CLI `sync-metadata`).
+///
+/// 메타데이터 기능이 생기기 전에 만든 문서나, 서버 밖에서 고친 파일의 front matter를 반영할 때 씁니다.
+pub async fn sync_all_document_metadata(
+    pool: &SqlitePool,
+    documents_path: &str,
+) -> Result<MetadataSyncReport, AppError> {
+    let mut report = MetadataSyncReport::default();
+    let documents = sqlx::query_as::<_, (String, String)>("SELECT id, file_path FROM documents ORDER BY id")
+        .fetch_all(pool)
+        .await?;
+
+    for (id, file_path) in documents {
+        let content = match services::read_markdown(documents_path, &file_path).await {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::warn!("Failed to read {} for metadata sync: {}", file_path, e);
+                report.failed.push(id);
+                continue;
+            }
+        };
+        match services::parse_front_matter(&content) {
+            Ok(metadata) => match replace_document_metadata(pool, &id, &metadata.unwrap_or_default()).await {
+                Ok(()) => report.synced += 1,
+                Err(e) => {
+                    tracing::warn!("Failed to sync metadata for document {}: {}", id, e);
+                    report.failed.push(id);
+                }
+            },
+            Err(e) => {
+                tracing::warn!("Invalid front matter in document {}: {}", id, e);
+                report.invalid.push(id);
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// 메타데이터 조건을 `d`(documents) 별칭에 대한 WHERE 조건으로 덧붙입니다.
+///
+/// 문서 목록(`db::list_documents`)과 검색(`db::search_documents`)이 함께 사용합니다.
+/// 값 조건은 문자열/숫자/불리언 값과 비교하고, 목록 값이면 항목 중 하나라도 같으면 일치합니다.
+pub(crate) fn push_metadata_conditions(
+    conditions: &mut String,
+    bindings: &mut Vec<String>,
+    metadata: &[MetadataCondition],
+) {
+    for condition in metadata {
+        bindings.push(condition.key.clone());
+        match &condition.value {
+            None => conditions.push_str(
+                " AND d.id IN (SELECT document_id FROM document_metadata WHERE key = ?)",
+            ),
+            Some(value) => {
+                conditions.push_str(
+                    r#" AND d.id IN (
+                        SELECT m.document_id FROM document_metadata m
+                        WHERE m.key = ? AND (
+                            m.value_text = ?
+                            OR (json_type(m.value) = 'array' AND EXISTS (
+                                SELECT 1 FROM json_each(m.value) j
+                                WHERE j.type IN ('text', 'integer', 'real') AND CAST(j.value AS TEXT) = ?
+                            ))
+                        )
+                    )"#,
+                );
+                bindings.push(value.clone());
+                bindings.push(value.clone());
+            }
+        }
+    }
+}
@@ -5,14 +5,28 @@
 //!
 //! 각 하위 모듈:
 //! - `documents`: 문서와 폴더의 CRUD(생성/조회/수정/삭제) 쿼리
+//! - `goals`: 글쓰기 목표(하루/주간 목표, 프로젝트 목표)와 날짜별 작성량 쿼리
+//! - `graph`: 지식 그래프용 관계 데이터(문서/태그/폴더/링크) 조회 쿼리
+//! - `journal`: 일지(데일리 노트) 설정과 날짜별 일지 문서 쿼리
+//! - `links`: 문서 간 위키 링크(백링크/나가는 링크) 쿼리
+//! - `metadata`: 문서 메타데이터(YAML front matter) 저장과 메타데이터 필터 조건
+//! - `saved_searches`: 저장된 검색(스마트 폴더) CRUD 쿼리
 //! - `search`: 전문검색(FTS5) 인덱스 관리 쿼리
 //! - `sessions`: 글쓰기 세션 관련 쿼리
+//! - `statuses`: 문서 상태 워크플로(상태 목록, 현재 상태, 변경 이력) 쿼리
 //! - `tags`: 태그 CRUD 및 문서-태그 관계 쿼리
 //! - `users`: 사용자 인증 관련 쿼리
 
 pub mod documents;
+pub mod goals;
+pub mod graph;
+pub mod journal;
+pub mod links;
+pub mod metadata;
+pub mod saved_searches;
 pub mod search;
 pub mod sessions;
+pub mod statuses;
 pub mod tags;
 pub mod users;
 pub mod versions;
@@ -20,7 +34,38 @@ pub mod versions;
 // 하위 모듈의 모든 공개 함수를 재공개(re-export)하여
 // `crate::db::list_documents`처럼 바로 접근할 수 있게 합니다.
 pub use documents::*;
+pub use goals::*;
+pub use graph::*;
+pub use journal::*;
+pub use links::*;
+pub use metadata::*;
+pub use saved_searches::*;
 pub use search::*;
 pub use sessions::*;
+pub use statuses::*;
 pub use tags::*;
 pub use versions::*;
+
+/// 테스트용 DB: 메모리 SQLite에 마이그레이션을 모두 적용하고 사용자 한 명을 만들어, 풀과 그 사용자 ID를 반환합니다.
+///
+/// 메모리 DB는 연결마다 따로 생기므로 연결을 하나만 두고, 그 연결이 닫히지 않게 합니다.
+#[cfg(test)]
+pub(crate) async fn test_pool() -> (sqlx::SqlitePool, String) {
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .expect("open in-memory database");
+    sqlx::migrate!("./migrations").run(&pool).await.expect("run migrations");
+
+    let user_id = uuid::Uuid::now_v7().to_string();
+    sqlx::query("INSERT INTO users (id, username, password_hash) VALUES (?, 'tester', 'x')")
+        .bind(&user_id)
+        .execute(&pool)
+        .await
+        .expect("create test user");
+
+    (pool, user_id)
+}
@@ -0,0 +1,230 @@
+//! # 저장된 검색(스마트 폴더) 데이터베이스 쿼리 모듈
+//!
+//! `saved_searches` 테이블의 CRUD 쿼리입니다.
+//! 필터는 `SearchFilters`를 JSON 문자열로 직렬화하여 `filters` 컬럼에 저장합니다.
+//! (필터 항목이 늘어나도 테이블 구조를 바꿀 필요가 없음)
+
+use crate::error::AppError;
+use crate::models::*;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+/// DB에서 읽은 저장된 검색 한 행 (`filters`가 아직 JSON 문자열인 상태)
+#[derive(sqlx::FromRow)]
+struct SavedSearchRow {
+    id: String,
+    name: String,
+    query: String,
+    filters: String,
+    created_at: String,
+    updated_at: String,
+}
+
+impl TryFrom<SavedSearchRow> for SavedSearch {
+    type Error = AppError;
+
+    fn try_from(row: SavedSearchRow) -> Result<Self, AppError> {
+        let filters = serde_json::from_str(&row.filters).map_err(|e| {
+            AppError::Internal(format!("Invalid filters in saved search {}: {}", row.id, e))
+        })?;
+        Ok(SavedSearch {
+            id: row.id,
+            name: row.name,
+            query: row.query,
+            filters,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// 필터를 DB에 저장할 JSON 문자열로 바꿉니다.
+fn filters_json(filters: &SearchFilters) -> Result<String, AppError> {
+    serde_json::to_string(filters).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// 같은 이름의 저장된 검색이 이미 있으면(UNIQUE 위반) 409 Conflict로 바꿉니다.
+fn map_name_conflict(e: sqlx::Error) -> AppError {
+    match &e {
+        sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+            AppError::Conflict("Saved search name already exists".to_string())
+        }
+        _ => AppError::Database(e),
+    }
+}
+
+/// 사용자의 저장된 검색을 이름순으로 조회합니다.
+pub async fn list_saved_searches(
+    pool: &SqlitePool,
+    user_id: &str,
+) -> Result<Vec<SavedSearch>, AppError> {
+    let rows = sqlx::query_as::<_, SavedSearchRow>(
+        r#"
+        SELECT id, name, query, filters, created_at, updated_at
+        FROM saved_searches WHERE user_id = ? ORDER BY name
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    rows.into_iter().map(SavedSearch::try_from).collect()
+}
+
+/// ID로 저장된 검색 하나를 조회합니다.
+pub async fn get_saved_search(
+    pool: &SqlitePool,
+    id: &str,
+    user_id: &str,
+) -> Result<Option<SavedSearch>, AppError> {
+    let row = sqlx::query_as::<_, SavedSearchRow>(
+        r#"
+        SELECT id, name, query, filters, created_at, updated_at
+        FROM saved_searches WHERE id = ? AND user_id = ?
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(SavedSearch::try_from).transpose()
+}
+
+/// 새 저장된 검색을 만들고 반환합니다.
+///
+/// # 에러
+/// 같은 이름이 이미 있으면 `AppError::Conflict`
+pub async fn create_saved_search(
+    pool: &SqlitePool,
+    name: &str,
+    query: &str,
+    filters: &SearchFilters,
+    user_id: &str,
+) -> Result<SavedSearch, AppError> {
+    let id = uuid::Uuid::now_v7().to_string();
+
+    sqlx::query(
+        "INSERT INTO saved_searches (id, user_id, name, query, filters) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(user_id)
+    .bind(name)
+    .bind(query)
+    .bind(filters_json(filters)?)
+    .execute(pool)
+    .await
+    .map_err(map_name_conflict)?;
+
+    get_saved_search(pool, &id, user_id)
+        .await?
+        .ok_or(AppError::Internal("Failed to retrieve created saved search".to_string()))
+}
+
+/// 저장된 검색의 이름/검색어/필터를 한 번에 덮어씁니다.
+///
+/// 부분 업데이트는 라우트에서 기존 값과 합친 뒤 호출합니다 (합친 결과를 검증해야 하므로).
+///
+/// # 반환값
+/// 해당 ID가 없으면 `Ok(None)`
+pub async fn update_saved_search(
+    pool: &SqlitePool,
+    id: &str,
+    name: &str,
+    query: &str,
+    filters: &SearchFilters,
+    user_id: &str,
+) -> Result<Option<SavedSearch>, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE saved_searches
+        SET name = ?, query = ?, filters = ?,
+            updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+        WHERE id = ? AND user_id = ?
+        "#,
+    )
+    .bind(name)
+    .bind(query)
+    .bind(filters_json(filters)?)
+    .bind(id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map_err(map_name_conflict)?;
+
+    if result.rows_affected() == 0 {
+        return Ok(None);
+    }
+    get_saved_search(pool, id, user_id).await
+}
+
+/// 저장된 검색을 삭제합니다. 검색 결과였던 문서는 영향을 받지 않습니다.
+pub async fn delete_saved_search(
+    pool: &SqlitePool,
+    id: &str,
+    user_id: &str,
+) -> Result<bool, AppError> {
+    let result = sqlx::query("DELETE FROM saved_searches WHERE id = ? AND user_id = ?")
+        .bind(id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// 저장된 검색 필터의 `tag_ids`에서 `source_ids`를 `target_id`로 바꿉니다.
+///
+/// 태그를 합치는 트랜잭션 안에서 호출하여, 합쳐져 사라지는 태그를 가리키던 스마트 폴더가
+/// 빈 결과를 내지 않게 합니다. 바꾼 뒤 중복된 ID는 하나만 남깁니다.
+///
+/// # 반환값
+/// 필터가 바뀐 저장된 검색 수
+pub async fn replace_saved_search_tags(
+    tx: &mut Transaction<'_, Sqlite>,
+    source_ids: &[String],
+    target_id: &str,
+    user_id: &str,
+) -> Result<usize, AppError> {
+    let rows = sqlx::query_as::<_, SavedSearchRow>(
+        r#"
+        SELECT id, name, query, filters, created_at, updated_at
+        FROM saved_searches WHERE user_id = ?
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let mut updated = 0;
+    for row in rows {
+        let mut saved = SavedSearch::try_from(row)?;
+        if !saved.filters.tag_ids.iter().any(|id| source_ids.contains(id)) {
+            continue;
+        }
+
+        let mut tag_ids: Vec<String> = Vec::with_capacity(saved.filters.tag_ids.len());
+        for id in saved.filters.tag_ids.drain(..) {
+            let id = if source_ids.contains(&id) { target_id.to_string() } else { id };
+            if !tag_ids.contains(&id) {
+                tag_ids.push(id);
+            }
+        }
+        saved.filters.tag_ids = tag_ids;
+
+        sqlx::query(
+            r#"
+            UPDATE saved_searches
+            SET filters = ?, updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            WHERE id = ? AND user_id = ?
+            "#,
+        )
+        .bind(filters_json(&saved.filters)?)
+        .bind(&saved.id)
+        .bind(user_id)
+        .execute(&mut **tx)
+        .await?;
+        updated += 1;
+    }
+
+    Ok(updated)
+}
@@ -7,59 +7,240 @@
 //! 일반 `LIKE '%키워드%'`는 모든 행을 순차 탐색하지만,
 //! FTS5는 역색인(inverted index)을 사용해 훨씬 빠르게 검색합니다.
 //!
-//! ## 외부 콘텐츠 테이블(External Content Table) 구조
-//! 이 프로젝트의 FTS5 테이블은 `content='documents'` 설정으로 생성되어 있습니다.
-//! 이는 FTS5가 검색 인덱스만 자체 관리하고, 원본 데이터는 `documents` 테이블에서
-//! 읽어온다는 뜻입니다. 따라서:
-//! - **검색(MATCH)**: FTS5 자체 인덱스를 사용 → 빠름
-//! - **컬럼 값 읽기**: `documents` 테이블에서 가져옴
-//! - **인덱스 관리**: INSERT/DELETE를 수동으로 해야 함 (자동 동기화 없음)
+//! ## 테이블 구조
+//! `documents_fts`는 trigram 토크나이저를 쓰는 일반 FTS5 테이블입니다 (migration 008).
+//! - **rowid**: `documents` 테이블의 rowid와 같은 값 → JOIN으로 메타데이터 조회
+//! - **title, content**: 색인 시점의 제목과 본문을 FTS 테이블에 직접 저장
+//! - **인덱스 관리**: 저장할 때마다 rowid로 지우고 다시 넣습니다 (자동 동기화 없음)
 //!
-//! ## 주의사항
-//! `documents` 테이블에는 `content` 컬럼이 없으므로(파일에 저장),
-//! `highlight()`나 `snippet()` 같은 FTS5 함수는 사용할 수 없습니다.
-//! 대신 `documents` 테이블의 `excerpt` 필드를 미리보기로 사용합니다.
+//! ## 색인을 갱신하는 경로
+//! 제목이나 본문이 바뀌는 모든 경로는 이 모듈의 함수로만 색인을 고칩니다.
+//! - 문서 생성: 빈 본문으로 색인 (`index_document_or_retry`) → 새 문서도 제목으로 검색됨
+//! - 본문 저장 (링크 다시 쓰기, 섹션 수정 포함): `index_document_or_retry`
+//! - 제목 변경: 파일 내용과 새 제목으로 `index_document_or_retry`
+//! - 문서 삭제: 같은 트랜잭션에서 `unindex_document`
+//! - 서버 시작: 빠진 문서를 `index_missing_documents`로 채움
+//!
+//! 색인이 어긋났을 때는 `check_search_index`로 찾고 `rebuild_search_index`로 .md 파일에서 다시 만듭니다.
+//!
+//! trigram은 3글자 미만 검색어를 MATCH로 찾을 수 없으므로,
+//! 짧은 검색어는 FTS 테이블의 컬럼에 LIKE로 검색합니다 (`services::search_query` 참고).
 
 use crate::error::AppError;
-use crate::models::Document;
-use sqlx::SqlitePool;
+use crate::models::{
+    Document, IndexCheckReport, IndexRebuildReport, SearchFilters, SearchRow, SearchSort,
+    TagMatchMode,
+};
+use crate::services::{self, FtsQuery, QueryField};
+use sqlx::{Sqlite, SqlitePool, Transaction};
 
-/// 전문검색을 수행하여 매칭되는 문서 목록을 반환합니다.
+/// 검색 결과 페이지 범위
+#[derive(Debug, Clone, Copy)]
+pub struct SearchPage {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+/// 전문검색을 수행하여 매칭되는 문서 목록과 전체 결과 수를 반환합니다.
+///
+/// 각 행에는 스니펫을 만들 수 있도록 색인된 제목/본문(`fts_title`, `fts_content`)이 함께 담깁니다.
 ///
-/// FTS5의 MATCH 연산자로 검색하고, rank(관련도 점수)로 정렬합니다.
-/// rank 값이 작을수록(음수) 더 관련도가 높은 문서입니다.
+/// ## 매개변수
+/// - `query`: 검색어 조건. 비어 있으면 필터만으로 검색합니다.
+/// - `filters`: 필터와 정렬 조건 (날짜는 `services::normalize_date_bound`로 정규화된 값)
+/// - `folder_ids`: 폴더 필터를 하위 폴더까지 펼친 ID 목록 (`filters.folder_id` 대신 사용)
+/// - `tag_groups`: `filters.tag_ids`의 태그마다 하위 태그까지 펼친 ID 목록 (`filters.tag_ids` 대신 사용).
+///   `any`면 한 그룹이라도, `all`이면 모든 그룹에서 태그가 하나 이상 붙은 문서가 일치합니다.
+///
+/// ## 정렬
+/// - `relevance`: rank(BM25 관련도 점수)순. rank 값이 작을수록(음수) 더 관련도가 높습니다.
+///   짧은 검색어(LIKE)만 있으면 관련도를 계산할 수 없으므로 최근 수정순으로 정렬합니다.
+/// - `updated`: 최근 수정순, `title`: 제목순
+///
+/// 같은 값끼리는 문서 ID로 정렬하여 페이지를 넘겨도 순서가 흔들리지 않게 합니다.
 ///
 /// ## SQL 쿼리 설명
 /// ```sql
 /// FROM documents_fts                    -- FTS5 가상 테이블에서 검색
 /// JOIN documents d ON d.rowid = ...     -- documents 테이블과 JOIN하여 메타데이터 조회
-/// WHERE documents_fts MATCH ?           -- FTS5 전문검색 수행
+/// WHERE documents_fts MATCH ?           -- 3글자 이상 검색어 (trigram)
+///   AND d.rowid NOT IN (... MATCH ?)    -- 3글자 이상 제외 검색어
+///   AND (documents_fts.title LIKE ? OR documents_fts.content LIKE ?)  -- 짧은 검색어/접두사마다
+///   AND d.folder_id IN (...) AND ...    -- 필터
 /// ORDER BY documents_fts.rank           -- 관련도순 정렬 (BM25 알고리즘)
 /// ```
-///
-/// ## 검색 문법 예시
-/// - `"hello world"` → "hello"와 "world"가 모두 포함된 문서 (AND)
-/// - `hello OR world` → 둘 중 하나라도 포함된 문서
-/// - `hello*` → "hello"로 시작하는 단어가 포함된 문서 (접두사 검색)
-pub async fn search_documents(pool: &SqlitePool, query: &str, user_id: &str) -> Result<Vec<Document>, AppError> {
-    let documents = sqlx::query_as::<_, Document>(
+pub async fn search_documents(
+    pool: &SqlitePool,
+    query: &FtsQuery,
+    filters: &SearchFilters,
+    folder_ids: Option<&[String]>,
+    tag_groups: &[Vec<String>],
+    user_id: &str,
+    page: SearchPage,
+) -> Result<(Vec<SearchRow>, i64), AppError> {
+    let mut conditions = String::from(" WHERE d.user_id = ? AND d.deleted_at IS NULL");
+    let mut bindings: Vec<String> = vec![user_id.to_string()];
+
+    // ── 검색어 ──
+    if let Some(expr) = &query.match_expr {
+        conditions.push_str(" AND documents_fts MATCH ?");
+        bindings.push(expr.clone());
+    }
+    if let Some(expr) = &query.exclude_expr {
+        conditions.push_str(
+            " AND d.rowid NOT IN (SELECT rowid FROM documents_fts WHERE documents_fts MATCH ?)",
+        );
+        bindings.push(expr.clone());
+    }
+    for like in &query.like_conditions {
+        let columns: &[&str] = match like.field {
+            QueryField::Any => &["documents_fts.title", "documents_fts.content"],
+            QueryField::Title => &["documents_fts.title"],
+        };
+        // (컬럼1 LIKE 패턴1 OR 컬럼1 LIKE 패턴2 OR 컬럼2 LIKE 패턴1 ...)
+        let mut alternatives = Vec::new();
+        for column in columns {
+            for pattern in &like.patterns {
+                alternatives.push(format!("{} LIKE ? ESCAPE '\\'", column));
+                bindings.push(pattern.clone());
+            }
+        }
+        conditions.push_str(if like.negated { " AND NOT (" } else { " AND (" });
+        conditions.push_str(&alternatives.join(" OR "));
+        conditions.push(')');
+    }
+
+    // ── 필터 ──
+    // IN 절의 자리표시자(?)를 값 개수만큼 만듭니다. 예: "?, ?, ?"
+    let placeholders = |n: usize| vec!["?"; n].join(", ");
+
+    if let Some(ids) = folder_ids {
+        if ids.is_empty() {
+            conditions.push_str(" AND 0");
+        } else {
+            conditions.push_str(&format!(" AND d.folder_id IN ({})", placeholders(ids.len())));
+            bindings.extend(ids.iter().cloned());
+        }
+    }
+    if !tag_groups.is_empty() {
+        // 태그 하나(와 그 하위 태그들)마다: d.id IN (그 태그들이 붙은 문서)
+        let groups: Vec<String> = tag_groups
+            .iter()
+            .map(|ids| {
+                if ids.is_empty() {
+                    return "0".to_string();
+                }
+                bindings.extend(ids.iter().cloned());
+                format!(
+                    "d.id IN (SELECT document_id FROM document_tags WHERE tag_id IN ({}))",
+                    placeholders(ids.len())
+                )
+            })
+            .collect();
+        let separator = match filters.tag_mode {
+            TagMatchMode::Any => " OR ",
+            TagMatchMode::All => " AND ",
+        };
+        conditions.push_str(&format!(" AND ({})", groups.join(separator)));
+    }
+    if let Some(archived) = filters.archived {
+        conditions.push_str(" AND d.is_archived = ?");
+        bindings.push(if archived { "1" } else { "0" }.to_string());
+    }
+    if let Some(pinned) = filters.pinned {
+        conditions.push_str(" AND d.is_pinned = ?");
+        bindings.push(if pinned { "1" } else { "0" }.to_string());
+    }
+    let date_bounds = [
+        ("d.created_at >= ?", &filters.created_after),
+        ("d.created_at < ?", &filters.created_before),
+        ("d.updated_at >= ?", &filters.updated_after),
+        ("d.updated_at < ?", &filters.updated_before),
+    ];
+    for (condition, value) in date_bounds {
+        if let Some(value) = value {
+            conditions.push_str(" AND ");
+            conditions.push_str(condition);
+            bindings.push(value.clone());
+        }
+    }
+    if let Some(min_words) = filters.min_words {
+        conditions.push_str(" AND d.word_count >= ?");
+        bindings.push(min_words.to_string());
+    }
+    super::push_status_conditions(&mut conditions, &mut bindings, &filters.statuses);
+    super::push_metadata_conditions(&mut conditions, &mut bindings, &filters.metadata);
+
+    let from = " FROM documents_fts JOIN documents d ON d.rowid = documents_fts.rowid";
+
+    // ── 전체 결과 수 ──
+    let count_sql = format!("SELECT COUNT(*){}{}", from, conditions);
+    let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+    for binding in &bindings {
+        count_query = count_query.bind(binding);
+    }
+    let total = count_query.fetch_one(pool).await?;
+
+    // ── 현재 페이지 ──
+    let order_by = match filters.sort {
+        SearchSort::Relevance if query.match_expr.is_some() => "documents_fts.rank, d.id",
+        SearchSort::Relevance | SearchSort::Updated => "d.updated_at DESC, d.id DESC",
+        SearchSort::Title => "d.title COLLATE NOCASE, d.id",
+    };
+    // LIMIT/OFFSET은 정수로 검증된 값이므로 SQL에 직접 넣습니다.
+    let sql = format!(
         r#"
         SELECT d.id, d.folder_id, d.title, d.slug, d.file_path,
-               d.word_count, d.char_count, d.excerpt,
-               d.is_pinned, d.is_archived, d.created_at, d.updated_at
-        FROM documents_fts
-        JOIN documents d ON d.rowid = documents_fts.rowid
-        WHERE documents_fts MATCH ? AND d.user_id = ?
-        ORDER BY documents_fts.rank
-        LIMIT 50
+               d.word_count, d.char_count, d.char_count_no_spaces, d.manuscript_pages, d.excerpt,
+               d.is_pinned, d.is_archived, d.is_template, d.status, d.created_at, d.updated_at,
+               documents_fts.title AS fts_title, documents_fts.content AS fts_content
+        {}{}
+        ORDER BY {}
+        LIMIT {} OFFSET {}
         "#,
-    )
-    .bind(query)
-    .bind(user_id)
-    .fetch_all(pool)
-    .await?;
+        from, conditions, order_by, page.limit, page.offset
+    );
+    let mut q = sqlx::query_as::<_, SearchRow>(&sql);
+    for binding in &bindings {
+        q = q.bind(binding);
+    }
+    let rows = q.fetch_all(pool).await?;
+
+    Ok((rows, total))
+}
+
+/// 색인된 본문에 주어진 문자열 중 하나라도 들어 있는 문서를 조회합니다.
+///
+/// 태그 합치기에서 `#태그`를 다시 쓸 문서를 고를 때처럼, 모든 .md 파일을 열지 않고
+/// 후보만 추리는 데 씁니다. 색인 기준이므로 호출자는 실제 파일 내용으로 다시 확인해야 합니다.
+/// (LIKE는 ASCII 대소문자를 구분하지 않으므로 후보가 조금 더 많을 수 있습니다)
+pub async fn list_documents_containing(
+    pool: &SqlitePool,
+    needles: &[String],
+    user_id: &str,
+) -> Result<Vec<Document>, AppError> {
+    if needles.is_empty() {
+        return Ok(Vec::new());
+    }
+    let conditions = vec!["documents_fts.content LIKE ? ESCAPE '\\'"; needles.len()].join(" OR ");
+    let sql = format!(
+        r#"
+        SELECT d.id, d.folder_id, d.title, d.slug, d.file_path, d.word_count, d.char_count, d.char_count_no_spaces, d.manuscript_pages,
+               d.excerpt, d.is_pinned, d.is_archived, d.is_template, d.status, d.created_at, d.updated_at
+        FROM documents_fts JOIN documents d ON d.rowid = documents_fts.rowid
+        WHERE d.user_id = ? AND d.deleted_at IS NULL AND ({})
+        ORDER BY d.id
+        "#,
+        conditions
+    );
+
+    let mut query = sqlx::query_as::<_, Document>(&sql).bind(user_id);
+    for needle in needles {
+        query = query.bind(services::like_pattern(needle));
+    }
+    let docs = query.fetch_all(pool).await?;
 
-    Ok(documents)
+    Ok(docs)
 }
 
 /// 문서의 제목과 본문을 FTS5 인덱스에 등록/갱신합니다.
@@ -68,31 +249,17 @@ pub async fn search_documents(pool: &SqlitePool, query: &str, user_id: &str) ->
 ///
 /// ## 동작 방식
 /// 1. 문서의 `rowid`(SQLite 내부 정수 ID)를 조회합니다
-/// 2. 이전 내용(old_title, old_content)이 있으면 FTS5 삭제 명령으로 기존 인덱스 제거
+/// 2. 같은 rowid의 기존 인덱스 행을 삭제합니다
 /// 3. 새 내용(title, content)을 FTS5에 INSERT
 ///
-/// ## FTS5 외부 콘텐츠 테이블의 삭제 방식
-/// 일반적인 `DELETE FROM`이 아니라 특수한 삭제 명령을 사용해야 합니다:
-/// ```sql
-/// INSERT INTO fts테이블(fts테이블, rowid, 컬럼들) VALUES('delete', rowid, 이전값들)
-/// ```
-/// 이 방식은 FTS5가 역색인에서 해당 토큰들을 정확히 제거하기 위해
-/// **이전 값과 정확히 동일한 내용**을 전달받아야 합니다.
-///
-/// ## 에러 처리
-/// 삭제와 삽입 모두 `let _ =`로 에러를 무시합니다(best-effort).
-/// 이유: FTS 인덱스 업데이트 실패가 문서 저장 자체를 실패시키면 안 되기 때문입니다.
-/// 인덱스가 꼬이면 검색이 부정확해질 수 있지만, 문서 데이터는 안전합니다.
+/// 2~3은 한 트랜잭션으로 묶여 있어, 검색 중에 문서가 잠깐 사라지는 일이 없습니다.
 pub async fn index_document(
     pool: &SqlitePool,
     id: &str,
     title: &str,
     content: &str,
-    old_title: Option<&str>,
-    old_content: Option<&str>,
 ) -> Result<(), AppError> {
     // rowid 조회: SQLite는 TEXT PRIMARY KEY를 쓰더라도 내부적으로 정수 rowid를 자동 부여합니다.
-    // FTS5 외부 콘텐츠 테이블은 이 rowid로 원본 테이블과 매핑됩니다.
     // query_as::<_, (i64,)>: 결과를 i64 하나짜리 튜플로 매핑합니다
     let row: Option<(i64,)> = sqlx::query_as("SELECT rowid FROM documents WHERE id = ?")
         .bind(id)
@@ -105,30 +272,403 @@ pub async fn index_document(
         return Ok(());
     };
 
-    // 이전 내용이 있을 때만 기존 인덱스를 삭제합니다
-    // (첫 번째 저장 시에는 old 값이 None이므로 삭제를 건너뜁니다)
-    if let (Some(old_t), Some(old_c)) = (old_title, old_content) {
-        // FTS5 외부 콘텐츠 테이블의 특수 삭제 명령
-        // 첫 번째 컬럼에 'delete' 문자열을 넣으면 "삭제 모드"로 동작합니다
-        let _ = sqlx::query(
-            "INSERT INTO documents_fts(documents_fts, rowid, title, content) VALUES('delete', ?, ?, ?)",
-        )
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM documents_fts WHERE rowid = ?")
         .bind(rowid)
-        .bind(old_t)
-        .bind(old_c)
-        .execute(pool)
-        .await;
-        // let _ = ...: 결과를 의도적으로 무시합니다 (삭제 실패해도 계속 진행)
-    }
+        .execute(&mut *tx)
+        .await?;
 
-    // 새 내용으로 FTS5 인덱스에 추가합니다
     // rowid를 명시적으로 지정하여 documents 테이블의 해당 행과 매핑합니다
-    let _ = sqlx::query("INSERT INTO documents_fts(rowid, title, content) VALUES(?, ?, ?)")
+    sqlx::query("INSERT INTO documents_fts(rowid, title, content) VALUES(?, ?, ?)")
         .bind(rowid)
         .bind(title)
         .bind(content)
-        .execute(pool)
-        .await;
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 문서와 그 버전들의 색인 행을 삭제합니다.
+///
+/// 문서를 삭제하는 트랜잭션 안에서, documents 행을 지우기 전에 호출해야 합니다 (`db::delete_document`).
+/// 버전은 `ON DELETE CASCADE`로 함께 지워지므로 버전 검색 인덱스(`document_versions_fts`)도 여기서 지웁니다.
+pub async fn unindex_document(
+    tx: &mut Transaction<'_, Sqlite>,
+    id: &str,
+    user_id: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "DELETE FROM documents_fts WHERE rowid = (SELECT rowid FROM documents WHERE id = ? AND user_id = ?)",
+    )
+    .bind(id)
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
 
+    sqlx::query(
+        r#"
+        DELETE FROM document_versions_fts WHERE rowid IN (
+            SELECT v.rowid FROM document_versions v
+            JOIN documents d ON d.id = v.document_id
+            WHERE d.id = ? AND d.user_id = ?
+        )
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
     Ok(())
 }
+
+/// 색인 실패 시 바로 다시 시도하는 횟수 (첫 시도 포함)
+const INDEX_ATTEMPTS: u32 = 3;
+/// 바로 재시도할 때의 첫 대기 시간 (시도마다 두 배)
+const INDEX_RETRY_DELAY_MS: u64 = 100;
+/// 바로 재시도까지 실패했을 때 백그라운드에서 다시 시도하기 전 대기 시간 (초)
+const INDEX_BACKGROUND_RETRY_SECS: [u64; 3] = [5, 30, 120];
+
+/// 문서를 색인하고, 실패하면 로그를 남긴 뒤 다시 시도합니다.
+///
+/// 저장 요청에서 사용합니다. 색인은 파일과 메타데이터가 저장된 뒤의 부가 작업이므로,
+/// 색인에 실패해도 저장 요청 자체를 실패시키지 않습니다. 대신:
+/// 1. 짧은 간격(100ms, 200ms)으로 바로 재시도합니다 (대부분 일시적인 `database is locked`).
+/// 2. 그래도 실패하면 백그라운드 작업이 5초, 30초, 120초 뒤에 다시 시도합니다.
+///    이때는 그 사이 다른 저장이 있었을 수 있으므로 최신 제목과 파일 내용을 다시 읽습니다.
+/// 3. 모두 실패하면 에러 로그를 남깁니다. `tecindo check-index`/`reindex`로 복구할 수 있습니다.
+pub async fn index_document_or_retry(
+    pool: &SqlitePool,
+    documents_path: &str,
+    id: &str,
+    title: &str,
+    content: &str,
+) {
+    let mut delay = INDEX_RETRY_DELAY_MS;
+    for attempt in 1..=INDEX_ATTEMPTS {
+        match index_document(pool, id, title, content).await {
+            Ok(()) => return,
+            Err(e) => {
+                tracing::warn!(
+                    "Search indexing failed for document {} (attempt {}/{}): {}",
+                    id,
+                    attempt,
+                    INDEX_ATTEMPTS,
+                    e
+                );
+            }
+        }
+        if attempt < INDEX_ATTEMPTS {
+            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+            delay *= 2;
+        }
+    }
+
+    // SqlitePool과 String은 'static으로 옮길 수 있도록 복제합니다 (풀은 Arc라 복제 비용이 작음).
+    let pool = pool.clone();
+    let documents_path = documents_path.to_string();
+    let id = id.to_string();
+    tokio::spawn(async move {
+        for secs in INDEX_BACKGROUND_RETRY_SECS {
+            tokio::time::sleep(std::time::Duration::from_secs(secs)).await;
+            match reindex_document(&pool, &documents_path, &id).await {
+                Ok(_) => {
+                    tracing::info!("Search indexing for document {} succeeded on retry", id);
+                    return;
+                }
+                Err(e) => tracing::warn!("Search indexing retry failed for document {}: {}", id, e),
+            }
+        }
+        tracing::error!(
+            "Giving up search indexing for document {}; run `tecindo reindex` to repair the index",
+            id
+        );
+    });
+}
+
+/// DB의 현재 제목과 .md 파일의 현재 내용으로 문서 하나를 다시 색인합니다.
+///
+/// # 반환값
+/// 문서가 삭제되어 색인하지 않았으면 `false`
+pub async fn reindex_document(
+    pool: &SqlitePool,
+    documents_path: &str,
+    id: &str,
+) -> Result<bool, AppError> {
+    let row: Option<(String, String)> =
+        sqlx::query_as("SELECT title, file_path FROM documents WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await?;
+    let Some((title, file_path)) = row else {
+        return Ok(false);
+    };
+    let content = services::read_markdown(documents_path, &file_path).await?;
+    index_document(pool, id, &title, &content).await?;
+    Ok(true)
+}
+
+/// 검색 인덱스에 없는 문서를 모두 찾아 파일을 읽어 색인합니다.
+///
+/// 서버 시작 시 호출됩니다. 인덱스를 새로 만든 직후(migration 008)나
+/// 색인이 누락된 문서가 있을 때 검색 결과에서 빠지지 않도록 합니다.
+/// 파일을 읽지 못한 문서는 경고 로그를 남기고 건너뜁니다.
+///
+/// # 반환값
+/// 새로 색인한 문서 수
+pub async fn index_missing_documents(
+    pool: &SqlitePool,
+    documents_path: &str,
+) -> Result<usize, AppError> {
+    let missing: Vec<(String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT id, title, file_path FROM documents
+        WHERE rowid NOT IN (SELECT rowid FROM documents_fts)
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut indexed = 0;
+    for (id, title, file_path) in missing {
+        let content = match services::read_markdown(documents_path, &file_path).await {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::warn!("Failed to read {} for search indexing: {}", file_path, e);
+                continue;
+            }
+        };
+        index_document(pool, &id, &title, &content).await?;
+        indexed += 1;
+    }
+
+    Ok(indexed)
+}
+
+/// 인덱스 재구성/검사 대상 문서 목록을 조회합니다. `user_id`가 None이면 모든 사용자의 문서입니다.
+async fn list_index_targets(
+    pool: &SqlitePool,
+    user_id: Option<&str>,
+) -> Result<Vec<(String, String, String)>, AppError> {
+    let rows = match user_id {
+        Some(user_id) => {
+            sqlx::query_as("SELECT id, title, file_path FROM documents WHERE user_id = ? ORDER BY id")
+                .bind(user_id)
+                .fetch_all(pool)
+                .await?
+        }
+        None => {
+            sqlx::query_as("SELECT id, title, file_path FROM documents ORDER BY id")
+                .fetch_all(pool)
+                .await?
+        }
+    };
+    Ok(rows)
+}
+
+/// 삭제된 문서를 가리키는 색인 행의 조건
+const ORPHAN_CONDITION: &str = "rowid NOT IN (SELECT rowid FROM documents)";
+
+/// .md 파일을 다시 읽어 검색 인덱스를 재구성합니다.
+///
+/// 문서마다 기존 색인 행을 지우고 다시 넣으므로(`index_document`),
+/// 재구성 중에도 다른 문서는 계속 검색됩니다.
+/// 파일을 읽지 못한 문서는 `failed`에 담고 기존 색인을 그대로 둡니다.
+///
+/// `user_id`가 있으면 그 사용자의 문서만 재구성합니다 (API).
+/// None이면 모든 문서를 재구성하고, 삭제된 문서를 가리키는 색인 행도 지웁니다 (CLI).
+pub async fn rebuild_search_index(
+    pool: &SqlitePool,
+    documents_path: &str,
+    user_id: Option<&str>,
+) -> Result<IndexRebuildReport, AppError> {
+    let mut report = IndexRebuildReport::default();
+
+    for (id, title, file_path) in list_index_targets(pool, user_id).await? {
+        let result = match services::read_markdown(documents_path, &file_path).await {
+            Ok(content) => index_document(pool, &id, &title, &content).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => report.indexed += 1,
+            Err(e) => {
+                tracing::warn!("Failed to rebuild search index for document {}: {}", id, e);
+                report.failed.push(id);
+            }
+        }
+    }
+
+    if user_id.is_none() {
+        report.orphans_removed =
+            sqlx::query(&format!("DELETE FROM documents_fts WHERE {}", ORPHAN_CONDITION))
+                .execute(pool)
+                .await?
+                .rows_affected();
+    }
+
+    Ok(report)
+}
+
+/// 검색 인덱스가 DB와 .md 파일 내용과 일치하는지 검사합니다.
+///
+/// - 문서마다 색인 행이 있는지, 색인된 제목/본문이 현재 제목/파일 내용과 같은지 비교합니다.
+/// - FTS5의 `integrity-check` 명령으로 인덱스 자료구조가 손상되지 않았는지 확인합니다.
+/// - `user_id`가 None이면(CLI) 삭제된 문서를 가리키는 색인 행 수도 셉니다.
+///   사용자별 검사에서는 어느 사용자의 행이었는지 알 수 없으므로 0입니다.
+pub async fn check_search_index(
+    pool: &SqlitePool,
+    documents_path: &str,
+    user_id: Option<&str>,
+) -> Result<IndexCheckReport, AppError> {
+    let mut report = IndexCheckReport::default();
+
+    for (id, title, file_path) in list_index_targets(pool, user_id).await? {
+        report.checked += 1;
+        let indexed: Option<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT documents_fts.title, documents_fts.content
+            FROM documents_fts JOIN documents d ON d.rowid = documents_fts.rowid
+            WHERE d.id = ?
+            "#,
+        )
+        .bind(&id)
+        .fetch_optional(pool)
+        .await?;
+
+        let Some((indexed_title, indexed_content)) = indexed else {
+            report.missing.push(id);
+            continue;
+        };
+        match services::read_markdown(documents_path, &file_path).await {
+            Ok(content) if indexed_title == title && indexed_content == content => {}
+            Ok(_) => report.stale.push(id),
+            Err(_) => report.unreadable.push(id),
+        }
+    }
+
+    if user_id.is_none() {
+        report.orphaned = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM documents_fts WHERE {}",
+            ORPHAN_CONDITION
+        ))
+        .fetch_one(pool)
+        .await?;
+    }
+
+    // 'integrity-check'는 인덱스가 손상되었으면 SQLITE_CORRUPT 에러를 반환합니다.
+    report.integrity_ok =
+        match sqlx::query("INSERT INTO documents_fts(documents_fts) VALUES('integrity-check')")
+            .execute(pool)
+            .await
+        {
+            Ok(_) => true,
+            Err(e) => {
+                tracing::warn!("Search index integrity check failed: {}", e);
+                false
+            }
+        };
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateDocumentRequest;
+
+    /// 문서를 만들고 본문을 색인합니다 (.md 파일은 만들지 않음).
+    async fn add_document(pool: &SqlitePool, user_id: &str, title: &str, content: &str) -> String {
+        let id = uuid::Uuid::now_v7().to_string();
+        let req = CreateDocumentRequest { title: Some(title.to_string()), folder_id: None, template_id: None };
+        let file_path = format!("{}.md", id);
+        crate::db::create_document(pool, &id, &req, file_path, slug::slugify(title), user_id)
+            .await
+            .unwrap();
+        index_document(pool, &id, title, content).await.unwrap();
+        id
+    }
+
+    /// 검색어로 찾은 문서 제목들을 제목순으로 반환합니다.
+    async fn search_titles(pool: &SqlitePool, user_id: &str, input: &str) -> Vec<String> {
+        let query = services::build_fts_query(input).unwrap();
+        let page = SearchPage { limit: 50, offset: 0 };
+        let (rows, _) = search_documents(pool, &query, &SearchFilters::default(), None, &[], user_id, page)
+            .await
+            .unwrap();
+        let mut titles: Vec<String> = rows.into_iter().map(|row| row.document.title).collect();
+        titles.sort();
+        titles
+    }
+
+    #[tokio::test]
+    async fn short_terms_fall_back_to_like() {
+        let (pool, user_id) = crate::db::test_pool().await;
+        add_document(&pool, &user_id, "일기", "오늘은 소설을 썼다").await;
+        add_document(&pool, &user_id, "메모", "장보기 목록").await;
+
+        // 2글자 검색어는 trigram으로 MATCH할 수 없으므로 LIKE로 찾아야 합니다.
+        assert_eq!(search_titles(&pool, &user_id, "소설").await, ["일기"]);
+        // 1글자도 마찬가지입니다.
+        assert_eq!(search_titles(&pool, &user_id, "록").await, ["메모"]);
+        // 짧은 검색어와 긴 검색어를 섞어도 둘 다 만족해야 합니다.
+        assert_eq!(search_titles(&pool, &user_id, "소설 오늘은").await, ["일기"]);
+        assert!(search_titles(&pool, &user_id, "소설 장보기").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn trigram_matches_inside_compound_words() {
+        let (pool, user_id) = crate::db::test_pool().await;
+        add_document(&pool, &user_id, "설정집", "세계관설정 초안과 지도").await;
+        add_document(&pool, &user_id, "잡담", "세계 여행 계획").await;
+
+        // 띄어쓰기 없이 붙은 "세계관설정" 안의 "세계관"도 찾습니다 (단어 단위 토크나이저는 못 찾음).
+        assert_eq!(search_titles(&pool, &user_id, "세계관").await, ["설정집"]);
+        assert_eq!(search_titles(&pool, &user_id, "관설정").await, ["설정집"]);
+    }
+
+    #[tokio::test]
+    async fn names_are_found_with_attached_particles() {
+        let (pool, user_id) = crate::db::test_pool().await;
+        add_document(&pool, &user_id, "1장", "민지가 문을 열었다.").await;
+        add_document(&pool, &user_id, "2장", "그날 민지는 돌아오지 않았다.").await;
+        add_document(&pool, &user_id, "3장", "준호만 남았다.").await;
+
+        // 조사가 붙은 "민지가", "민지는" 모두 "민지"로 찾습니다.
+        assert_eq!(search_titles(&pool, &user_id, "민지").await, ["1장", "2장"]);
+        // 조사까지 넣어 검색하면 그 형태만 찾습니다.
+        assert_eq!(search_titles(&pool, &user_id, "민지가").await, ["1장"]);
+        assert_eq!(search_titles(&pool, &user_id, "민지는").await, ["2장"]);
+        // 제외 검색어도 짧으면 LIKE로 처리됩니다.
+        assert_eq!(search_titles(&pool, &user_id, "남았다 -민지").await, ["3장"]);
+    }
+
+    #[tokio::test]
+    async fn retry_indexes_after_transient_failure() {
+        let (pool, user_id) = crate::db::test_pool().await;
+        let id = add_document(&pool, &user_id, "초안", "").await;
+
+        // 색인 테이블을 잠시 치워 첫 시도를 실패시키고, 다음 시도(100ms 뒤) 전에 되돌립니다.
+        sqlx::query("ALTER TABLE documents_fts RENAME TO documents_fts_away")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let restore = {
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+                sqlx::query("ALTER TABLE documents_fts_away RENAME TO documents_fts")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+            })
+        };
+
+        assert!(index_document(&pool, &id, "초안", "다시 쓴 문장").await.is_err());
+        index_document_or_retry(&pool, "unused", &id, "초안", "다시 쓴 문장").await;
+        restore.await.unwrap();
+
+        assert_eq!(search_titles(&pool, &user_id, "다시 쓴").await, ["초안"]);
+    }
+}
@@ -0,0 +1,222 @@
+//! # 문서 상태 워크플로 데이터베이스 쿼리 모듈
+//!
+//! `document_statuses`(사용자별 상태 목록), `documents.status`(문서의 현재 상태),
+//! `document_status_history`(상태 변경 이력) 쿼리입니다.
+
+use crate::error::AppError;
+use crate::models::*;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+/// 사용자의 상태 목록을 순서대로 조회합니다. 저장한 적이 없으면 기본 목록을 반환합니다.
+pub async fn list_document_statuses(pool: &SqlitePool, user_id: &str) -> Result<Vec<DocumentStatus>, AppError> {
+    let statuses = sqlx::query_as::<_, DocumentStatus>(
+        "SELECT key, name, color FROM document_statuses WHERE user_id = ? ORDER BY sort_order",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    if statuses.is_empty() {
+        return Ok(default_document_statuses());
+    }
+    Ok(statuses)
+}
+
+/// 사용자의 상태 목록을 통째로 교체합니다. 목록의 순서가 `sort_order`가 됩니다.
+pub async fn replace_document_statuses(
+    pool: &SqlitePool,
+    statuses: &[DocumentStatus],
+    user_id: &str,
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM document_statuses WHERE user_id = ?")
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for (sort_order, status) in statuses.iter().enumerate() {
+        sqlx::query("INSERT INTO document_statuses (user_id, key, name, color, sort_order) VALUES (?, ?, ?, ?, ?)")
+            .bind(user_id)
+            .bind(&status.key)
+            .bind(&status.name)
+            .bind(&status.color)
+            .bind(sort_order as i64)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// 사용자의 문서들이 현재 쓰고 있는 상태 key와 문서 수를 조회합니다.
+///
+/// 휴지통의 문서도 셉니다 — 상태 목록에서 빠진 상태로 복원되지 않게 하기 위해서입니다.
+pub async fn list_used_statuses(pool: &SqlitePool, user_id: &str) -> Result<Vec<(String, i64)>, AppError> {
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT status, COUNT(*) FROM documents WHERE user_id = ? AND status IS NOT NULL GROUP BY status",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// 트랜잭션 안에서 문서의 상태를 바꾸고 이력을 남깁니다.
+///
+/// `PATCH /documents/:id`와 일괄 작업(`set_status`)이 함께 사용합니다.
+/// 상태 key는 호출자가 사용자 상태 목록에 있는지 확인한 값이어야 합니다.
+///
+/// # 반환값
+/// - `true`: 상태가 바뀌어 이력을 남김
+/// - `false`: 이미 같은 상태이거나 문서가 없음 (아무것도 바꾸지 않음)
+pub(crate) async fn change_document_status(
+    tx: &mut Transaction<'_, Sqlite>,
+    document_id: &str,
+    status: Option<&str>,
+    user_id: &str,
+) -> Result<bool, AppError> {
+    let current: Option<(Option<String>,)> =
+        sqlx::query_as("SELECT status FROM documents WHERE id = ? AND user_id = ?")
+            .bind(document_id)
+            .bind(user_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+    let Some((from_status,)) = current else {
+        return Ok(false);
+    };
+    if from_status.as_deref() == status {
+        return Ok(false);
+    }
+
+    sqlx::query(
+        "UPDATE documents SET status = ?, updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now') WHERE id = ? AND user_id = ?",
+    )
+    .bind(status)
+    .bind(document_id)
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query("INSERT INTO document_status_history (id, document_id, from_status, to_status) VALUES (?, ?, ?, ?)")
+        .bind(uuid::Uuid::now_v7().to_string())
+        .bind(document_id)
+        .bind(&from_status)
+        .bind(status)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(true)
+}
+
+/// 문서의 상태를 바꾸고 이력을 남깁니다 (`change_document_status`를 트랜잭션 하나로 감쌈).
+///
+/// 내용 저장 시 front matter의 `status:`를 반영할 때 사용합니다.
+pub async fn set_document_status(
+    pool: &SqlitePool,
+    document_id: &str,
+    status: Option<&str>,
+    user_id: &str,
+) -> Result<bool, AppError> {
+    let mut tx = pool.begin().await?;
+    let changed = change_document_status(&mut tx, document_id, status, user_id).await?;
+    tx.commit().await?;
+    Ok(changed)
+}
+
+/// 문서의 상태 변경 이력을 최근 순으로 조회합니다.
+///
+/// 문서 소유권은 호출자가 확인해야 합니다.
+pub async fn list_status_history(pool: &SqlitePool, document_id: &str) -> Result<Vec<StatusChange>, AppError> {
+    // 같은 밀리초에 바뀐 이력은 UUIDv7 ID(생성 순서)로 순서를 정합니다.
+    let history = sqlx::query_as::<_, StatusChange>(
+        r#"
+        SELECT id, document_id, from_status, to_status, changed_at
+        FROM document_status_history
+        WHERE document_id = ?
+        ORDER BY changed_at DESC, id DESC
+        "#,
+    )
+    .bind(document_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(history)
+}
+
+/// 상태별 문서 수와 단어 수 합계를 계산합니다.
+///
+/// - `folder_ids`: Some이면 이 폴더들에 속한 문서만 (`get_folder_stats`와 같음)
+/// - `tag_ids`: Some이면 이 태그들 중 하나라도 붙은 문서만 (`get_tag_stats`와 같음)
+///
+/// 문서가 하나도 없는 상태는 결과에 없습니다. 상태가 없는 문서는 `status`가 None인 행으로 묶입니다.
+pub async fn list_status_totals(
+    pool: &SqlitePool,
+    folder_ids: Option<&[String]>,
+    tag_ids: Option<&[String]>,
+    user_id: &str,
+) -> Result<Vec<StatusTotal>, AppError> {
+    // 비어 있으면 `IN (NULL)`이 되어 아무 문서도 일치하지 않습니다.
+    let placeholders = |ids: &[String]| {
+        if ids.is_empty() {
+            "NULL".to_string()
+        } else {
+            vec!["?"; ids.len()].join(", ")
+        }
+    };
+
+    let mut conditions = String::from("WHERE user_id = ? AND deleted_at IS NULL");
+    let mut bindings: Vec<&str> = vec![user_id];
+    if let Some(ids) = folder_ids {
+        conditions.push_str(&format!(" AND folder_id IN ({})", placeholders(ids)));
+        bindings.extend(ids.iter().map(String::as_str));
+    }
+    if let Some(ids) = tag_ids {
+        conditions.push_str(&format!(
+            " AND id IN (SELECT document_id FROM document_tags WHERE tag_id IN ({}))",
+            placeholders(ids)
+        ));
+        bindings.extend(ids.iter().map(String::as_str));
+    }
+
+    let sql = format!(
+        r#"
+        SELECT status, COUNT(*) AS document_count, COALESCE(SUM(word_count), 0) AS word_count
+        FROM documents
+        {}
+        GROUP BY status
+        "#,
+        conditions
+    );
+
+    let mut query = sqlx::query_as::<_, StatusTotal>(&sql);
+    for binding in bindings {
+        query = query.bind(binding);
+    }
+    let totals = query.fetch_all(pool).await?;
+
+    Ok(totals)
+}
+
+/// 상태 필터 조건을 WHERE 절에 덧붙입니다 (`db::list_documents`, `db::search_documents` 공용).
+///
+/// 상태들 중 하나인 문서와 일치합니다 (OR). `none`은 상태가 없는 문서입니다.
+/// 문서 테이블의 별칭은 `d`여야 합니다.
+pub(crate) fn push_status_conditions(conditions: &mut String, bindings: &mut Vec<String>, statuses: &[String]) {
+    if statuses.is_empty() {
+        return;
+    }
+
+    let mut alternatives = Vec::new();
+    let keys: Vec<&String> = statuses.iter().filter(|s| s.as_str() != NO_STATUS_FILTER).collect();
+    if !keys.is_empty() {
+        alternatives.push(format!("d.status IN ({})", vec!["?"; keys.len()].join(", ")));
+        bindings.extend(keys.into_iter().cloned());
+    }
+    if statuses.iter().any(|s| s == NO_STATUS_FILTER) {
+        alternatives.push("d.status IS NULL".to_string());
+    }
+    conditions.push_str(&format!(" AND ({})", alternatives.join(" OR ")));
+}
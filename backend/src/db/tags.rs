@@ -4,8 +4,18 @@
 //! 모든 함수는 `SqlitePool` 참조를 받아 비동기로 실행됩니다.
 //!
 //! ## 테이블 구조
-//! - `tags`: 태그 엔티티 (id, name, color)
-//! - `document_tags`: 문서와 태그의 다대다(N:M) 관계 테이블
+//! - `tags`: 태그 엔티티 (id, name, color, parent_id — 부모 태그로 트리 구성)
+//! - `document_tags`: 문서와 태그의 다대다(N:M) 관계 테이블 (붙인 시각 `created_at`, 출처 `source` 포함)
+//!
+//! ## 연결 출처 (`document_tags.source`)
+//! - `manual`: 태그 UI/API로 직접 붙인 태그 (`add_tag_to_document`, `merge_tags`)
+//! - `hashtag`: 본문의 `#해시태그`에서 붙은 태그 (`sync_hashtag_tags`가 내용 저장 때마다 다시 맞춤)
+//!
+//! 같은 태그를 직접도 붙이고 해시태그로도 쓰면 `manual`로 남아, 해시태그를 지워도 떨어지지 않습니다.
+//!
+//! ## 마지막 사용 시각
+//! 태그를 붙인 시각(`document_tags.created_at`)과 태그된 문서의 수정 시각 중 가장 최근 값입니다.
+//! 붙인 시각이 기록되기 전(마이그레이션 011 이전)에 붙인 태그는 문서의 수정 시각만 봅니다.
 
 use crate::error::AppError;
 use crate::models::*;
@@ -20,7 +30,7 @@ use sqlx::SqlitePool;
 /// - `fetch_all`은 모든 행을 Vec으로 반환합니다
 pub async fn list_tags(pool: &SqlitePool, user_id: &str) -> Result<Vec<Tag>, AppError> {
     let tags = sqlx::query_as::<_, Tag>(
-        "SELECT id, name, color FROM tags WHERE user_id = ? ORDER BY name",
+        "SELECT id, name, color, parent_id FROM tags WHERE user_id = ? ORDER BY name",
     )
     .bind(user_id)
     .fetch_all(pool)
@@ -29,6 +39,116 @@ pub async fn list_tags(pool: &SqlitePool, user_id: &str) -> Result<Vec<Tag>, App
     Ok(tags)
 }
 
+/// 모든 태그를 사용 현황(문서 수, 총 단어 수, 마지막 사용 시각)과 함께 이름순으로 조회합니다.
+///
+/// `LEFT JOIN`이므로 아무 문서에도 붙지 않은 태그도 `document_count = 0`으로 포함됩니다.
+/// 휴지통의 문서는 세지 않습니다.
+///
+/// 마지막 사용 시각: 인자가 둘인 `MAX(a, b)`는 SQLite의 스칼라 함수로 행마다 붙인 시각과
+/// 문서 수정 시각 중 늦은 쪽을 고르고, 이를 감싼 `MAX(...)`는 집계 함수로 그중 최댓값을 고릅니다.
+/// 스칼라 MAX는 인자 중 NULL이 있으면 NULL이 되므로 붙인 시각이 없으면 수정 시각으로 대신합니다.
+pub async fn list_tags_with_usage(
+    pool: &SqlitePool,
+    user_id: &str,
+) -> Result<Vec<TagWithUsage>, AppError> {
+    let tags = sqlx::query_as::<_, TagWithUsage>(
+        r#"
+        SELECT t.id, t.name, t.color, t.parent_id,
+               COUNT(d.id) AS document_count,
+               COALESCE(SUM(d.word_count), 0) AS word_count,
+               MAX(MAX(d.updated_at, COALESCE(dt.created_at, d.updated_at))) AS last_used_at
+        FROM tags t
+        LEFT JOIN document_tags dt ON dt.tag_id = t.id
+        LEFT JOIN documents d ON d.id = dt.document_id AND d.deleted_at IS NULL
+        WHERE t.user_id = ?
+        GROUP BY t.id
+        ORDER BY t.name
+        "#,
+    )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(tags)
+}
+
+/// 태그 자신과 모든 하위 태그의 ID를 조회합니다.
+///
+/// `WITH RECURSIVE`(재귀 CTE)로 `parent_id`를 따라 트리를 내려갑니다 (`list_descendant_folder_ids`와 같은 방식).
+/// `UNION`은 중복 행을 버리므로 혹시 순환이 생겨도 무한히 돌지 않습니다.
+/// 태그가 없거나 다른 사용자의 태그면 빈 목록을 반환합니다.
+pub async fn list_descendant_tag_ids(
+    pool: &SqlitePool,
+    tag_id: &str,
+    user_id: &str,
+) -> Result<Vec<String>, AppError> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        r#"
+        WITH RECURSIVE subtree(id) AS (
+            SELECT id FROM tags WHERE id = ? AND user_id = ?
+            UNION
+            SELECT t.id FROM tags t JOIN subtree s ON t.parent_id = s.id
+            WHERE t.user_id = ?
+        )
+        SELECT id FROM subtree
+        "#,
+    )
+    .bind(tag_id)
+    .bind(user_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
+
+/// 주어진 태그들 중 하나라도 붙은 문서들의 통계 합계를 계산합니다.
+///
+/// 부모 태그의 통계는 `list_descendant_tag_ids`로 펼친 ID 목록을 넘겨 계산합니다.
+/// 여러 태그가 붙은 문서도 한 번만 세도록, 문서별로 먼저 묶은 뒤(GROUP BY document_id) 합산합니다.
+/// `tag_ids`가 비어 있으면 모든 값이 0인 결과를 반환합니다.
+pub async fn get_tag_stats(
+    pool: &SqlitePool,
+    tag_ids: &[String],
+    user_id: &str,
+) -> Result<TagStats, AppError> {
+    // folder_ids와 마찬가지로 비어 있으면 `IN (NULL)`이 되어 아무 문서도 일치하지 않습니다.
+    let placeholders = if tag_ids.is_empty() {
+        "NULL".to_string()
+    } else {
+        vec!["?"; tag_ids.len()].join(", ")
+    };
+    // u: 문서별로 처음/마지막으로 태그를 붙인 시각 (MIN/MAX는 NULL을 무시)
+    let sql = format!(
+        r#"
+        SELECT COUNT(d.id) AS document_count,
+               COALESCE(SUM(d.is_archived), 0) AS archived_count,
+               COALESCE(SUM(d.word_count), 0) AS word_count,
+               COALESCE(SUM(d.char_count), 0) AS char_count,
+               COALESCE(SUM(d.char_count_no_spaces), 0) AS char_count_no_spaces,
+               COALESCE(SUM(d.manuscript_pages), 0) AS manuscript_pages,
+               MIN(COALESCE(u.first_tagged_at, d.created_at)) AS first_used_at,
+               MAX(MAX(d.updated_at, COALESCE(u.last_tagged_at, d.updated_at))) AS last_used_at
+        FROM documents d
+        JOIN (
+            SELECT document_id, MIN(created_at) AS first_tagged_at, MAX(created_at) AS last_tagged_at
+            FROM document_tags
+            WHERE tag_id IN ({placeholders})
+            GROUP BY document_id
+        ) u ON u.document_id = d.id
+        WHERE d.user_id = ? AND d.deleted_at IS NULL
+        "#
+    );
+
+    let mut query = sqlx::query_as::<_, TagStats>(&sql);
+    for id in tag_ids {
+        query = query.bind(id);
+    }
+    let stats = query.bind(user_id).fetch_one(pool).await?;
+
+    Ok(stats)
+}
+
 /// ID로 태그 하나를 조회합니다.
 ///
 /// `fetch_optional`은 결과가 0행이면 None, 1행이면 Some(Tag)을 반환합니다.
@@ -36,7 +156,7 @@ pub async fn list_tags(pool: &SqlitePool, user_id: &str) -> Result<Vec<Tag>, App
 /// `fetch_optional`이 더 안전합니다.
 pub async fn get_tag(pool: &SqlitePool, id: &str, user_id: &str) -> Result<Option<Tag>, AppError> {
     let tag = sqlx::query_as::<_, Tag>(
-        "SELECT id, name, color FROM tags WHERE id = ? AND user_id = ?",
+        "SELECT id, name, color, parent_id FROM tags WHERE id = ? AND user_id = ?",
     )
     .bind(id)
     .bind(user_id)
@@ -58,10 +178,11 @@ pub async fn get_tag(pool: &SqlitePool, id: &str, user_id: &str) -> Result<Optio
 pub async fn create_tag(pool: &SqlitePool, req: &CreateTagRequest, user_id: &str) -> Result<Tag, AppError> {
     let id = uuid::Uuid::now_v7().to_string();
 
-    sqlx::query("INSERT INTO tags (id, name, color, user_id) VALUES (?, ?, ?, ?)")
+    sqlx::query("INSERT INTO tags (id, name, color, parent_id, user_id) VALUES (?, ?, ?, ?, ?)")
         .bind(&id)
         .bind(&req.name)
         .bind(&req.color)
+        .bind(&req.parent_id)
         .bind(user_id)
         .execute(pool)
         .await?;
@@ -109,6 +230,15 @@ pub async fn update_tag(
             .await?;
     }
 
+    if let Some(parent_id) = &req.parent_id {
+        sqlx::query("UPDATE tags SET parent_id = ? WHERE id = ? AND user_id = ?")
+            .bind(parent_id)
+            .bind(id)
+            .bind(user_id)
+            .execute(pool)
+            .await?;
+    }
+
     get_tag(pool, id, user_id).await
 }
 
@@ -130,17 +260,107 @@ pub async fn delete_tag(pool: &SqlitePool, id: &str, user_id: &str) -> Result<bo
     Ok(result.rows_affected() > 0)
 }
 
-/// 문서에 태그를 연결합니다 (다대다 관계 추가).
+/// 여러 태그(`source_ids`)를 대상 태그(`target_id`)로 합칩니다.
+///
+/// 한 트랜잭션 안에서 다음을 처리하므로, 중간에 실패하면 아무것도 바뀌지 않습니다.
+/// 1. 원래 태그가 붙은 문서에 대상 태그를 붙임 (`INSERT OR IGNORE`로 이미 붙은 문서는 건너뜀)
+///    옮긴 연결은 출처가 `manual`이 됩니다. 본문에 `#원래태그`가 남아 있으면 대상 태그를 뒷받침할
+///    해시태그가 없으므로, 다음 저장 때 떨어지지 않도록 직접 붙인 태그로 취급합니다.
+/// 2. 원래 태그의 하위 태그를 대상 태그 아래로 옮김
+/// 3. 저장된 검색 필터의 태그 ID를 대상 태그로 바꿈
+/// 4. 원래 태그 삭제 (`document_tags`의 원래 연결은 CASCADE로 함께 삭제)
 ///
-/// `INSERT OR IGNORE`: 이미 동일한 (document_id, tag_id) 조합이 존재하면
-/// 에러를 발생시키지 않고 무시합니다. 이를 통해 중복 연결을 방지합니다.
+/// 태그의 존재 여부, 대상이 원래 태그의 하위 태그가 아닌지는 호출자가 미리 확인합니다.
+/// 결과의 `documents_rewritten`(해시태그 다시 쓰기)은 호출자가 채웁니다.
+pub async fn merge_tags(
+    pool: &SqlitePool,
+    target_id: &str,
+    source_ids: &[String],
+    user_id: &str,
+) -> Result<TagMergeReport, AppError> {
+    let placeholders = vec!["?"; source_ids.len()].join(", ");
+    let mut tx = pool.begin().await?;
+
+    let count_sql = format!(
+        "SELECT COUNT(DISTINCT document_id) FROM document_tags WHERE tag_id IN ({})",
+        placeholders
+    );
+    let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+    for id in source_ids {
+        count_query = count_query.bind(id);
+    }
+    let documents = count_query.fetch_one(&mut *tx).await?;
+
+    // 처음 붙인 시각을 유지하려고 문서별로 가장 이른 created_at을 옮깁니다.
+    let retag_sql = format!(
+        r#"
+        INSERT OR IGNORE INTO document_tags (document_id, tag_id, created_at)
+        SELECT document_id, ?, MIN(created_at) FROM document_tags
+        WHERE tag_id IN ({})
+        GROUP BY document_id
+        "#,
+        placeholders
+    );
+    let mut retag_query = sqlx::query(&retag_sql).bind(target_id);
+    for id in source_ids {
+        retag_query = retag_query.bind(id);
+    }
+    let retagged = retag_query.execute(&mut *tx).await?;
+
+    let reparent_sql = format!(
+        "UPDATE tags SET parent_id = ? WHERE user_id = ? AND parent_id IN ({})",
+        placeholders
+    );
+    let mut reparent_query = sqlx::query(&reparent_sql).bind(target_id).bind(user_id);
+    for id in source_ids {
+        reparent_query = reparent_query.bind(id);
+    }
+    let reparented = reparent_query.execute(&mut *tx).await?;
+
+    let saved_searches_updated =
+        super::replace_saved_search_tags(&mut tx, source_ids, target_id, user_id).await?;
+
+    let delete_sql = format!(
+        "DELETE FROM tags WHERE user_id = ? AND id IN ({})",
+        placeholders
+    );
+    let mut delete_query = sqlx::query(&delete_sql).bind(user_id);
+    for id in source_ids {
+        delete_query = delete_query.bind(id);
+    }
+    delete_query.execute(&mut *tx).await?;
+
+    tx.commit().await?;
+
+    Ok(TagMergeReport {
+        merged_tag_ids: source_ids.to_vec(),
+        documents: documents as u64,
+        documents_retagged: retagged.rows_affected(),
+        children_moved: reparented.rows_affected(),
+        saved_searches_updated,
+        documents_rewritten: 0,
+    })
+}
+
+/// 문서에 태그를 직접 연결합니다 (다대다 관계 추가, 출처 `manual`).
+///
+/// `ON CONFLICT ... DO UPDATE` (UPSERT): 이미 동일한 (document_id, tag_id) 조합이 존재하면
+/// 새 행을 넣지 않고 기존 행의 출처만 `manual`로 바꿉니다. 이를 통해 중복 연결을 방지하고,
+/// 해시태그로 붙어 있던 태그를 직접 붙이면 해시태그를 지워도 남게 합니다.
 /// (document_tags 테이블의 PRIMARY KEY가 복합키이므로 중복 시 충돌 발생)
+/// 이미 붙어 있던 태그는 붙인 시각도 처음 값 그대로 유지됩니다.
 pub async fn add_tag_to_document(
     pool: &SqlitePool,
     document_id: &str,
     tag_id: &str,
 ) -> Result<(), AppError> {
-    sqlx::query("INSERT OR IGNORE INTO document_tags (document_id, tag_id) VALUES (?, ?)")
+    sqlx::query(
+        r#"
+        INSERT INTO document_tags (document_id, tag_id, created_at, source)
+        VALUES (?, ?, strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), 'manual')
+        ON CONFLICT (document_id, tag_id) DO UPDATE SET source = 'manual'
+        "#,
+    )
         .bind(document_id)
         .bind(tag_id)
         .execute(pool)
@@ -149,6 +369,31 @@ pub async fn add_tag_to_document(
     Ok(())
 }
 
+/// 한 문서의 태그 연결을 다른 문서로 복사합니다 (문서 복제).
+///
+/// 출처(`manual`/`hashtag`)도 그대로 복사합니다. 해시태그 연결은 복제본의 내용을 저장할 때
+/// 본문 기준으로 다시 맞춰지므로, 본문과 어긋나지 않습니다.
+pub async fn copy_document_tags(
+    pool: &SqlitePool,
+    source_id: &str,
+    target_id: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO document_tags (document_id, tag_id, created_at, source)
+        SELECT ?, tag_id, strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), source
+        FROM document_tags
+        WHERE document_id = ?
+        "#,
+    )
+    .bind(target_id)
+    .bind(source_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
 /// 문서에서 태그 연결을 해제합니다.
 ///
 /// ## 반환값
@@ -168,6 +413,90 @@ pub async fn remove_tag_from_document(
     Ok(result.rows_affected() > 0)
 }
 
+/// 본문의 해시태그에 맞춰 문서의 `hashtag` 출처 태그 연결을 갱신합니다.
+///
+/// 내용을 저장할 때마다 호출됩니다 (`routes::documents::save_document_content`).
+/// `hashtags`는 `services::extract_hashtags`의 결과로, 해시태그마다 `/`로 나뉜 경로입니다.
+///
+/// 한 트랜잭션 안에서:
+/// 1. 해시태그마다 태그를 찾고, 없으면 만듭니다. `#소설/판타지`는 "소설" 아래에 "판타지"를 만들고
+///    마지막 태그("판타지")만 문서에 붙입니다. 이미 있는 태그는 (다른 부모 아래 있더라도) 그대로 씁니다.
+/// 2. 본문에서 사라진 해시태그의 연결을 지웁니다. 직접 붙인(`manual`) 태그는 건드리지 않습니다.
+/// 3. 새 해시태그의 연결을 `hashtag` 출처로 추가합니다 (이미 붙어 있으면 출처를 바꾸지 않음).
+pub async fn sync_hashtag_tags(
+    pool: &SqlitePool,
+    document_id: &str,
+    hashtags: &[Vec<String>],
+    user_id: &str,
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    let mut tag_ids: Vec<String> = Vec::with_capacity(hashtags.len());
+    for path in hashtags {
+        let mut parent_id: Option<String> = None;
+        for name in path {
+            let existing: Option<String> =
+                sqlx::query_scalar("SELECT id FROM tags WHERE user_id = ? AND name = ?")
+                    .bind(user_id)
+                    .bind(name)
+                    .fetch_optional(&mut *tx)
+                    .await?;
+            let id = match existing {
+                Some(id) => id,
+                None => {
+                    let id = uuid::Uuid::now_v7().to_string();
+                    sqlx::query("INSERT INTO tags (id, name, parent_id, user_id) VALUES (?, ?, ?, ?)")
+                        .bind(&id)
+                        .bind(name)
+                        .bind(&parent_id)
+                        .bind(user_id)
+                        .execute(&mut *tx)
+                        .await?;
+                    id
+                }
+            };
+            parent_id = Some(id);
+        }
+        if let Some(id) = parent_id {
+            if !tag_ids.contains(&id) {
+                tag_ids.push(id);
+            }
+        }
+    }
+
+    // 본문에 남아 있지 않은 해시태그 연결 삭제
+    // (tag_ids가 비었을 때 `NOT IN (NULL)`은 항상 NULL이 되어 아무것도 지우지 못하므로 조건을 뺍니다)
+    let mut delete_sql =
+        String::from("DELETE FROM document_tags WHERE document_id = ? AND source = 'hashtag'");
+    if !tag_ids.is_empty() {
+        delete_sql.push_str(&format!(
+            " AND tag_id NOT IN ({})",
+            vec!["?"; tag_ids.len()].join(", ")
+        ));
+    }
+    let mut delete_query = sqlx::query(&delete_sql).bind(document_id);
+    for id in &tag_ids {
+        delete_query = delete_query.bind(id);
+    }
+    delete_query.execute(&mut *tx).await?;
+
+    for id in &tag_ids {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO document_tags (document_id, tag_id, created_at, source)
+            VALUES (?, ?, strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), 'hashtag')
+            "#,
+        )
+        .bind(document_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
 /// 특정 문서에 연결된 모든 태그를 조회합니다.
 ///
 /// `document_tags` 중간 테이블을 JOIN하여 문서에 속한 태그 목록을 가져옵니다.
@@ -177,10 +506,10 @@ pub async fn remove_tag_from_document(
 /// tags ←── document_tags ──→ documents
 ///  (1)         (N:M)           (1)
 /// ```
-pub async fn get_document_tags(pool: &SqlitePool, document_id: &str) -> Result<Vec<Tag>, AppError> {
-    let tags = sqlx::query_as::<_, Tag>(
+pub async fn get_document_tags(pool: &SqlitePool, document_id: &str) -> Result<Vec<DocumentTag>, AppError> {
+    let tags = sqlx::query_as::<_, DocumentTag>(
         r#"
-        SELECT t.id, t.name, t.color
+        SELECT t.id, t.name, t.color, t.parent_id, dt.source
         FROM tags t
         JOIN document_tags dt ON dt.tag_id = t.id
         WHERE dt.document_id = ?
@@ -1,12 +1,13 @@
-use crate::models::{DocumentVersion, DocumentVersionSummary};
+use super::SearchPage;
+use crate::models::{DocumentVersion, DocumentVersionSummary, VersionSearchRow};
+use crate::services::{FtsQuery, TextStats};
 use sqlx::SqlitePool;
 
 pub async fn create_version(
     pool: &SqlitePool,
     document_id: &str,
     content: &str,
-    word_count: i64,
-    char_count: i64,
+    stats: &TextStats,
 ) -> Result<(), sqlx::Error> {
     let id = uuid::Uuid::now_v7().to_string();
 
@@ -23,21 +24,30 @@ pub async fn create_version(
     .fetch_one(&mut *tx)
     .await?;
 
-    sqlx::query(
+    let result = sqlx::query(
         r#"
-        INSERT INTO document_versions (id, document_id, version_number, content, word_count, char_count)
-        VALUES (?, ?, ?, ?, ?, ?)
+        INSERT INTO document_versions (id, document_id, version_number, content, word_count, char_count, char_count_no_spaces, manuscript_pages)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(&id)
     .bind(document_id)
     .bind(next_version)
     .bind(content)
-    .bind(word_count)
-    .bind(char_count)
+    .bind(stats.words as i64)
+    .bind(stats.chars as i64)
+    .bind(stats.chars_no_spaces as i64)
+    .bind(stats.manuscript_pages as i64)
     .execute(&mut *tx)
     .await?;
 
+    // 버전 검색 인덱스에도 같은 rowid로 넣습니다 (같은 트랜잭션).
+    sqlx::query("INSERT INTO document_versions_fts(rowid, content) VALUES(?, ?)")
+        .bind(result.last_insert_rowid())
+        .bind(content)
+        .execute(&mut *tx)
+        .await?;
+
     tx.commit().await?;
     Ok(())
 }
@@ -48,7 +58,7 @@ pub async fn list_versions(
 ) -> Result<Vec<DocumentVersionSummary>, sqlx::Error> {
     let versions = sqlx::query_as::<_, DocumentVersionSummary>(
         r#"
-        SELECT id, document_id, version_number, word_count, char_count, created_at
+        SELECT id, document_id, version_number, word_count, char_count, char_count_no_spaces, manuscript_pages, created_at
         FROM document_versions
         WHERE document_id = ?
         ORDER BY version_number DESC
@@ -67,7 +77,7 @@ pub async fn get_version(
 ) -> Result<Option<DocumentVersion>, sqlx::Error> {
     let version = sqlx::query_as::<_, DocumentVersion>(
         r#"
-        SELECT id, document_id, version_number, content, word_count, char_count, created_at
+        SELECT id, document_id, version_number, content, word_count, char_count, char_count_no_spaces, manuscript_pages, created_at
         FROM document_versions
         WHERE id = ?
         "#,
@@ -126,27 +136,118 @@ pub async fn needs_version_snapshot(
     Ok(result.is_some())
 }
 
+/// 문서당 최신 `max_versions`개만 남기고 오래된 버전을 삭제합니다.
+///
+/// 삭제할 버전의 검색 인덱스 행을 먼저 지운 뒤 버전을 지웁니다 (같은 트랜잭션).
 pub async fn prune_versions(
     pool: &SqlitePool,
     document_id: &str,
     max_versions: u32,
 ) -> Result<(), sqlx::Error> {
-    sqlx::query(
-        r#"
-        DELETE FROM document_versions
+    // 남길 버전을 제외한 나머지 (두 쿼리가 같은 조건을 사용)
+    const PRUNED: &str = r#"
+        SELECT rowid FROM document_versions
         WHERE document_id = ? AND id NOT IN (
             SELECT id FROM document_versions
             WHERE document_id = ?
             ORDER BY version_number DESC
             LIMIT ?
         )
-        "#,
-    )
+    "#;
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(&format!(
+        "DELETE FROM document_versions_fts WHERE rowid IN ({})",
+        PRUNED
+    ))
     .bind(document_id)
     .bind(document_id)
     .bind(max_versions)
-    .execute(pool)
+    .execute(&mut *tx)
     .await?;
 
+    sqlx::query(&format!(
+        "DELETE FROM document_versions WHERE rowid IN ({})",
+        PRUNED
+    ))
+    .bind(document_id)
+    .bind(document_id)
+    .bind(max_versions)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
     Ok(())
 }
+
+/// 버전 본문을 전문검색하여 일치하는 버전 목록과 전체 결과 수를 반환합니다.
+///
+/// `document_versions_fts`(trigram)를 사용하며, 검색어 조건은 `db::search_documents`와 같습니다.
+/// `document_id`가 있으면 그 문서의 버전만, 없으면 사용자의 모든 문서의 버전을 검색합니다 (휴지통의 문서는 제외).
+/// 결과는 최신 버전부터 정렬합니다 ("언제 썼는지"를 찾는 용도이므로 관련도순보다 시간순이 유용).
+pub async fn search_versions(
+    pool: &SqlitePool,
+    query: &FtsQuery,
+    user_id: &str,
+    document_id: Option<&str>,
+    page: SearchPage,
+) -> Result<(Vec<VersionSearchRow>, i64), sqlx::Error> {
+    let mut conditions = String::from(" WHERE d.user_id = ? AND d.deleted_at IS NULL");
+    let mut bindings: Vec<String> = vec![user_id.to_string()];
+
+    if let Some(document_id) = document_id {
+        conditions.push_str(" AND v.document_id = ?");
+        bindings.push(document_id.to_string());
+    }
+    if let Some(expr) = &query.match_expr {
+        conditions.push_str(" AND document_versions_fts MATCH ?");
+        bindings.push(expr.clone());
+    }
+    if let Some(expr) = &query.exclude_expr {
+        conditions.push_str(
+            " AND v.rowid NOT IN (SELECT rowid FROM document_versions_fts WHERE document_versions_fts MATCH ?)",
+        );
+        bindings.push(expr.clone());
+    }
+    // 버전 검색에는 `title:` 검색어가 없으므로(`services::build_content_query`) 본문 컬럼만 검사합니다.
+    for like in &query.like_conditions {
+        let alternatives = vec!["document_versions_fts.content LIKE ? ESCAPE '\\'"; like.patterns.len()];
+        bindings.extend(like.patterns.iter().cloned());
+        conditions.push_str(if like.negated { " AND NOT (" } else { " AND (" });
+        conditions.push_str(&alternatives.join(" OR "));
+        conditions.push(')');
+    }
+
+    let from = r#"
+        FROM document_versions_fts
+        JOIN document_versions v ON v.rowid = document_versions_fts.rowid
+        JOIN documents d ON d.id = v.document_id
+    "#;
+
+    let count_sql = format!("SELECT COUNT(*){}{}", from, conditions);
+    let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+    for binding in &bindings {
+        count_query = count_query.bind(binding);
+    }
+    let total = count_query.fetch_one(pool).await?;
+
+    let sql = format!(
+        r#"
+        SELECT v.id, v.document_id, v.version_number, v.word_count, v.char_count,
+               v.char_count_no_spaces, v.manuscript_pages, v.created_at,
+               d.title AS document_title, v.content
+        {}{}
+        ORDER BY v.created_at DESC, v.id DESC
+        LIMIT {} OFFSET {}
+        "#,
+        from, conditions, page.limit, page.offset
+    );
+    let mut q = sqlx::query_as::<_, VersionSearchRow>(&sql);
+    for binding in &bindings {
+        q = q.bind(binding);
+    }
+    let rows = q.fetch_all(pool).await?;
+
+    Ok((rows, total))
+}
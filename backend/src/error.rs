@@ -64,6 +64,10 @@ pub enum AppError {
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
 
+    /// 권한 없음 (HTTP 403): 로그인은 했지만 허용되지 않은 작업
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     /// 리소스 충돌 (HTTP 409)
     #[error("Conflict: {0}")]
     Conflict(String),
@@ -126,6 +130,9 @@ impl IntoResponse for AppError {
             AppError::Unauthorized(ref msg) => {
                 (StatusCode::UNAUTHORIZED, "unauthorized", msg.clone())
             }
+            AppError::Forbidden(ref msg) => {
+                (StatusCode::FORBIDDEN, "forbidden", msg.clone())
+            }
             AppError::Conflict(ref msg) => {
                 (StatusCode::CONFLICT, "conflict", msg.clone())
             }
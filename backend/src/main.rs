@@ -11,6 +11,12 @@
 //! 5. 파일 저장 디렉토리 생성
 //! 6. API 라우터 설정
 //! 7. HTTP 서버 시작
+//!
+//! ## 관리 명령 (CLI)
+//! 인자를 주면 서버를 시작하지 않고 관리 명령을 실행한 뒤 종료합니다.
+//! - `tecindo reindex`: 모든 문서의 검색 인덱스를 .md 파일에서 다시 만듭니다.
+//! - `tecindo check-index`: 검색 인덱스가 DB/파일과 어긋났는지 검사합니다 (문제가 있으면 종료 코드 1).
+//! - `tecindo sync-metadata`: 모든 문서의 YAML front matter를 다시 읽어 메타데이터를 맞춥니다.
 
 // ── 모듈 선언 ──
 // `mod` 키워드는 다른 파일을 모듈로 가져옵니다.
@@ -30,7 +36,7 @@ mod services;
 use anyhow::Result; // anyhow::Result: 어떤 에러 타입이든 담을 수 있는 범용 Result 타입
 use axum::{
     // Axum: Rust의 비동기 웹 프레임워크. Express.js와 비슷한 역할
-    routing::{get, patch, post, put, delete}, // HTTP 메서드별 라우팅 함수들
+    routing::{get, patch, post, delete}, // HTTP 메서드별 라우팅 함수들
     Router,                                    // 라우터: URL 경로와 핸들러를 연결하는 구조체
 };
 use config::Config; // 우리가 만든 설정 모듈
@@ -123,6 +129,20 @@ async fn main() -> Result<()> {
         tracing::info!("Created uploads directory: {}", config.uploads_path);
     }
 
+    // 관리 명령이 주어졌으면 실행하고 종료합니다 (서버는 시작하지 않음).
+    // std::env::args(): 프로그램 인자 목록. 첫 번째(nth(0))는 실행 파일 경로입니다.
+    if let Some(command) = std::env::args().nth(1) {
+        return run_command(&command, &pool, &config.documents_path).await;
+    }
+
+    // 검색 인덱스에 빠진 문서를 색인합니다 (인덱스 재구성 직후 등).
+    // 실패해도 서버는 계속 시작합니다 — 검색 결과에서만 빠질 뿐 문서 데이터는 안전합니다.
+    match db::index_missing_documents(&pool, &config.documents_path).await {
+        Ok(0) => {}
+        Ok(n) => tracing::info!("Indexed {} documents missing from the search index", n),
+        Err(e) => tracing::warn!("Failed to index missing documents: {}", e),
+    }
+
     // ── 7단계: 애플리케이션 상태(State) 생성 ──
     // AppState: 모든 라우트 핸들러가 공유하는 데이터를 담는 구조체
     // Axum에서는 State를 통해 핸들러에 의존성을 주입합니다.
@@ -135,6 +155,9 @@ async fn main() -> Result<()> {
         jwt_secret: config.jwt_secret.clone(),
         max_document_versions: config.max_document_versions,
         version_interval_minutes: config.version_interval_minutes,
+        // 빠른 열기 인덱스는 비어 있는 상태로 시작하여 사용자가 처음 검색할 때 채워집니다.
+        quick_open: Default::default(),
+        admin_usernames: config.admin_usernames.clone(),
     };
 
     // ── 8단계: API 라우터 설정 ──
@@ -157,27 +180,74 @@ async fn main() -> Result<()> {
         // 문서(Document) CRUD API
         // .post()를 .route()에 체이닝하면 같은 경로에 여러 HTTP 메서드를 매핑할 수 있습니다.
         .route("/documents", get(list_documents).post(create_document))
+        // 빠른 열기(Ctrl+P): 고정 경로가 `{id}`보다 우선합니다.
+        .route("/documents/quick-open", get(quick_open))
+        // 일괄 작업: quick-open과 같이 고정 경로이므로 `{id}`로 해석되지 않습니다.
+        .route("/documents/bulk", post(bulk_documents))
         // {id}는 URL 경로 파라미터 (Path<String>으로 핸들러에서 추출)
         .route("/documents/{id}", get(get_document).patch(update_document).delete(delete_document))
         .route("/documents/{id}/content", get(get_document_content).put(update_document_content))
+        .route("/documents/{id}/stats", get(get_document_stats))
+        .route("/documents/{id}/duplicate", post(duplicate_document))
         .route("/documents/{id}/export/pdf", get(export_document_pdf))
+        // 휴지통: DELETE /documents/{id}로 옮긴 문서를 복원하거나 영구 삭제합니다.
+        .route("/trash", get(list_trash).delete(empty_trash))
+        .route("/trash/{id}", delete(purge_document))
+        .route("/trash/{id}/restore", post(restore_document))
+        // 헤딩 개요 및 섹션 단위 API
+        .route("/documents/{id}/outline", get(get_document_outline))
+        .route("/documents/{id}/sections/{anchor}", get(get_document_section).put(update_document_section))
+        // 문서 간 위키 링크([[문서 제목]]) API
+        .route("/documents/{id}/backlinks", get(get_document_backlinks))
+        .route("/documents/{id}/outgoing-links", get(get_document_outgoing_links))
+        // 문서 메타데이터(YAML front matter) API
+        .route("/documents/{id}/metadata", get(get_document_metadata).patch(update_document_metadata))
+        // 문서 상태 워크플로 API: 상태는 PATCH /documents/{id}로 바꾸고, 이력은 여기서 조회합니다.
+        .route("/documents/{id}/status-history", get(get_document_status_history))
+        .route("/statuses", get(list_document_statuses).put(update_document_statuses))
+        .route("/statuses/stats", get(get_status_stats))
         // 폴더(Folder) CRUD API
         .route("/folders", get(list_folders).post(create_folder))
         .route("/folders/{id}", patch(update_folder).delete(delete_folder))
+        .route("/folders/{id}/stats", get(get_folder_stats))
         // 태그(Tag) CRUD API
         .route("/tags", get(list_tags).post(create_tag))
         .route("/tags/{id}", patch(update_tag).delete(delete_tag))
+        .route("/tags/{id}/stats", get(get_tag_stats))
+        .route("/tags/{id}/merge", post(merge_tags))
         // 문서-태그 관계 API
         .route("/documents/{id}/tags", get(get_document_tags).post(add_tag_to_document))
         .route("/documents/{id}/tags/{tag_id}", delete(remove_tag_from_document))
         // 전문검색(FTS5) API
         .route("/search", get(search))
+        .route("/search/index/rebuild", post(rebuild_index))
+        .route("/search/index/check", get(check_index))
+        // 저장된 검색(스마트 폴더) API
+        .route("/saved-searches", get(list_saved_searches).post(create_saved_search))
+        .route(
+            "/saved-searches/{id}",
+            get(get_saved_search).patch(update_saved_search).delete(delete_saved_search),
+        )
+        .route("/saved-searches/{id}/documents", get(get_saved_search_documents))
+        // 문서 그래프(지식 그래프) API
+        .route("/graph", get(get_graph))
         // 글쓰기 세션 API
         .route("/documents/{id}/sessions", get(list_document_sessions).post(create_writing_session))
         .route("/sessions/{id}", patch(end_writing_session))
+        // 글쓰기 목표 API: 진행률은 세션과 문서 단어 수로 계산합니다.
+        .route("/goals", get(get_writing_goals).put(update_writing_goals))
+        .route("/goals/progress", get(get_goal_progress))
+        .route("/goals/projects", get(list_project_goals).post(create_project_goal))
+        .route("/goals/projects/{id}", patch(update_project_goal).delete(delete_project_goal))
         // 문서 버전 히스토리 API
         .route("/documents/{id}/versions", get(list_document_versions).post(create_version_snapshot))
+        .route("/documents/{id}/versions/search", get(search_document_versions))
+        .route("/versions/search", get(search_all_versions))
         .route("/versions/{id}", get(get_version_content))
+        // 일지(데일리 노트) API: `/journal/settings`는 고정 경로라 `{date}`보다 우선합니다.
+        .route("/journal", get(list_journal_days))
+        .route("/journal/settings", get(get_journal_settings).put(update_journal_settings))
+        .route("/journal/{date}", get(get_journal_entry))
         // 헬스체크 API (서버 상태 확인용)
         .route("/health", get(health_check))
         // .with_state(): 이 라우터의 모든 핸들러에서 AppState를 사용할 수 있게 합니다.
@@ -241,3 +311,33 @@ async fn main() -> Result<()> {
     // Ok(()): 성공을 나타내는 Result 값. ()는 "빈 값"(unit 타입)입니다.
     Ok(())
 }
+
+/// CLI 관리 명령을 실행합니다. 결과는 JSON으로 표준 출력에 씁니다.
+async fn run_command(command: &str, pool: &sqlx::SqlitePool, documents_path: &str) -> Result<()> {
+    match command {
+        "reindex" => {
+            let report = db::rebuild_search_index(pool, documents_path, None).await?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            if !report.failed.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        "check-index" => {
+            let report = db::check_search_index(pool, documents_path, None).await?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            if !report.is_healthy() {
+                std::process::exit(1);
+            }
+        }
+        "sync-metadata" => {
+            let report = db::sync_all_document_metadata(pool, documents_path).await?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            if !report.failed.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        // anyhow::bail!: 에러 메시지를 담은 Err를 즉시 반환합니다.
+        _ => anyhow::bail!("Unknown command: {} (available: reindex, check-index, sync-metadata)", command),
+    }
+    Ok(())
+}
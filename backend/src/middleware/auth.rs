@@ -50,6 +50,7 @@ impl FromRequestParts<AppState> for AuthUser {
 }
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum AuthError {
     MissingToken,
     InvalidToken,
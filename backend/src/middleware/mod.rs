@@ -1,3 +1,4 @@
 pub mod auth;
 
+#[allow(unused_imports)] // 외부에서는 `middleware::auth::AuthUser`처럼 전체 경로로 사용 중
 pub use auth::*;
@@ -1,3 +1,4 @@
+use super::{MetadataCondition, TagMatchMode};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -9,13 +10,34 @@ pub struct Document {
     pub file_path: String,
     pub word_count: i64,
     pub char_count: i64,
+    /// 공백 제외 글자 수
+    pub char_count_no_spaces: i64,
+    /// 200자 원고지 매수
+    pub manuscript_pages: i64,
     pub excerpt: Option<String>,
     pub is_pinned: i64,
     pub is_archived: i64,
+    /// 템플릿 문서 여부 (`POST /documents`의 `template_id`로 쓸 수 있음)
+    pub is_template: i64,
+    /// 상태 워크플로의 현재 상태 key (예: `draft`). None이면 상태 없음
+    pub status: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+/// 휴지통의 문서 (`GET /api/v1/trash`)
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct TrashedDocument {
+    pub id: String,
+    /// 원래 있던 폴더. 휴지통에 있는 동안 폴더가 삭제되면 None (복원하면 루트로 돌아감)
+    pub folder_id: Option<String>,
+    pub title: String,
+    pub word_count: i64,
+    pub updated_at: String,
+    /// 휴지통으로 옮긴 시각
+    pub deleted_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Folder {
     pub id: String,
@@ -27,10 +49,70 @@ pub struct Folder {
     pub updated_at: String,
 }
 
+/// 폴더에 속한 문서들의 통계 합계
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct FolderStats {
+    pub document_count: i64,
+    pub word_count: i64,
+    pub char_count: i64,
+    pub char_count_no_spaces: i64,
+    pub manuscript_pages: i64,
+}
+
+/// 문서 목록 정렬 기준 (`GET /documents?sort=`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentSort {
+    /// 제목 가나다순 (기본 오름차순)
+    Title,
+    /// 생성일 (기본 내림차순)
+    Created,
+    /// 수정일 (기본 내림차순)
+    Updated,
+    /// 단어 수 (기본 내림차순)
+    Words,
+}
+
+/// 정렬 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// 문서 목록 필터와 정렬 조건
+///
+/// 라우트가 쿼리 파라미터를 해석하고, 폴더/태그를 하위 항목까지 펼친 뒤 `db::list_documents`에 넘깁니다.
+/// 지정한 조건은 모두 만족해야 합니다 (AND).
+#[derive(Debug, Clone, Default)]
+pub struct DocumentListFilters {
+    /// 이 폴더들 중 하나에 속한 문서만. None이면 폴더와 무관
+    pub folder_ids: Option<Vec<String>>,
+    /// 폴더에 속하지 않은(루트) 문서만
+    pub root_only: bool,
+    /// 태그마다 그 태그와 하위 태그 ID 목록 (`db::search_documents`의 `tag_groups`와 같음)
+    pub tag_groups: Vec<Vec<String>>,
+    pub tag_mode: TagMatchMode,
+    pub archived: Option<bool>,
+    pub pinned: Option<bool>,
+    pub template: Option<bool>,
+    /// 이 상태들 중 하나인 문서만. `none`은 상태가 없는 문서
+    pub statuses: Vec<String>,
+    /// front matter 메타데이터 조건 (모두 만족)
+    pub metadata: Vec<MetadataCondition>,
+    /// None이면 기존 순서 (고정 문서 먼저, 최근 수정순)
+    pub sort: Option<DocumentSort>,
+    /// None이면 정렬 기준별 기본 방향
+    pub order: Option<SortOrder>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateDocumentRequest {
     pub title: Option<String>,
     pub folder_id: Option<String>,
+    /// 본문의 틀로 쓸 템플릿 문서 ID. 자리표시자(`{{date}}` 등)를 치환한 내용으로 시작합니다.
+    pub template_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -40,6 +122,71 @@ pub struct UpdateDocumentRequest {
     pub folder_id: Option<Option<String>>,
     pub is_pinned: Option<bool>,
     pub is_archived: Option<bool>,
+    pub is_template: Option<bool>,
+    /// 상태 변경: 필드 누락 = 변경 안 함, null = 상태 지움, key = 그 상태로 변경 (이력에 기록)
+    #[serde(default, deserialize_with = "super::tag::nullable")]
+    pub status: Option<Option<String>>,
+    /// 제목 변경 시 이 문서를 가리키는 `[[위키 링크]]`를 새 제목으로 다시 쓸지 여부 (기본값: false)
+    pub rewrite_links: Option<bool>,
+}
+
+/// `POST /documents/bulk` 요청 본문
+///
+/// 예: `{ "ids": ["...", "..."], "action": "move", "folder_id": "..." }`
+/// `action`과 그 매개변수는 같은 단계에 씁니다 (`#[serde(flatten)]`).
+#[derive(Debug, Deserialize)]
+pub struct BulkDocumentsRequest {
+    pub ids: Vec<String>,
+    #[serde(flatten)]
+    pub action: BulkDocumentAction,
+}
+
+/// 여러 문서에 한 번에 적용할 작업
+///
+/// `#[serde(tag = "action")]`: `"action"` 필드의 값으로 어떤 변형(variant)인지 구분합니다.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum BulkDocumentAction {
+    /// 폴더로 이동. `folder_id`가 없거나 null이면 루트로 이동합니다.
+    Move { folder_id: Option<String> },
+    AddTags { tag_ids: Vec<String> },
+    RemoveTags { tag_ids: Vec<String> },
+    Pin,
+    Unpin,
+    Archive,
+    Unarchive,
+    /// 상태 변경. `status`가 없거나 null이면 상태를 지웁니다. 상태가 실제로 바뀐 문서만 이력에 남습니다.
+    SetStatus { status: Option<String> },
+    /// 휴지통으로 이동 (`DELETE /documents/:id`와 같음). 영구 삭제는 `DELETE /trash`로 합니다
+    Trash,
+    /// 마크다운 내용을 응답에 담아 반환 (DB는 바꾸지 않음)
+    Export,
+}
+
+/// 일괄 작업의 문서별 결과
+#[derive(Debug, Serialize)]
+pub struct BulkItemResult {
+    pub id: String,
+    pub ok: bool,
+    /// 실패 사유 (`ok`가 false일 때만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 내보낸 문서의 제목 (`export`일 때만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 내보낸 마크다운 내용 (`export`일 때만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl BulkItemResult {
+    pub fn ok(id: &str) -> Self {
+        Self { id: id.to_string(), ok: true, error: None, title: None, content: None }
+    }
+
+    pub fn failed(id: &str, error: impl Into<String>) -> Self {
+        Self { id: id.to_string(), ok: false, error: Some(error.into()), title: None, content: None }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,85 @@
+//! # 글쓰기 목표 모델 정의
+//!
+//! 하루/주간 단어 수 목표와 프로젝트(폴더)별 목표 분량·마감일의 데이터 구조체들입니다.
+//!
+//! ## 구조체 역할
+//! - `WritingGoals`: 하루/주간 목표 (응답용, 적용 시작일 포함)
+//! - `UpdateWritingGoalsRequest`: 하루/주간 목표 교체 요청
+//! - `ProjectGoal`: 폴더의 목표 분량과 마감일
+//! - `CreateProjectGoalRequest`, `UpdateProjectGoalRequest`: 프로젝트 목표 생성/수정 요청
+//! - `DailyWords`: 날짜별로 쓴 단어 수 (글쓰기 세션 합계)
+//! - `GoalDay`: 하루 목표 달성 이력 한 건
+
+use serde::{Deserialize, Serialize};
+
+/// 하루/주간 목표 — DB의 `writing_goals` 테이블 한 행에 대응합니다.
+///
+/// 목표를 바꿀 때마다 그날부터 적용되는 행이 쌓이며, 현재 목표는 가장 최근 행입니다.
+/// 아직 정한 적이 없으면 모든 필드가 None입니다.
+#[derive(Debug, Clone, Default, Serialize, sqlx::FromRow)]
+pub struct WritingGoals {
+    /// 하루에 쓸 단어 수 (None이면 목표 없음)
+    pub daily_words: Option<i64>,
+    /// 한 주(월요일 시작)에 쓸 단어 수 (None이면 목표 없음)
+    pub weekly_words: Option<i64>,
+    /// 이 목표가 적용되기 시작한 날짜 (`YYYY-MM-DD`, UTC)
+    pub effective_from: Option<String>,
+}
+
+/// 하루/주간 목표 교체 요청 — `PUT /api/v1/goals`
+///
+/// 두 목표를 함께 교체합니다. 빠지거나 null인 목표는 "목표 없음"이 됩니다.
+#[derive(Debug, Deserialize)]
+pub struct UpdateWritingGoalsRequest {
+    pub daily_words: Option<i64>,
+    pub weekly_words: Option<i64>,
+}
+
+/// 프로젝트 목표 — DB의 `project_goals` 테이블 한 행에 대응합니다.
+///
+/// 폴더(와 하위 폴더)의 문서 단어 수 합계가 마감일까지 `target_words`에 이르는 것이 목표입니다.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct ProjectGoal {
+    pub id: String,
+    pub folder_id: String,
+    pub target_words: i64,
+    /// 마감일 (`YYYY-MM-DD`). None이면 마감 없음
+    pub deadline: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// 프로젝트 목표 생성 요청 — `POST /api/v1/goals/projects`
+#[derive(Debug, Deserialize)]
+pub struct CreateProjectGoalRequest {
+    pub folder_id: String,
+    pub target_words: i64,
+    pub deadline: Option<String>,
+}
+
+/// 프로젝트 목표 수정 요청 — `PATCH /api/v1/goals/projects/:id`
+#[derive(Debug, Deserialize)]
+pub struct UpdateProjectGoalRequest {
+    pub target_words: Option<i64>,
+    /// 마감일 변경: 필드 누락 = 변경 안 함, null = 마감 없앰, 날짜 = 그 날짜로 변경
+    #[serde(default, deserialize_with = "super::tag::nullable")]
+    pub deadline: Option<Option<String>>,
+}
+
+/// 날짜별로 쓴 단어 수 — 종료된 글쓰기 세션의 단어 수 증가분 합계
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct DailyWords {
+    /// `YYYY-MM-DD` (세션 시작 시각 기준, UTC)
+    pub date: String,
+    pub words: i64,
+}
+
+/// 하루 목표 달성 이력 한 건 (`GET /api/v1/goals/progress`의 `history`)
+#[derive(Debug, Clone, Serialize)]
+pub struct GoalDay {
+    pub date: String,
+    pub words: i64,
+    /// 그날 적용된 하루 목표
+    pub goal: i64,
+    pub met: bool,
+}
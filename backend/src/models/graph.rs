@@ -0,0 +1,81 @@
+//! # 문서 그래프 모델 정의
+//!
+//! `GET /graph` 응답에 사용하는 노드/엣지 구조체와,
+//! 그래프를 만들기 위해 DB에서 읽어오는 행(row) 구조체들입니다.
+
+use serde::Serialize;
+
+/// 그래프 노드의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphNodeKind {
+    Document,
+    Tag,
+    Folder,
+}
+
+/// 그래프 엣지의 종류
+///
+/// - `Link`: 문서 → 문서 (`[[위키 링크]]`)
+/// - `Tag`: 문서 → 태그 (태그 소속)
+/// - `Folder`: 폴더 → 문서 또는 폴더 → 하위 폴더 (폴더 포함 관계)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphEdgeKind {
+    Link,
+    Tag,
+    Folder,
+}
+
+/// 그래프 노드 하나
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    /// 원본 엔티티의 ID (문서/태그/폴더 ID, 모두 UUIDv7이라 서로 겹치지 않음)
+    pub id: String,
+    pub kind: GraphNodeKind,
+    /// 화면에 표시할 이름 (문서 제목, 태그 이름, 폴더 이름)
+    pub label: String,
+    /// 노드 크기 가중치: 문서는 단어 수, 태그/폴더는 포함된 문서 수
+    pub weight: i64,
+}
+
+/// 그래프 엣지 하나 (방향 있음: source → target)
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+    pub kind: GraphEdgeKind,
+    /// 엣지 가중치: 링크는 링크 개수, 태그/폴더는 1
+    pub weight: i64,
+}
+
+/// `GET /graph` 응답 본문
+#[derive(Debug, Clone, Serialize)]
+pub struct Graph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// 그래프 계산용 문서 행 (본문 없이 메타데이터만)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct GraphDocumentRow {
+    pub id: String,
+    pub title: String,
+    pub folder_id: Option<String>,
+    pub word_count: i64,
+}
+
+/// 문서-태그 연결 한 건
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct GraphTagMembership {
+    pub document_id: String,
+    pub tag_id: String,
+}
+
+/// 대상 문서까지 해석된 위키 링크 한 건
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct GraphLinkRow {
+    pub source_id: String,
+    pub target_id: String,
+    pub link_count: i64,
+}
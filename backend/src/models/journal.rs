@@ -0,0 +1,58 @@
+//! # 일지(데일리 노트) 모델 정의
+//!
+//! 날짜마다 하나씩 만드는 일지 문서와 사용자별 일지 설정의 데이터 구조체들입니다.
+//!
+//! ## 구조체 역할
+//! - `JournalSettings`: 일지 폴더/템플릿/제목 형식 설정 (응답용)
+//! - `UpdateJournalSettingsRequest`: 일지 설정 교체 요청
+//! - `JournalDay`: 일지가 있는 날짜 하나 (목록 응답용)
+
+use serde::{Deserialize, Serialize};
+
+/// 일지 제목 형식의 기본값 (예: "2026-03-02")
+pub const DEFAULT_JOURNAL_TITLE_FORMAT: &str = "{{date}}";
+
+/// 사용자별 일지 설정 — DB의 `journal_settings` 테이블 한 행에 대응합니다.
+///
+/// 아직 저장한 적이 없으면 기본값(루트 폴더, 템플릿 없음, 제목 `{{date}}`)을 씁니다.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct JournalSettings {
+    /// 일지를 만들 폴더 (None이면 루트)
+    pub folder_id: Option<String>,
+    /// 새 일지의 본문 틀로 쓸 템플릿 문서 (None이면 빈 문서)
+    pub template_id: Option<String>,
+    /// 새 일지의 제목 형식. 템플릿과 같은 자리표시자(`{{date}}`, `{{weekday}}` 등)를 씁니다.
+    pub title_format: String,
+}
+
+impl Default for JournalSettings {
+    fn default() -> Self {
+        Self {
+            folder_id: None,
+            template_id: None,
+            title_format: DEFAULT_JOURNAL_TITLE_FORMAT.to_string(),
+        }
+    }
+}
+
+/// 일지 설정 교체 요청 — `PUT /api/v1/journal/settings`
+///
+/// 설정 전체를 교체합니다. 빠진 필드는 기본값이 됩니다.
+#[derive(Debug, Deserialize)]
+pub struct UpdateJournalSettingsRequest {
+    pub folder_id: Option<String>,
+    pub template_id: Option<String>,
+    pub title_format: Option<String>,
+}
+
+/// 일지가 있는 날짜 하나 — `GET /api/v1/journal` 응답 항목
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct JournalDay {
+    /// `YYYY-MM-DD`
+    pub date: String,
+    pub document_id: String,
+    pub title: String,
+    pub word_count: i64,
+    pub char_count: i64,
+    pub updated_at: String,
+}
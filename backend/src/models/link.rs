@@ -0,0 +1,53 @@
+//! # 위키 링크 모델 정의
+//!
+//! 문서 간 `[[위키 링크]]` 조회 응답에 사용하는 구조체들입니다.
+//! - `Backlink`: 현재 문서를 가리키는 다른 문서 (`GET /documents/:id/backlinks`)
+//! - `OutgoingLink`: 현재 문서가 가리키는 대상 (`GET /documents/:id/outgoing-links`)
+//! - `LinkRewriteReport`: 제목 변경 시 링크 다시 쓰기 결과 (`PATCH /documents/:id`의 `rewrite_links`)
+
+use serde::{Deserialize, Serialize};
+
+/// 현재 문서로 링크를 건 문서 한 건
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Backlink {
+    /// 링크를 건 문서의 ID
+    pub id: String,
+    pub title: String,
+    pub slug: String,
+    pub folder_id: Option<String>,
+    pub updated_at: String,
+    /// 해당 문서 안에서 현재 문서를 가리키는 링크 개수
+    pub link_count: i64,
+}
+
+/// 현재 문서에서 나가는 링크 한 건
+///
+/// 대상 문서가 아직 없으면 `document_id`, `document_title`이 None입니다 (미해결 링크).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct OutgoingLink {
+    pub target_title: String,
+    pub target_slug: String,
+    pub link_count: i64,
+    /// slug로 해석된 대상 문서 ID
+    pub document_id: Option<String>,
+    /// 대상 문서의 현재 제목
+    pub document_title: Option<String>,
+}
+
+/// 링크 다시 쓰기 결과
+///
+/// 문서 하나를 다시 쓰지 못해도 나머지 문서는 계속 다시 쓰며, 실패한 문서는 `failed`에 담습니다.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LinkRewriteReport {
+    /// 실제로 내용이 바뀐 문서 수
+    pub rewritten: usize,
+    pub failed: Vec<LinkRewriteFailure>,
+}
+
+/// 링크를 다시 쓰지 못한 문서 한 건
+#[derive(Debug, Clone, Serialize)]
+pub struct LinkRewriteFailure {
+    pub id: String,
+    pub title: String,
+    pub error: String,
+}
@@ -0,0 +1,52 @@
+//! # 문서 메타데이터(YAML front matter) 모델 정의
+//!
+//! front matter에서 읽은 키/값 메타데이터로 문서를 거르는 조건입니다.
+//! 메타데이터 값 자체는 `serde_json::Map<String, Value>`로 주고받습니다.
+//! - `MetadataCondition`: 메타데이터 필터 조건 하나
+//! - `MetadataSyncReport`: 모든 문서의 front matter를 다시 읽은 결과 (CLI `sync-metadata`)
+
+use serde::{Deserialize, Serialize};
+
+/// 메타데이터 필터 조건 하나
+///
+/// 쿼리 파라미터로는 `meta=status:초고,pov:민지,due`처럼 씁니다.
+/// - `key:value`: 키의 값이 일치하는 문서 (목록 값이면 항목 중 하나가 일치)
+/// - `key`: 키가 있는 문서
+///
+/// 조건이 여러 개면 모두 만족해야 합니다 (AND).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataCondition {
+    pub key: String,
+    /// None이면 키가 있는지만 봅니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl MetadataCondition {
+    /// `status:초고,pov:민지,due` 형식의 문자열을 조건 목록으로 나눕니다.
+    ///
+    /// 값은 첫 `:` 뒤 전체이므로 `time:09:30`도 됩니다. 값에는 `,`를 쓸 수 없습니다.
+    /// 키가 빈 항목은 버립니다.
+    pub fn parse_list(raw: &str) -> Vec<Self> {
+        raw.split(',')
+            .filter_map(|item| {
+                let (key, value) = match item.split_once(':') {
+                    Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+                    None => (item.trim(), None),
+                };
+                (!key.is_empty()).then(|| MetadataCondition { key: key.to_string(), value })
+            })
+            .collect()
+    }
+}
+
+/// 모든 문서의 front matter를 다시 읽어 메타데이터를 맞춘 결과 (CLI `sync-metadata`)
+#[derive(Debug, Default, Serialize)]
+pub struct MetadataSyncReport {
+    /// 메타데이터를 다시 저장한 문서 수 (front matter가 없어 비운 문서 포함)
+    pub synced: usize,
+    /// front matter의 YAML이 깨져 있어 건너뛴 문서 ID (기존 메타데이터는 그대로 남음)
+    pub invalid: Vec<String>,
+    /// 파일을 읽지 못했거나 저장에 실패한 문서 ID
+    pub failed: Vec<String>,
+}
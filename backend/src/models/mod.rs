@@ -3,9 +3,20 @@
 //! 애플리케이션에서 사용하는 데이터 구조체(struct)들을 정의합니다.
 //! 각 하위 모듈은 특정 도메인의 데이터 타입을 담당합니다:
 //! - `document`: 문서(Document)와 폴더(Folder) 관련 구조체
+//! - `goal`: 글쓰기 목표(하루/주간 목표, 프로젝트 목표와 마감일) 관련 구조체
+//! - `graph`: 문서 그래프(노드/엣지) 관련 구조체
+//! - `journal`: 일지(데일리 노트) 설정과 날짜별 일지 관련 구조체
+//! - `link`: 문서 간 위키 링크(백링크/나가는 링크) 관련 구조체
+//! - `metadata`: YAML front matter 메타데이터 필터 조건
+//! - `outline`: 헤딩 기반 문서 개요와 섹션 관련 구조체
+//! - `quick_open`: 빠른 열기(Ctrl+P) 인덱스 항목과 결과 관련 구조체
+//! - `saved_search`: 저장된 검색(스마트 폴더) 관련 구조체
+//! - `search`: 전문검색 결과(하이라이트, 스니펫, 일치 위치) 관련 구조체
 //! - `session`: 글쓰기 세션 관련 구조체
+//! - `status`: 문서 상태 워크플로(상태 목록, 상태 변경 이력) 관련 구조체
 //! - `tag`: 태그 관련 구조체
 //! - `user`: 사용자(User) 관련 구조체
+//! - `version`: 문서 버전 히스토리와 버전 검색 결과 관련 구조체
 //!
 //! `pub use X::*;`는 하위 모듈의 모든 공개 항목을
 //! 이 모듈에서 바로 접근할 수 있게 재공개(re-export)합니다.
@@ -14,7 +25,17 @@
 // pub mod: 하위 모듈을 공개(public)로 선언합니다.
 // pub이 없으면 이 모듈 내부에서만 접근 가능합니다.
 pub mod document;
+pub mod goal;
+pub mod graph;
+pub mod journal;
+pub mod link;
+pub mod metadata;
+pub mod outline;
+pub mod quick_open;
+pub mod saved_search;
+pub mod search;
 pub mod session;
+pub mod status;
 pub mod tag;
 pub mod user;
 pub mod version;
@@ -23,7 +44,18 @@ pub mod version;
 // `*`(glob)는 모든 공개 항목을 의미합니다.
 // 이렇게 하면 사용하는 쪽에서 `models::Document`처럼 짧게 쓸 수 있습니다.
 pub use document::*;
+pub use goal::*;
+pub use graph::*;
+pub use journal::*;
+pub use link::*;
+pub use metadata::*;
+pub use outline::*;
+pub use quick_open::*;
+pub use saved_search::*;
+pub use search::*;
 pub use session::*;
+pub use status::*;
 pub use tag::*;
+#[allow(unused_imports)] // db/users.rs는 `models::user::User` 전체 경로를 사용
 pub use user::*;
 pub use version::*;
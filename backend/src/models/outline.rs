@@ -0,0 +1,43 @@
+//! # 문서 개요(Outline) 모델 정의
+//!
+//! 마크다운 헤딩으로 만든 문서 개요와 섹션 단위 조회 응답 구조체입니다.
+//! - `OutlineHeading`: 헤딩 하나와 그 섹션의 위치/단어 수 (`GET /documents/:id/outline`)
+//! - `DocumentSection`: 섹션 하나의 내용 (`GET /documents/:id/sections/:anchor`)
+
+use serde::Serialize;
+
+/// 문서 개요의 헤딩 하나
+///
+/// 섹션은 헤딩부터 같은 레벨 이하(숫자가 같거나 작은)의 다음 헤딩 직전까지입니다.
+/// 바이트 오프셋은 UTF-8 기준이며 `section_end`는 구간 끝(미포함)입니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct OutlineHeading {
+    /// 헤딩 레벨 (1~6, setext는 `=`가 1, `-`가 2)
+    pub level: u8,
+    /// 헤딩 텍스트 (`#`, 닫는 `#`, 앞뒤 공백 제외)
+    pub text: String,
+    /// 섹션 API에서 사용하는 앵커 (GitHub 방식, 중복 시 `-1`, `-2` 접미사)
+    pub anchor: String,
+    /// 헤딩이 시작하는 줄 번호 (1부터 시작)
+    pub line: usize,
+    /// 헤딩이 끝나는 줄 번호 (setext 헤딩은 밑줄 줄)
+    pub end_line: usize,
+    /// 헤딩 시작 바이트 오프셋
+    pub byte_start: usize,
+    /// 헤딩 본문(헤딩 줄 다음)이 시작하는 바이트 오프셋
+    pub body_start: usize,
+    /// 섹션이 끝나는 바이트 오프셋 (하위 섹션 포함)
+    pub section_end: usize,
+    /// 다음 헤딩(레벨 무관) 전까지 이 섹션 본문의 단어 수
+    pub word_count: usize,
+    /// 하위 섹션까지 포함한 단어 수
+    pub total_word_count: usize,
+}
+
+/// 섹션 하나의 내용
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentSection {
+    pub heading: OutlineHeading,
+    /// 헤딩 줄을 포함한 섹션 전체 마크다운 (하위 섹션 포함)
+    pub content: String,
+}
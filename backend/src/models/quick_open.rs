@@ -0,0 +1,30 @@
+//! # 빠른 열기(Quick Open) 모델 정의
+//!
+//! Ctrl+P 문서 전환기에서 사용하는 구조체들입니다.
+//! - `QuickOpenEntry`: 메모리 인덱스에 올려 두는 문서 한 건 (제목, 폴더 경로, 태그, 수정일)
+//! - `QuickOpenHit`: 검색 결과 한 건 (인덱스 항목 + 점수)
+
+use serde::Serialize;
+
+/// 빠른 열기 인덱스 항목
+#[derive(Debug, Clone, Serialize)]
+pub struct QuickOpenEntry {
+    pub id: String,
+    pub title: String,
+    pub folder_id: Option<String>,
+    /// 루트부터 이어 붙인 폴더 이름 경로 (예: "소설/단편"). 루트 문서는 빈 문자열
+    pub folder_path: String,
+    /// 태그 이름 목록 (이름순)
+    pub tags: Vec<String>,
+    pub is_archived: bool,
+    pub updated_at: String,
+}
+
+/// 빠른 열기 결과 한 건
+#[derive(Debug, Clone, Serialize)]
+pub struct QuickOpenHit {
+    #[serde(flatten)]
+    pub entry: QuickOpenEntry,
+    /// 일치 정도 + 최근 수정 가산점 (클수록 위에 표시)
+    pub score: f64,
+}
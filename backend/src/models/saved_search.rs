@@ -0,0 +1,51 @@
+//! # 저장된 검색(스마트 폴더) 모델 정의
+//!
+//! 검색어와 필터를 이름과 함께 저장한 "스마트 폴더"의 데이터 구조체들입니다.
+//! 결과 문서 목록은 저장하지 않고, 열 때마다 `GET /search`와 같은 방식으로 다시 검색합니다.
+//!
+//! ## 구조체 역할
+//! - `SavedSearch`: 저장된 검색 (응답용)
+//! - `CreateSavedSearchRequest`: 저장된 검색 생성 요청
+//! - `UpdateSavedSearchRequest`: 저장된 검색 수정 요청 (부분 업데이트)
+
+use super::SearchFilters;
+use serde::{Deserialize, Serialize};
+
+/// 저장된 검색 — DB의 `saved_searches` 테이블 한 행에 대응합니다.
+///
+/// DB에는 `filters`가 JSON 문자열로 저장되어 있으므로,
+/// `db::saved_searches`에서 읽을 때 `SearchFilters`로 변환합니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedSearch {
+    pub id: String,
+    /// 스마트 폴더 이름 (사용자별로 고유)
+    pub name: String,
+    /// 검색어 (`GET /search`의 `q`와 같은 문법, 빈 문자열이면 필터만 사용)
+    pub query: String,
+    /// 필터와 정렬 조건
+    pub filters: SearchFilters,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// 저장된 검색 생성 요청 — `POST /api/v1/saved-searches`
+///
+/// 예: `{ "name": "이번 주 챕터 초고", "query": "초고", "filters": { "tag_ids": ["..."], "updated_after": "-7d" } }`
+#[derive(Debug, Deserialize)]
+pub struct CreateSavedSearchRequest {
+    pub name: String,
+    #[serde(default)]
+    pub query: String,
+    #[serde(default)]
+    pub filters: SearchFilters,
+}
+
+/// 저장된 검색 수정 요청 — `PATCH /api/v1/saved-searches/:id`
+///
+/// 보낸 필드만 바뀝니다. `filters`는 부분 병합하지 않고 통째로 교체합니다.
+#[derive(Debug, Deserialize)]
+pub struct UpdateSavedSearchRequest {
+    pub name: Option<String>,
+    pub query: Option<String>,
+    pub filters: Option<SearchFilters>,
+}
@@ -0,0 +1,163 @@
+//! # 전문검색 모델 정의
+//!
+//! `GET /search` 응답과 검색 인덱스 관리에 사용하는 구조체들입니다.
+//! - `SearchRow`: DB에서 읽은 검색 결과 한 행 (문서 메타데이터 + 색인된 제목/본문)
+//! - `SearchHit`: 응답용 검색 결과 (문서 필드 + 하이라이트/스니펫/일치 정보)
+//! - `SearchFilters`: 검색어 외의 필터와 정렬 조건 (폴더, 태그, 상태, 날짜, 단어 수, 메타데이터)
+//! - `IndexRebuildReport`, `IndexCheckReport`: 검색 인덱스 재구성/검사 결과
+
+use super::{Document, MetadataCondition};
+use serde::{Deserialize, Serialize};
+
+/// 검색 결과 정렬 기준
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchSort {
+    /// 관련도순 (BM25). 3글자 이상 검색어가 없으면 최근 수정순
+    #[default]
+    Relevance,
+    /// 최근 수정순
+    Updated,
+    /// 제목 가나다순
+    Title,
+}
+
+/// 여러 태그를 지정했을 때의 일치 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatchMode {
+    /// 태그 중 하나라도 붙은 문서
+    #[default]
+    Any,
+    /// 모든 태그가 붙은 문서
+    All,
+}
+
+/// 검색 필터와 정렬 조건
+///
+/// 모든 필드가 선택 항목이며, 지정한 조건은 모두 만족해야 합니다 (AND).
+/// 날짜는 `YYYY-MM-DD` 또는 RFC 3339 형식이며, `*_after`는 이상(포함), `*_before`는 미만(제외)입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchFilters {
+    /// 이 폴더와 모든 하위 폴더의 문서만
+    pub folder_id: Option<String>,
+    /// 태그 ID 목록 (각 태그의 하위 태그가 붙은 문서도 일치)
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+    #[serde(default)]
+    pub tag_mode: TagMatchMode,
+    /// true: 아카이브된 문서만, false: 아카이브되지 않은 문서만, None: 모두
+    pub archived: Option<bool>,
+    /// true: 고정된 문서만, false: 고정되지 않은 문서만, None: 모두
+    pub pinned: Option<bool>,
+    pub created_after: Option<String>,
+    pub created_before: Option<String>,
+    pub updated_after: Option<String>,
+    pub updated_before: Option<String>,
+    /// 최소 단어 수
+    pub min_words: Option<i64>,
+    /// 이 상태들 중 하나인 문서만. `none`은 상태가 없는 문서
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<String>,
+    /// front matter 메타데이터 조건 (모두 만족)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metadata: Vec<MetadataCondition>,
+    #[serde(default)]
+    pub sort: SearchSort,
+}
+
+impl SearchFilters {
+    /// 정렬 외에 결과를 좁히는 조건이 하나라도 있는지 확인합니다.
+    pub fn has_conditions(&self) -> bool {
+        self.folder_id.is_some()
+            || !self.tag_ids.is_empty()
+            || self.archived.is_some()
+            || self.pinned.is_some()
+            || self.created_after.is_some()
+            || self.created_before.is_some()
+            || self.updated_after.is_some()
+            || self.updated_before.is_some()
+            || self.min_words.is_some()
+            || !self.statuses.is_empty()
+            || !self.metadata.is_empty()
+    }
+}
+
+/// 검색 쿼리 결과 한 행
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SearchRow {
+    /// `#[sqlx(flatten)]`: 같은 행의 컬럼들로 Document를 만듭니다.
+    #[sqlx(flatten)]
+    pub document: Document,
+    /// 색인 시점의 제목 (`documents_fts.title`)
+    pub fts_title: String,
+    /// 색인 시점의 본문 (`documents_fts.content`)
+    pub fts_content: String,
+}
+
+/// 검색어가 어디서 일치했는지
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchedIn {
+    Title,
+    Content,
+    Both,
+}
+
+/// 검색 결과 한 건
+///
+/// 문서 필드는 `#[serde(flatten)]`으로 펼쳐서 기존 응답(`Document` 배열)과 호환됩니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    #[serde(flatten)]
+    pub document: Document,
+    /// 일치한 부분을 `<mark>…</mark>`로 감싼 제목 (HTML 이스케이프됨)
+    pub title_highlight: String,
+    /// 본문에서 처음 일치한 부분 주변을 잘라 `<mark>`로 표시한 스니펫 (HTML 이스케이프됨)
+    /// 본문에서 일치하지 않았으면 None
+    pub snippet: Option<String>,
+    /// 제목과 본문에서 검색어가 나타난 총 횟수
+    pub match_count: usize,
+    pub matched_in: MatchedIn,
+}
+
+/// 검색 인덱스를 다시 만든 결과 (`POST /search/index/rebuild`, `tecindo reindex`)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IndexRebuildReport {
+    /// 다시 색인한 문서 수
+    pub indexed: usize,
+    /// 파일을 읽지 못했거나 색인에 실패한 문서 ID (기존 색인은 그대로 남음)
+    pub failed: Vec<String>,
+    /// 삭제된 문서를 가리키던 색인 행 중 지운 수
+    pub orphans_removed: u64,
+}
+
+/// 검색 인덱스 검사 결과 (`GET /search/index/check`, `tecindo check-index`)
+///
+/// 색인된 제목/본문을 DB의 제목과 .md 파일 내용과 비교하여 어긋난 문서를 찾습니다.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IndexCheckReport {
+    /// 검사한 문서 수
+    pub checked: usize,
+    /// 색인 행이 없는 문서 ID (검색 결과에 나오지 않음)
+    pub missing: Vec<String>,
+    /// 색인된 제목/본문이 현재와 다른 문서 ID (옛 내용으로 검색됨)
+    pub stale: Vec<String>,
+    /// .md 파일을 읽지 못해 본문을 비교하지 못한 문서 ID
+    pub unreadable: Vec<String>,
+    /// 삭제된 문서를 가리키는 색인 행 수
+    pub orphaned: i64,
+    /// FTS5 `integrity-check` 통과 여부 (인덱스 자료구조 손상 검사)
+    pub integrity_ok: bool,
+}
+
+impl IndexCheckReport {
+    /// 어긋난 곳이 하나도 없는지 확인합니다.
+    pub fn is_healthy(&self) -> bool {
+        self.integrity_ok
+            && self.missing.is_empty()
+            && self.stale.is_empty()
+            && self.unreadable.is_empty()
+            && self.orphaned == 0
+    }
+}
@@ -0,0 +1,74 @@
+//! # 문서 상태 워크플로 모델 정의
+//!
+//! 초고 → 퇴고 → 완성 → 발행처럼 문서가 어느 단계에 있는지 나타내는 상태와 그 이력입니다.
+//!
+//! ## 구조체 역할
+//! - `DocumentStatus`: 사용자 상태 목록의 상태 하나 (요청/응답 겸용)
+//! - `UpdateDocumentStatusesRequest`: 상태 목록 교체 요청
+//! - `StatusChange`: 문서의 상태 변경 이력 한 건
+//! - `StatusTotal`: 상태별 문서 수와 단어 수 합계 (통계용)
+
+use serde::{Deserialize, Serialize};
+
+/// 기본 상태 목록 (key, 이름). 사용자가 상태 목록을 저장한 적이 없으면 이 목록을 씁니다.
+pub const DEFAULT_DOCUMENT_STATUSES: &[(&str, &str)] = &[
+    ("draft", "초고"),
+    ("revising", "퇴고"),
+    ("final", "완성"),
+    ("published", "발행"),
+];
+
+/// 상태 필터에서 "상태 없음"을 뜻하는 값 (`status=none`). 상태 key로는 쓸 수 없습니다.
+pub const NO_STATUS_FILTER: &str = "none";
+
+/// 상태 하나 — DB의 `document_statuses` 테이블 한 행에 대응합니다.
+///
+/// `key`는 문서(`documents.status`)와 이력에 저장되는 값이라 바꾸지 않고,
+/// 화면에 보이는 `name`과 `color`만 바꿉니다.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct DocumentStatus {
+    /// 영문 소문자/숫자/`-`/`_` (예: `draft`)
+    pub key: String,
+    /// 표시 이름 (예: "초고")
+    pub name: String,
+    /// 색상 코드 (선택)
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+/// 기본 상태 목록을 만듭니다.
+pub fn default_document_statuses() -> Vec<DocumentStatus> {
+    DEFAULT_DOCUMENT_STATUSES
+        .iter()
+        .map(|(key, name)| DocumentStatus { key: key.to_string(), name: name.to_string(), color: None })
+        .collect()
+}
+
+/// 상태 목록 교체 요청 — `PUT /api/v1/statuses`
+///
+/// 목록 전체를 교체하며, 순서가 곧 워크플로 순서입니다.
+#[derive(Debug, Deserialize)]
+pub struct UpdateDocumentStatusesRequest {
+    pub statuses: Vec<DocumentStatus>,
+}
+
+/// 문서의 상태 변경 이력 한 건 — DB의 `document_status_history` 테이블 한 행에 대응합니다.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct StatusChange {
+    pub id: String,
+    pub document_id: String,
+    /// 바뀌기 전 상태 (None: 상태 없음)
+    pub from_status: Option<String>,
+    /// 바뀐 상태 (None: 상태를 지움)
+    pub to_status: Option<String>,
+    pub changed_at: String,
+}
+
+/// 상태별 문서 수와 단어 수 합계 (`GROUP BY status` 한 행)
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct StatusTotal {
+    /// None: 상태가 없는 문서들
+    pub status: Option<String>,
+    pub document_count: i64,
+    pub word_count: i64,
+}
@@ -5,11 +5,20 @@
 //!
 //! ## 구조체 역할
 //! - `Tag`: 데이터베이스에 저장된 태그를 표현 (응답용)
+//! - `DocumentTag`: 문서에 붙은 태그 (태그 + 붙은 경로: 직접/해시태그)
+//! - `TagWithUsage`: 태그 목록 응답용 (태그 + 문서 수/총 단어 수/마지막 사용 시각)
+//! - `TagTreeNode`: 태그 트리 응답용 (태그 + 전체 경로 + 하위 태그들)
+//! - `TagStats`: 태그가 붙은 문서들의 통계 합계 (`GET /tags/:id/stats`)
 //! - `CreateTagRequest`: 새 태그 생성 시 클라이언트가 보내는 JSON 본문
 //! - `UpdateTagRequest`: 태그 수정 시 클라이언트가 보내는 JSON 본문
 //! - `AddTagToDocumentRequest`: 문서에 태그를 연결할 때 클라이언트가 보내는 JSON 본문
+//! - `MergeTagsRequest`, `TagMergeReport`: 여러 태그를 하나로 합치는 요청과 결과
+//!
+//! ## 계층 구조
+//! 태그는 폴더처럼 `parent_id`로 트리를 이룹니다 (예: 소설 → 판타지 → 세계관).
+//! 경로는 최상위부터 이름을 `/`로 이어 표현하므로 태그 이름에는 `/`를 쓸 수 없습니다.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// 태그 엔티티 — DB의 `tags` 테이블 한 행(row)에 대응합니다.
 ///
@@ -27,6 +36,68 @@ pub struct Tag {
     /// 태그 색상 코드 (예: "#FF5733"), 없을 수도 있으므로 Option 타입
     /// Option<T>: 값이 있으면 Some(값), 없으면 None — Rust의 null 안전 처리 방식
     pub color: Option<String>,
+    /// 부모 태그 ID (None이면 최상위 태그)
+    pub parent_id: Option<String>,
+}
+
+/// 문서에 붙은 태그 — `GET /api/v1/documents/:id/tags` 응답의 한 항목입니다.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct DocumentTag {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub tag: Tag,
+    /// 붙은 경로: "manual"(직접 붙임) 또는 "hashtag"(본문의 `#태그`에서 자동으로 붙음)
+    /// 해시태그로 붙은 태그는 본문에서 해시태그를 지우면 함께 떨어집니다.
+    pub source: String,
+}
+
+/// 사용 현황이 포함된 태그 — `GET /api/v1/tags` 응답의 한 항목입니다.
+///
+/// 태그 관리 화면에서 실제로 쓰이는 태그와 아무 문서에도 붙지 않은 태그를 구분하는 데 씁니다.
+/// `#[sqlx(flatten)]`/`#[serde(flatten)]`: Tag의 필드를 같은 행/같은 JSON 객체에 펼칩니다.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct TagWithUsage {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub tag: Tag,
+    /// 이 태그가 붙은 문서 수 (아카이브된 문서 포함)
+    pub document_count: i64,
+    /// 이 태그가 붙은 문서들의 단어 수 합계
+    pub word_count: i64,
+    /// 마지막으로 사용한 시각: 태그를 붙인 시각과 태그된 문서의 수정 시각 중 가장 최근
+    /// 붙은 문서가 없으면 None
+    pub last_used_at: Option<String>,
+}
+
+/// 태그 트리의 노드 — `GET /api/v1/tags` 응답의 `tree` 항목입니다.
+///
+/// 사용 현황(`document_count` 등)은 이 태그 자체의 값이며 하위 태그는 포함하지 않습니다.
+/// 하위 태그까지 합친 값은 `GET /tags/:id/stats`로 조회합니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct TagTreeNode {
+    #[serde(flatten)]
+    pub tag: TagWithUsage,
+    /// 최상위 태그부터 이름을 `/`로 이은 경로 (예: "소설/판타지/세계관")
+    pub path: String,
+    /// 하위 태그 (이름순)
+    pub children: Vec<TagTreeNode>,
+}
+
+/// 태그가 붙은 문서들의 통계 합계
+///
+/// 하위 태그까지 합산할 때 여러 태그가 붙은 문서는 한 번만 셉니다.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct TagStats {
+    pub document_count: i64,
+    /// 그중 아카이브된 문서 수
+    pub archived_count: i64,
+    pub word_count: i64,
+    pub char_count: i64,
+    pub char_count_no_spaces: i64,
+    pub manuscript_pages: i64,
+    /// 이 태그를 처음 붙인 시각 (기록이 없으면 태그된 문서의 가장 이른 생성 시각)
+    pub first_used_at: Option<String>,
+    pub last_used_at: Option<String>,
 }
 
 /// 태그 생성 요청 — `POST /api/v1/tags`의 요청 본문(body)에 해당합니다.
@@ -40,6 +111,8 @@ pub struct CreateTagRequest {
     pub name: String,
     /// 태그 색상 코드 (선택)
     pub color: Option<String>,
+    /// 부모 태그 ID (선택, 없으면 최상위 태그)
+    pub parent_id: Option<String>,
 }
 
 /// 태그 수정 요청 — `PATCH /api/v1/tags/:id`의 요청 본문에 해당합니다.
@@ -53,6 +126,22 @@ pub struct UpdateTagRequest {
     pub name: Option<String>,
     /// 변경할 태그 색상 (None이면 변경하지 않음)
     pub color: Option<String>,
+    /// 부모 태그 변경: 필드 누락 = 변경 안 함, null = 최상위로 이동, ID = 그 태그 아래로 이동
+    #[serde(default, deserialize_with = "nullable")]
+    pub parent_id: Option<Option<String>>,
+}
+
+/// `Option<Option<T>>` 필드에서 `null`과 필드 누락을 구분합니다.
+///
+/// serde 기본 동작은 `null`도 바깥 `None`으로 만들어 "변경 안 함"과 구별되지 않습니다.
+/// 필드가 있으면 이 함수가 호출되어 `Some(...)`으로 감싸고(`null` → `Some(None)`),
+/// 필드가 없으면 `#[serde(default)]`에 따라 `None`이 됩니다.
+pub(crate) fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 /// 문서에 태그 추가 요청 — `POST /api/v1/documents/:id/tags`의 요청 본문에 해당합니다.
@@ -64,3 +153,33 @@ pub struct AddTagToDocumentRequest {
     /// 문서에 연결할 태그의 ID
     pub tag_id: String,
 }
+
+/// 태그 합치기 요청 — `POST /api/v1/tags/:id/merge`의 요청 본문에 해당합니다.
+///
+/// 경로의 `:id`가 남는 태그(대상)이고, `source_ids`의 태그들은 대상에 합쳐진 뒤 삭제됩니다.
+/// 예: `{ "source_ids": ["<world-building>"], "rewrite_hashtags": true }`
+#[derive(Debug, Deserialize)]
+pub struct MergeTagsRequest {
+    /// 대상에 합칠 태그 ID 목록 (1개 이상)
+    pub source_ids: Vec<String>,
+    /// 본문의 `#원래태그` 해시태그도 `#대상태그`로 다시 쓸지 여부 (기본값: false)
+    #[serde(default)]
+    pub rewrite_hashtags: bool,
+}
+
+/// 태그 합치기 결과
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TagMergeReport {
+    /// 합쳐져 삭제된 태그 ID
+    pub merged_tag_ids: Vec<String>,
+    /// 원래 태그 중 하나라도 붙어 있던 문서 수
+    pub documents: u64,
+    /// 대상 태그가 새로 붙은 문서 수 (이미 대상 태그가 있던 문서는 중복 없이 건너뜀)
+    pub documents_retagged: u64,
+    /// 대상 태그 아래로 옮긴 하위 태그 수
+    pub children_moved: u64,
+    /// 태그 필터를 대상 태그로 바꾼 저장된 검색 수
+    pub saved_searches_updated: usize,
+    /// 해시태그를 다시 쓴 문서 수 (`rewrite_hashtags`가 false면 0)
+    pub documents_rewritten: usize,
+}
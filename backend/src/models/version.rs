@@ -8,6 +8,8 @@ pub struct DocumentVersion {
     pub content: String,
     pub word_count: i64,
     pub char_count: i64,
+    pub char_count_no_spaces: i64,
+    pub manuscript_pages: i64,
     pub created_at: String,
 }
 
@@ -18,5 +20,29 @@ pub struct DocumentVersionSummary {
     pub version_number: i64,
     pub word_count: i64,
     pub char_count: i64,
+    pub char_count_no_spaces: i64,
+    pub manuscript_pages: i64,
     pub created_at: String,
 }
+
+/// 버전 검색 쿼리 결과 한 행 (버전 메타데이터 + 문서 제목 + 버전 본문)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct VersionSearchRow {
+    #[sqlx(flatten)]
+    pub version: DocumentVersionSummary,
+    pub document_title: String,
+    pub content: String,
+}
+
+/// 버전 검색 결과 한 건
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionSearchHit {
+    #[serde(flatten)]
+    pub version: DocumentVersionSummary,
+    /// 현재 문서 제목
+    pub document_title: String,
+    /// 버전 본문에서 처음 일치한 부분 주변을 `<mark>`로 표시한 스니펫 (HTML 이스케이프됨)
+    pub snippet: Option<String>,
+    /// 버전 본문에서 검색어가 나타난 횟수
+    pub match_count: usize,
+}
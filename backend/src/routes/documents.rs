@@ -4,13 +4,17 @@
 //! HTTP 핸들러 함수들입니다.
 //!
 //! ## 엔드포인트
-//! - `GET    /api/v1/documents`             → 문서 목록 조회
+//! - `GET    /api/v1/documents`             → 문서 목록 조회 (필터/정렬/페이지네이션/필드 선택)
 //! - `POST   /api/v1/documents`             → 새 문서 생성
+//! - `GET    /api/v1/documents/quick-open`  → 빠른 열기(Ctrl+P) 퍼지 제목 검색
 //! - `GET    /api/v1/documents/:id`         → 단일 문서 조회
 //! - `PATCH  /api/v1/documents/:id`         → 문서 수정 (부분 업데이트)
-//! - `DELETE /api/v1/documents/:id`         → 문서 삭제
+//! - `DELETE /api/v1/documents/:id`         → 문서를 휴지통으로 이동 (`routes::trash`에서 복원/영구 삭제)
+//! - `POST   /api/v1/documents/bulk`        → 여러 문서 일괄 작업 (이동/태그/고정/보관/상태/휴지통/내보내기)
 //! - `GET    /api/v1/documents/:id/content` → 문서 내용(마크다운) 조회
 //! - `PUT    /api/v1/documents/:id/content` → 문서 내용 수정
+//! - `GET    /api/v1/documents/:id/stats`   → 단어/어절/글자 수 통계
+//! - `POST   /api/v1/documents/:id/duplicate` → 문서 복제 (내용, 태그, 폴더)
 //!
 //! ## Axum 핸들러 패턴
 //! Axum 핸들러는 **Extractor(추출기)**를 매개변수로 받습니다.
@@ -30,6 +34,8 @@ use crate::{
     models::*,
     services,
 };
+use super::search::{next_cursor, search_page};
+use super::statuses::{ensure_known_status, status_from_front_matter};
 use axum::{
     extract::{Path, Query, State}, // Axum Extractor: 요청에서 데이터 추출
     http::{StatusCode, header, HeaderMap},
@@ -60,33 +66,242 @@ pub struct AppState {
     pub max_document_versions: u32,
     /// 버전 생성 최소 간격 (분)
     pub version_interval_minutes: u32,
+    /// 빠른 열기(Ctrl+P)용 사용자별 메모리 인덱스
+    pub quick_open: services::QuickOpenIndex,
+    /// 관리자 사용자 이름 목록 (`ADMIN_USERNAMES`)
+    pub admin_usernames: Vec<String>,
+}
+
+#[cfg(test)]
+impl AppState {
+    /// 테스트용 상태: 메모리 DB(`db::test_pool`)와 임시 문서 디렉토리를 쓰며, 테스트 사용자 ID를 함께 반환합니다.
+    pub(crate) async fn for_tests() -> (Self, String) {
+        let (pool, user_id) = db::test_pool().await;
+        let documents_path = std::env::temp_dir().join(format!("tecindo-test-{}", uuid::Uuid::now_v7()));
+        let state = AppState {
+            pool,
+            documents_path: documents_path.to_string_lossy().into_owned(),
+            jwt_secret: "test".to_string(),
+            max_document_versions: 50,
+            version_interval_minutes: 5,
+            quick_open: Default::default(),
+            admin_usernames: Vec::new(),
+        };
+        (state, user_id)
+    }
 }
 
 /// 문서 목록 조회용 쿼리 파라미터
+///
+/// 모든 파라미터가 선택 항목입니다. 아무것도 주지 않으면 보관하지 않은 모든 문서를 한 번에 반환합니다.
 #[derive(Deserialize)]
 pub struct ListDocumentsQuery {
-    /// 특정 태그가 붙은 문서만 필터링
+    /// 특정 태그(와 그 하위 태그)가 붙은 문서만 필터링
     pub tag_id: Option<String>,
+    /// 쉼표로 구분된 태그 ID 목록 (`tag_id`와 함께 쓰면 합쳐서 봅니다)
+    pub tag_ids: Option<String>,
+    /// 여러 태그의 일치 방식 (기본값: any)
+    pub tag_mode: Option<TagMatchMode>,
+    /// 폴더 ID. `root`이면 폴더에 속하지 않은 문서
+    pub folder_id: Option<String>,
+    /// 하위 폴더의 문서까지 포함할지 여부 (기본값: false)
+    pub recursive: Option<bool>,
+    /// 보관 문서만(true) 또는 보관 문서 제외(false). 주면 `include_archived`보다 우선합니다
+    pub archived: Option<bool>,
+    /// 보관 문서도 포함할지 여부 (기본값: false, `GET /graph`와 같음)
+    pub include_archived: Option<bool>,
+    pub pinned: Option<bool>,
+    /// 템플릿 문서만(true) 또는 템플릿 제외(false)
+    pub template: Option<bool>,
+    /// 쉼표로 구분된 상태 key 목록 (예: `draft,revising`). `none`은 상태가 없는 문서
+    pub status: Option<String>,
+    /// front matter 메타데이터 조건 (예: `status:초고,pov:민지,due`)
+    pub meta: Option<String>,
+    pub sort: Option<DocumentSort>,
+    pub order: Option<SortOrder>,
+    /// 쉼표로 구분된 응답 필드 목록 (예: `id,title,folder_id`). `id`는 항상 포함됩니다.
+    pub fields: Option<String>,
+    /// 한 페이지 문서 수 (기본 50, 최대 100). `limit`이나 `cursor`를 주면 페이지를 나눕니다.
+    pub limit: Option<i64>,
+    /// 이전 응답의 `next_cursor` 값
+    pub cursor: Option<String>,
 }
 
-/// `GET /documents` — 전체 문서 목록을 조회합니다.
+/// `fields` 파라미터로 고를 수 있는 문서 필드
+const DOCUMENT_FIELDS: &[&str] = &[
+    "id",
+    "folder_id",
+    "title",
+    "slug",
+    "file_path",
+    "word_count",
+    "char_count",
+    "char_count_no_spaces",
+    "manuscript_pages",
+    "excerpt",
+    "is_pinned",
+    "is_archived",
+    "is_template",
+    "status",
+    "created_at",
+    "updated_at",
+];
+
+/// 쉼표로 구분된 목록을 나눕니다. 빈 항목은 버립니다.
+fn split_list(raw: Option<&str>) -> Vec<String> {
+    raw.unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `GET /documents` — 문서 목록을 조회합니다.
 ///
 /// # 쿼리 파라미터
-/// - `tag_id` (선택): 특정 태그가 붙은 문서만 반환
+/// - `folder_id`, `recursive`: 폴더(와 하위 폴더)의 문서만. `folder_id=root`는 루트 문서만
+/// - `tag_id`, `tag_ids`, `tag_mode`: 태그 필터. 하위 태그가 붙은 문서도 포함합니다.
+/// - `archived`, `pinned`, `template`: 보관/고정/템플릿 여부 필터
+/// - `include_archived`: 보관 문서도 포함 (기본값: false). `archived`를 주면 무시합니다
+/// - `status`: 상태 필터 (`draft,revising`, 상태 없음은 `none`)
+/// - `meta`: front matter 메타데이터 조건 (`status:초고,due`)
+/// - `sort` (`title`/`created`/`updated`/`words`), `order` (`asc`/`desc`): 정렬
+/// - `fields`: 응답에 담을 필드 (사이드바처럼 일부 필드만 필요할 때)
+/// - `limit`, `cursor`: 페이지네이션 (`GET /search`와 같은 규칙)
 ///
 /// # 반환값
-/// `{ "documents": [...] }` 형태의 JSON
+/// `{ "documents": [...], "total": 120, "next_cursor": "50" }` 형태의 JSON.
+/// 페이지를 나누지 않으면 `next_cursor`는 항상 null입니다.
 pub async fn list_documents(
     State(state): State<AppState>,
     auth_user: AuthUser,
     Query(query): Query<ListDocumentsQuery>,
 ) -> Result<Json<Value>, AppError> {
-    let documents = if let Some(tag_id) = &query.tag_id {
-        db::list_documents_by_tag(&state.pool, tag_id, &auth_user.user_id).await?
+    let user_id = &auth_user.user_id;
+
+    let fields = split_list(query.fields.as_deref());
+    if let Some(unknown) = fields.iter().find(|f| !DOCUMENT_FIELDS.contains(&f.as_str())) {
+        return Err(AppError::BadRequest(format!("Unknown field: {}", unknown)));
+    }
+
+    let mut filters = DocumentListFilters {
+        tag_mode: query.tag_mode.unwrap_or_default(),
+        // 보관 문서는 기본으로 빼고, `include_archived=true`나 `archived`로 직접 고를 때만 포함합니다.
+        archived: query.archived.or((!query.include_archived.unwrap_or(false)).then_some(false)),
+        pinned: query.pinned,
+        template: query.template,
+        statuses: split_list(query.status.as_deref()),
+        metadata: MetadataCondition::parse_list(query.meta.as_deref().unwrap_or("")),
+        sort: query.sort,
+        order: query.order,
+        ..Default::default()
+    };
+
+    // 폴더 필터: `root`는 루트 문서만(하위 폴더 포함이면 전체 문서와 같으므로 필터 없음),
+    // 그 외에는 폴더를 확인하고 필요하면 하위 폴더까지 펼칩니다.
+    let recursive = query.recursive.unwrap_or(false);
+    match query.folder_id.as_deref() {
+        None => {}
+        Some("root") => filters.root_only = !recursive,
+        Some(folder_id) => {
+            let ids = if recursive {
+                db::list_descendant_folder_ids(&state.pool, folder_id, user_id).await?
+            } else {
+                db::get_folder(&state.pool, folder_id, user_id)
+                    .await?
+                    .map(|f| vec![f.id])
+                    .unwrap_or_default()
+            };
+            if ids.is_empty() {
+                return Err(AppError::NotFound);
+            }
+            filters.folder_ids = Some(ids);
+        }
+    }
+
+    // 태그 필터는 태그마다 하위 태그까지 펼칩니다. 없는 태그는 아무 문서와도 일치하지 않습니다.
+    let mut tag_ids = split_list(query.tag_ids.as_deref());
+    if let Some(tag_id) = &query.tag_id {
+        if !tag_ids.contains(tag_id) {
+            tag_ids.insert(0, tag_id.clone());
+        }
+    }
+    for tag_id in &tag_ids {
+        filters
+            .tag_groups
+            .push(db::list_descendant_tag_ids(&state.pool, tag_id, user_id).await?);
+    }
+
+    let page = if query.limit.is_some() || query.cursor.is_some() {
+        Some(search_page(query.limit, query.cursor.as_deref())?)
     } else {
-        db::list_documents(&state.pool, &auth_user.user_id).await?
+        None
     };
-    Ok(Json(json!({ "documents": documents })))
+
+    let (documents, total) = db::list_documents(&state.pool, &filters, user_id, page).await?;
+    let next_cursor = page.and_then(|page| next_cursor(page, documents.len(), total));
+
+    let documents = if fields.is_empty() {
+        json!(documents)
+    } else {
+        // 문서를 JSON 객체로 바꾼 뒤 고른 필드(와 id)만 남깁니다.
+        let selected: Vec<Value> = documents
+            .iter()
+            .map(|document| {
+                let mut value = json!(document);
+                if let Some(object) = value.as_object_mut() {
+                    object.retain(|key, _| key == "id" || fields.contains(key));
+                }
+                value
+            })
+            .collect();
+        json!(selected)
+    };
+
+    Ok(Json(json!({
+        "documents": documents,
+        "total": total,
+        "next_cursor": next_cursor,
+    })))
+}
+
+/// 빠른 열기 조회용 쿼리 파라미터
+#[derive(Deserialize)]
+pub struct QuickOpenQuery {
+    /// 검색어 (비어 있으면 최근 수정순)
+    #[serde(default)]
+    pub q: String,
+    /// 최대 결과 수 (기본 20, 최대 100)
+    pub limit: Option<usize>,
+}
+
+/// `GET /documents/quick-open?q=` — Ctrl+P 문서 전환기용 빠른 제목 검색입니다.
+///
+/// 전문검색 대신 메모리에 올려 둔 제목/폴더 경로/태그를 퍼지 매칭합니다
+/// (초성 "ㅅㅅ" → "소설", 오타 허용). 자세한 규칙은 `services::quick_open` 참고.
+///
+/// 응답: `{ "results": [{ "id", "title", "folder_path", "tags", "score", ... }] }` (점수 높은 순)
+pub async fn quick_open(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<QuickOpenQuery>,
+) -> Result<Json<Value>, AppError> {
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+
+    // 캐시가 없으면 DB에서 읽어 채웁니다.
+    let entries = match state.quick_open.get(&auth_user.user_id) {
+        (_, Some(entries)) => entries,
+        (generation, None) => {
+            let entries =
+                std::sync::Arc::new(db::list_quick_open_entries(&state.pool, &auth_user.user_id).await?);
+            state.quick_open.store(&auth_user.user_id, generation, entries.clone());
+            entries
+        }
+    };
+
+    let results = services::quick_open_search(&entries, &query.q, limit);
+    Ok(Json(json!({ "results": results })))
 }
 
 /// `GET /documents/:id` — 단일 문서를 조회합니다.
@@ -108,6 +323,8 @@ pub async fn get_document(
 /// `POST /documents` — 새 문서를 생성합니다.
 ///
 /// 빈 마크다운 파일을 디스크에 생성하고, DB에 메타데이터를 저장합니다.
+/// `template_id`를 주면 템플릿 문서의 본문에서 자리표시자(`{{date}}`, `{{title}}`, `{{folder}}` 등)를
+/// 치환한 내용으로 시작합니다. 템플릿이 아닌 문서를 주면 400입니다.
 ///
 /// # Extractor
 /// - `Json(req)`: HTTP 요청 본문(body)을 JSON으로 파싱하여
@@ -118,37 +335,143 @@ pub async fn create_document(
     auth_user: AuthUser,
     Json(req): Json<CreateDocumentRequest>,
 ) -> Result<Json<Document>, AppError> {
-    let id = uuid::Uuid::now_v7().to_string();
+    let user_id = &auth_user.user_id;
 
-    let title = if let Some(t) = req.title.as_deref() {
-        t.to_string()
-    } else {
-        let existing = db::list_untitled_titles(
-            &state.pool,
-            req.folder_id.as_deref(),
-            &auth_user.user_id,
-        ).await?;
-        generate_untitled_name(&existing)
+    // 파일을 만들기 전에 폴더와 템플릿부터 확인합니다.
+    let folder = find_owned_folder(&state, req.folder_id.as_deref(), user_id).await?;
+    let template = match &req.template_id {
+        Some(template_id) => {
+            let template = db::get_document(&state.pool, template_id, user_id)
+                .await?
+                .ok_or(AppError::NotFound)?;
+            if template.is_template == 0 {
+                return Err(AppError::BadRequest("Document is not a template".to_string()));
+            }
+            Some(template)
+        }
+        None => None,
     };
 
-    let folder_slug = if let Some(folder_id) = &req.folder_id {
-        let folder = db::get_folder(&state.pool, folder_id, &auth_user.user_id).await?;
-        folder.map(|f| f.slug)
-    } else {
-        None
+    let document = create_document_record(&state, req.title, folder.as_ref(), user_id).await?;
+
+    let Some(template) = template else {
+        return Ok(Json(document));
+    };
+    let now = chrono::Utc::now();
+    let content = services::read_markdown(&state.documents_path, &template.file_path).await?;
+    let content = services::expand_template(
+        &content,
+        &services::TemplateContext {
+            title: &document.title,
+            folder: folder.as_ref().map_or("", |f| f.name.as_str()),
+            date: now.date_naive(),
+            time: now.time(),
+        },
+    );
+    Ok(Json(save_initial_content(&state, document, &content, user_id).await?))
+}
+
+/// 폴더 ID가 주어지면 현재 사용자의 폴더인지 확인하여 반환합니다.
+///
+/// 없거나 다른 사용자의 폴더면 404입니다 (`PATCH /documents/:id`의 폴더 검증과 같음).
+async fn find_owned_folder(
+    state: &AppState,
+    folder_id: Option<&str>,
+    user_id: &str,
+) -> Result<Option<Folder>, AppError> {
+    match folder_id {
+        Some(folder_id) => db::get_folder(&state.pool, folder_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// 빈 새 문서를 만듭니다: .md 파일을 쓰고, DB에 등록하고, 제목으로 색인합니다.
+///
+/// `POST /documents`, 문서 복제, 일지(`routes::journal`)가 함께 사용합니다. `folder`는 소유권을 확인한 폴더여야 합니다.
+/// 제목이 없으면 같은 폴더의 Untitled 제목들을 보고 다음 이름을 붙입니다.
+pub(crate) async fn create_document_record(
+    state: &AppState,
+    title: Option<String>,
+    folder: Option<&Folder>,
+    user_id: &str,
+) -> Result<Document, AppError> {
+    let id = uuid::Uuid::now_v7().to_string();
+    let folder_id = folder.map(|f| f.id.clone());
+
+    let title = match title {
+        Some(t) => t,
+        None => {
+            let existing = db::list_untitled_titles(&state.pool, folder_id.as_deref(), user_id).await?;
+            generate_untitled_name(&existing)
+        }
     };
 
-    let file_path = services::generate_file_path(&title, folder_slug.as_deref(), &id);
+    let file_path = services::generate_file_path(&title, folder.map(|f| f.slug.as_str()), &id);
     let slug = slug::slugify(&title);
 
     services::write_markdown(&state.documents_path, &file_path, "").await?;
 
-    let req_with_title = CreateDocumentRequest {
+    let req = CreateDocumentRequest {
         title: Some(title),
-        folder_id: req.folder_id,
+        folder_id,
+        template_id: None,
     };
-    let document = db::create_document(&state.pool, &id, &req_with_title, file_path, slug, &auth_user.user_id).await?;
-    Ok(Json(document))
+    let document = db::create_document(&state.pool, &id, &req, file_path, slug, user_id).await?;
+
+    // 본문을 저장하기 전에도 제목으로 검색되도록 빈 본문으로 색인합니다.
+    db::index_document_or_retry(&state.pool, &state.documents_path, &id, &document.title, "").await;
+    state.quick_open.invalidate(user_id);
+
+    Ok(document)
+}
+
+/// 새로 만든 문서에 첫 내용을 저장하고, 통계가 반영된 문서를 다시 읽어 반환합니다.
+///
+/// 내용이 비어 있으면 저장하지 않습니다 (빈 버전 스냅샷이 생기지 않도록).
+pub(crate) async fn save_initial_content(
+    state: &AppState,
+    document: Document,
+    content: &str,
+    user_id: &str,
+) -> Result<Document, AppError> {
+    if content.is_empty() {
+        return Ok(document);
+    }
+    save_document_content(state, &document, content, user_id).await?;
+    db::get_document(&state.pool, &document.id, user_id)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+/// `POST /documents/:id/duplicate` — 문서를 복제합니다.
+///
+/// 새 파일에 같은 내용을 쓰고, 같은 폴더에 "제목 (사본)"으로 만듭니다.
+/// 태그는 출처(manual/hashtag)까지 그대로 복사합니다.
+/// 고정/보관/템플릿 표시는 복사하지 않습니다 (복제본은 일반 문서로 시작).
+pub async fn duplicate_document(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<Document>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    let source = db::get_document(&state.pool, &id, user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let content = services::read_markdown(&state.documents_path, &source.file_path).await?;
+    let folder = match &source.folder_id {
+        Some(folder_id) => db::get_folder(&state.pool, folder_id, user_id).await?,
+        None => None,
+    };
+
+    let title = format!("{} (사본)", source.title);
+    let document = create_document_record(&state, Some(title), folder.as_ref(), user_id).await?;
+    db::copy_document_tags(&state.pool, &source.id, &document.id).await?;
+
+    Ok(Json(save_initial_content(&state, document, &content, user_id).await?))
 }
 
 /// 같은 폴더 내 기존 Untitled 제목들을 보고 다음 고유 이름을 생성합니다.
@@ -175,49 +498,291 @@ fn generate_untitled_name(existing: &[String]) -> String {
 ///
 /// 요청 본문에 포함된 필드만 업데이트합니다 (부분 업데이트).
 /// 예: `{ "title": "새 제목" }`으로 제목만 변경 가능
+///
+/// `{ "title": "새 제목", "rewrite_links": true }`로 요청하면
+/// 이 문서를 가리키던 `[[이전 제목]]` 링크도 모두 `[[새 제목]]`으로 바뀝니다.
+/// 이때 응답의 문서 필드 옆에 `link_rewrite`(다시 쓴 문서 수와 실패한 문서 목록)가 붙습니다.
+/// 제목 변경은 이미 저장되었으므로, 일부 문서를 다시 쓰지 못해도 요청은 성공합니다.
 pub async fn update_document(
     State(state): State<AppState>,
     auth_user: AuthUser,
     Path(id): Path<String>,
     Json(req): Json<UpdateDocumentRequest>,
-) -> Result<Json<Document>, AppError> {
+) -> Result<Json<UpdateDocumentResponse>, AppError> {
     // folder_id가 지정된 경우, 해당 폴더가 현재 사용자 소유인지 검증
     if let Some(Some(folder_id)) = &req.folder_id {
         db::get_folder(&state.pool, folder_id, &auth_user.user_id)
             .await?
             .ok_or(AppError::NotFound)?;
     }
+    // 상태는 사용자 상태 목록에 있는 key여야 합니다.
+    if let Some(status) = &req.status {
+        ensure_known_status(&state, status.as_deref(), &auth_user.user_id).await?;
+    }
+
+    let before = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
 
     let document = db::update_document(&state.pool, &id, &req, &auth_user.user_id)
         .await?
         .ok_or(AppError::NotFound)?;
-    Ok(Json(document))
+    state.quick_open.invalidate(&auth_user.user_id);
+
+    // 제목이 바뀌었으면 새 제목으로 검색되도록 다시 색인합니다.
+    // 제목 변경 자체는 이미 저장되었으므로, 파일을 읽지 못해도 요청은 실패시키지 않습니다.
+    if before.title != document.title {
+        match services::read_markdown(&state.documents_path, &document.file_path).await {
+            Ok(content) => {
+                db::index_document_or_retry(
+                    &state.pool,
+                    &state.documents_path,
+                    &id,
+                    &document.title,
+                    &content,
+                )
+                .await
+            }
+            Err(e) => tracing::warn!("Failed to read {} for search indexing: {}", document.file_path, e),
+        }
+    }
+
+    // 상태가 바뀌었으면 front matter의 `status:`도 맞춥니다. 상태는 이미 저장되었으므로 실패해도 로그만 남깁니다.
+    if before.status != document.status {
+        if let Err(e) = write_status_to_front_matter(&state, &document, &auth_user.user_id).await {
+            tracing::warn!("Failed to write status to front matter of {}: {}", document.id, e);
+        }
+    }
+
+    // 제목이 바뀌었고 요청에서 원했다면, 이 문서를 가리키는 링크를 새 제목으로 다시 씁니다.
+    let mut link_rewrite = None;
+    if req.rewrite_links.unwrap_or(false) && before.title != document.title {
+        let report = rewrite_links_to(&state, &before.title, &document.title, &auth_user.user_id).await?;
+        tracing::info!(
+            "Rewrote wiki links in {} document(s), {} failed: '{}' → '{}'",
+            report.rewritten,
+            report.failed.len(),
+            before.title,
+            document.title
+        );
+        link_rewrite = Some(report);
+    }
+
+    Ok(Json(UpdateDocumentResponse { document, link_rewrite }))
+}
+
+/// `PATCH /documents/:id` 응답: 문서 필드에 링크 다시 쓰기 결과를 덧붙입니다.
+#[derive(Debug, serde::Serialize)]
+pub struct UpdateDocumentResponse {
+    #[serde(flatten)]
+    pub document: Document,
+    /// `rewrite_links`로 링크를 다시 썼을 때만 포함
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_rewrite: Option<LinkRewriteReport>,
+}
+
+/// API로 바뀐 문서 상태를 front matter의 `status:` 키에 씁니다 (상태를 지웠으면 키를 지움).
+///
+/// `status:` 키가 없거나 front matter가 없는 파일, YAML이 깨진 파일은 건드리지 않습니다.
+/// 키의 값이 이미 같은 상태를 가리키면(상태 이름으로 적은 경우 포함) 다시 쓰지 않습니다.
+async fn write_status_to_front_matter(
+    state: &AppState,
+    document: &Document,
+    user_id: &str,
+) -> Result<(), AppError> {
+    let content = services::read_markdown(&state.documents_path, &document.file_path).await?;
+    let Ok(Some(metadata)) = services::parse_front_matter(&content) else {
+        return Ok(());
+    };
+    let Some(value) = metadata.get("status") else {
+        return Ok(());
+    };
+    let statuses = db::list_document_statuses(&state.pool, user_id).await?;
+    if status_from_front_matter(&statuses, value).ok().flatten() == document.status {
+        return Ok(());
+    }
+
+    let mut changes = serde_json::Map::new();
+    changes.insert("status".to_string(), json!(document.status));
+    let updated = services::update_front_matter(&content, &changes).map_err(AppError::BadRequest)?;
+    save_document_content(state, document, &updated, user_id).await
 }
 
-/// `DELETE /documents/:id` — 문서를 삭제합니다.
+/// 이름이 바뀐 문서를 가리키는 `[[위키 링크]]`를 사용자의 모든 문서에서 다시 씁니다.
 ///
-/// DB 레코드와 디스크의 .md 파일을 모두 삭제합니다.
+/// `document_links`에서 이전 slug를 가리키는 문서만 골라 읽으므로,
+/// 링크가 없는 문서의 파일은 열지 않습니다.
+///
+/// 문서 하나를 읽거나 저장하지 못해도 멈추지 않고 다음 문서로 넘어가며, 실패한 문서는 결과에 담습니다.
+/// 대상 문서 목록을 조회하지 못했을 때만 에러를 반환합니다.
+async fn rewrite_links_to(
+    state: &AppState,
+    old_title: &str,
+    new_title: &str,
+    user_id: &str,
+) -> Result<LinkRewriteReport, AppError> {
+    let old_slug = slug::slugify(old_title);
+    let source_ids = db::list_link_source_ids(&state.pool, &old_slug, user_id).await?;
+
+    let mut report = LinkRewriteReport::default();
+    for source_id in source_ids {
+        let source = match db::get_document(&state.pool, &source_id, user_id).await {
+            Ok(Some(source)) => source,
+            Ok(None) => continue,
+            Err(e) => {
+                report.failed.push(LinkRewriteFailure { id: source_id, title: String::new(), error: e.to_string() });
+                continue;
+            }
+        };
+        let result = match services::read_markdown(&state.documents_path, &source.file_path).await {
+            Ok(content) => match services::rewrite_wiki_links(&content, old_title, new_title) {
+                Some(updated) => save_document_content(state, &source, &updated, user_id).await.map(|()| true),
+                None => Ok(false),
+            },
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(true) => report.rewritten += 1,
+            Ok(false) => {}
+            Err(e) => {
+                tracing::warn!("Failed to rewrite wiki links in document {}: {}", source.id, e);
+                report.failed.push(LinkRewriteFailure { id: source.id, title: source.title, error: e.to_string() });
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// `DELETE /documents/:id` — 문서를 휴지통으로 옮깁니다.
+///
+/// DB 레코드와 디스크의 .md 파일은 그대로 남으며, `POST /trash/:id/restore`로 되돌릴 수 있습니다.
+/// 영구 삭제는 `DELETE /trash/:id` 또는 `DELETE /trash`(휴지통 비우기)로 합니다.
 /// 성공 시 HTTP 204 No Content를 반환합니다 (본문 없음).
 pub async fn delete_document(
     State(state): State<AppState>,
     auth_user: AuthUser,
     Path(id): Path<String>,
 ) -> Result<StatusCode, AppError> {
-    let document = db::get_document(&state.pool, &id, &auth_user.user_id)
-        .await?
-        .ok_or(AppError::NotFound)?;
-
-    let deleted = db::delete_document(&state.pool, &id, &auth_user.user_id).await?;
-    if !deleted {
+    let trashed = db::trash_document(&state.pool, &id, &auth_user.user_id).await?;
+    if !trashed {
         return Err(AppError::NotFound);
     }
-
-    let file_path = std::path::PathBuf::from(&state.documents_path).join(&document.file_path);
-    let _ = tokio::fs::remove_file(file_path).await;
+    state.quick_open.invalidate(&auth_user.user_id);
 
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// 일괄 작업 한 번에 받을 수 있는 최대 문서 수
+const MAX_BULK_IDS: usize = 500;
+
+/// `POST /documents/bulk` — 여러 문서에 같은 작업을 한 번에 적용합니다.
+///
+/// 요청: `{ "ids": [...], "action": "move" | "add_tags" | "remove_tags" | "pin" | "unpin"
+///         | "archive" | "unarchive" | "set_status" | "trash" | "export", ...매개변수 }`
+///
+/// 단건 엔드포인트와 같은 소유권 검사를 거칩니다:
+/// - 대상 폴더나 태그가 내 것이 아니면 요청 전체가 404입니다 (`PATCH /documents/:id`와 같음).
+/// - 없거나 다른 사용자의 문서는 해당 항목만 `ok: false`로 표시하고 나머지는 계속 진행합니다.
+///
+/// DB 변경은 한 트랜잭션으로 적용되므로, 실패하면 어떤 문서도 바뀌지 않습니다.
+/// `trash`는 휴지통으로 옮기며(`DELETE /documents/:id`와 같음), 파일은 휴지통을 비울 때 지웁니다.
+/// 응답: `{ "results": [{ "id", "ok", "error"?, "title"?, "content"? }], "succeeded", "failed" }` (요청한 ID 순서)
+pub async fn bulk_documents(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(req): Json<BulkDocumentsRequest>,
+) -> Result<Json<Value>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    // 같은 ID가 여러 번 오면 처음 한 번만 처리합니다.
+    let mut ids: Vec<String> = Vec::with_capacity(req.ids.len());
+    for id in req.ids {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    if ids.is_empty() {
+        return Err(AppError::BadRequest("ids cannot be empty".to_string()));
+    }
+    if ids.len() > MAX_BULK_IDS {
+        return Err(AppError::BadRequest(format!(
+            "Too many ids: at most {} documents per request",
+            MAX_BULK_IDS
+        )));
+    }
+
+    match &req.action {
+        BulkDocumentAction::Move { folder_id: Some(folder_id) } => {
+            db::get_folder(&state.pool, folder_id, user_id)
+                .await?
+                .ok_or(AppError::NotFound)?;
+        }
+        BulkDocumentAction::AddTags { tag_ids } | BulkDocumentAction::RemoveTags { tag_ids } => {
+            if tag_ids.is_empty() {
+                return Err(AppError::BadRequest("tag_ids cannot be empty".to_string()));
+            }
+            for tag_id in tag_ids {
+                db::get_tag(&state.pool, tag_id, user_id)
+                    .await?
+                    .ok_or(AppError::NotFound)?;
+            }
+        }
+        BulkDocumentAction::SetStatus { status } => {
+            ensure_known_status(&state, status.as_deref(), user_id).await?;
+        }
+        _ => {}
+    }
+
+    // 문서마다 소유권을 확인합니다. 찾지 못한 문서는 결과에만 남기고 건너뜁니다.
+    let mut results = Vec::with_capacity(ids.len());
+    let mut documents = Vec::with_capacity(ids.len());
+    for id in &ids {
+        match db::get_document(&state.pool, id, user_id).await? {
+            Some(document) => documents.push(document),
+            None => results.push(BulkItemResult::failed(id, "Document not found")),
+        }
+    }
+
+    if let BulkDocumentAction::Export = req.action {
+        for document in &documents {
+            let result = match services::read_markdown(&state.documents_path, &document.file_path).await {
+                Ok(content) => BulkItemResult {
+                    title: Some(document.title.clone()),
+                    content: Some(content),
+                    ..BulkItemResult::ok(&document.id)
+                },
+                Err(e) => BulkItemResult::failed(&document.id, e.to_string()),
+            };
+            results.push(result);
+        }
+    } else {
+        let found_ids: Vec<String> = documents.iter().map(|d| d.id.clone()).collect();
+        db::bulk_update_documents(&state.pool, &found_ids, &req.action, user_id).await?;
+        state.quick_open.invalidate(user_id);
+        // 상태를 바꿨으면 front matter에 `status:` 키가 있는 파일도 맞춥니다 (`PATCH /documents/:id`와 같음).
+        if let BulkDocumentAction::SetStatus { status } = &req.action {
+            for document in &documents {
+                let document = Document { status: status.clone(), ..document.clone() };
+                if let Err(e) = write_status_to_front_matter(&state, &document, user_id).await {
+                    tracing::warn!("Failed to write status to front matter of {}: {}", document.id, e);
+                }
+            }
+        }
+        results.extend(documents.iter().map(|d| BulkItemResult::ok(&d.id)));
+    }
+
+    // 요청한 순서대로 결과를 정렬합니다.
+    results.sort_by_key(|r| ids.iter().position(|id| *id == r.id));
+    let succeeded = results.iter().filter(|r| r.ok).count();
+
+    Ok(Json(json!({
+        "results": results,
+        "succeeded": succeeded,
+        "failed": results.len() - succeeded,
+    })))
+}
+
 /// `GET /documents/:id/content` — 문서의 마크다운 내용을 조회합니다.
 ///
 /// 디스크의 .md 파일을 읽어 JSON으로 반환합니다.
@@ -235,10 +800,27 @@ pub async fn get_document_content(
     Ok(Json(DocumentContent { content }))
 }
 
+/// `GET /documents/:id/stats` — 문서 본문의 단어/어절/글자 수 통계를 조회합니다.
+///
+/// 저장된 값이 아니라 현재 파일 내용을 다시 파싱하여 계산합니다.
+/// 응답: `{ "words": 120, "eojeols": 110, "chars": 480, "chars_no_spaces": 370 }`
+pub async fn get_document_stats(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<services::TextStats>, AppError> {
+    let document = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let content = services::read_markdown(&state.documents_path, &document.file_path).await?;
+    Ok(Json(services::analyze_text(&content)))
+}
+
 /// `PUT /documents/:id/content` — 문서의 마크다운 내용을 수정합니다.
 ///
 /// 디스크 파일을 덮어쓰고, DB의 단어 수/글자 수/미리보기를 업데이트합니다.
-/// 또한 전문검색(FTS5) 인덱스도 갱신합니다.
+/// 또한 전문검색(FTS5) 인덱스와 위키 링크 목록도 갱신합니다.
 /// 성공 시 HTTP 204 No Content를 반환합니다.
 pub async fn update_document_content(
     State(state): State<AppState>,
@@ -250,71 +832,123 @@ pub async fn update_document_content(
         .await?
         .ok_or(AppError::NotFound)?;
 
-    // FTS 인덱스 업데이트를 위해 이전 내용을 읽어둡니다.
-    // .ok(): Result를 Option으로 변환 (에러를 무시하고 None으로 처리)
-    let old_content = services::read_markdown(&state.documents_path, &document.file_path)
-        .await
-        .ok();
+    save_document_content(&state, &document, &req.content, &auth_user.user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 문서 내용을 저장하고, 내용에서 파생되는 데이터를 모두 갱신합니다.
+///
+/// `PUT /documents/:id/content`뿐 아니라 링크 다시 쓰기처럼
+/// 서버가 본문을 고치는 경로도 모두 이 함수를 거쳐 저장합니다.
+///
+/// 1. 디스크 파일 덮어쓰기
+/// 2. DB의 단어 수/글자 수/미리보기 갱신
+/// 3. 위키 링크(`document_links`) 갱신
+/// 4. 해시태그(`#태그`)로 붙은 태그 갱신 (없는 태그는 생성, 지운 해시태그의 연결은 삭제)
+/// 5. YAML front matter를 메타데이터(`document_metadata`)로 갱신 (YAML이 깨져 있으면 건너뜀).
+///    `status:` 키가 있으면 문서 상태에도 반영 (목록에 없는 값이면 파일을 쓰기 전에 400)
+/// 6. 버전 스냅샷 (설정된 간격이 지났을 때만)
+/// 7. 전문검색(FTS5) 인덱스 갱신 (실패하면 로그를 남기고 재시도하며, 저장 자체는 성공)
+/// 8. 빠른 열기 인덱스 무효화
+pub(crate) async fn save_document_content(
+    state: &AppState,
+    document: &Document,
+    content: &str,
+    user_id: &str,
+) -> Result<(), AppError> {
+    let id = &document.id;
+
+    // front matter의 `status:`는 문서 상태로 반영하므로, 파일을 쓰기 전에 상태 목록에 있는지 확인합니다.
+    let front_matter = services::parse_front_matter(content);
+    let front_matter_status = match &front_matter {
+        Ok(Some(metadata)) => match metadata.get("status") {
+            Some(value) => {
+                let statuses = db::list_document_statuses(&state.pool, user_id).await?;
+                Some(status_from_front_matter(&statuses, value)?)
+            }
+            None => None,
+        },
+        _ => None,
+    };
 
     // 새 내용을 디스크 파일에 저장합니다.
-    services::write_markdown(&state.documents_path, &document.file_path, &req.content).await?;
+    services::write_markdown(&state.documents_path, &document.file_path, content).await?;
 
-    // 단어 수와 글자 수를 계산합니다.
-    // as i64: usize(부호 없는 정수)를 i64(부호 있는 정수)로 캐스팅합니다.
-    //   SQLite의 INTEGER는 i64에 매핑됩니다.
-    let word_count = services::count_words(&req.content) as i64;
-    let char_count = services::count_chars(&req.content) as i64;
+    // 마크다운을 파싱하여 본문의 단어 수와 글자 수를 계산합니다.
+    let stats = services::analyze_text(content);
 
     // 미리보기(excerpt): 내용의 처음 200자를 추출합니다.
     // chars().take()를 사용하여 유니코드 문자 단위로 안전하게 자릅니다.
-    let excerpt = if req.content.is_empty() {
+    let excerpt = if content.is_empty() {
         None
-    } else if req.content.chars().count() > 200 {
-        Some(req.content.chars().take(200).collect::<String>())
+    } else if content.chars().count() > 200 {
+        Some(content.chars().take(200).collect::<String>())
     } else {
-        Some(req.content.clone())
+        Some(content.to_string())
     };
 
     // DB의 문서 메타데이터(단어 수, 글자 수, 미리보기, 수정일)를 업데이트합니다.
     sqlx::query(
         r#"
         UPDATE documents
-        SET word_count = ?, char_count = ?, excerpt = ?,
+        SET word_count = ?, char_count = ?, char_count_no_spaces = ?,
+            manuscript_pages = ?, excerpt = ?,
             updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
         WHERE id = ? AND user_id = ?
         "#,
     )
-    .bind(word_count)
-    .bind(char_count)
+    // as i64: usize(부호 없는 정수)를 i64(부호 있는 정수)로 캐스팅합니다.
+    //   SQLite의 INTEGER는 i64에 매핑됩니다.
+    .bind(stats.words as i64)
+    .bind(stats.chars as i64)
+    .bind(stats.chars_no_spaces as i64)
+    .bind(stats.manuscript_pages as i64)
     .bind(excerpt)
-    .bind(&id)
-    .bind(&auth_user.user_id)
+    .bind(id)
+    .bind(user_id)
     .execute(&state.pool)
     .await?;
 
+    // 본문의 [[위키 링크]]를 파싱하여 링크 목록을 갱신합니다.
+    let link_targets = services::collect_link_targets(content);
+    db::replace_document_links(&state.pool, id, &link_targets).await?;
+
+    // 본문의 #해시태그를 태그로 연결합니다. 직접 붙인 태그는 그대로 둡니다.
+    let hashtags = services::extract_hashtags(content);
+    db::sync_hashtag_tags(&state.pool, id, &hashtags, user_id).await?;
+
+    // front matter를 메타데이터로 저장합니다. front matter가 없으면 메타데이터도 비웁니다.
+    // 작성 중이라 YAML이 잠시 깨진 경우에는 마지막으로 읽은 메타데이터를 그대로 둡니다.
+    match front_matter {
+        Ok(metadata) => {
+            db::replace_document_metadata(&state.pool, id, &metadata.unwrap_or_default()).await?
+        }
+        Err(e) => tracing::debug!("Skipping metadata sync for {}: invalid front matter: {}", id, e),
+    }
+    // `status:` 키가 있었다면 문서 상태도 맞춥니다. 실제로 바뀌면 상태 변경 이력에 남습니다.
+    if let Some(status) = front_matter_status {
+        db::set_document_status(&state.pool, id, status.as_deref(), user_id).await?;
+    }
+
     // 설정된 간격이 지났을 때만 버전 스냅샷 저장 (best-effort)
-    if db::should_create_version(&state.pool, &id, state.version_interval_minutes)
+    if db::should_create_version(&state.pool, id, state.version_interval_minutes)
         .await
         .unwrap_or(true)
     {
-        let _ = db::create_version(&state.pool, &id, &req.content, word_count, char_count).await;
-        let _ = db::prune_versions(&state.pool, &id, state.max_document_versions).await;
+        let _ = db::create_version(&state.pool, id, content, &stats).await;
+        let _ = db::prune_versions(&state.pool, id, state.max_document_versions).await;
     }
 
     // FTS5(전문검색) 인덱스를 갱신합니다.
     // 검색 기능이 최신 내용을 반영할 수 있도록 합니다.
-    // .as_deref(): Option<String> → Option<&str> 변환
-    db::index_document(
-        &state.pool,
-        &id,
-        &document.title,
-        &req.content,
-        Some(&document.title),
-        old_content.as_deref(),
-    )
-    .await?;
+    db::index_document_or_retry(&state.pool, &state.documents_path, id, &document.title, content)
+        .await;
 
-    Ok(StatusCode::NO_CONTENT)
+    // 수정일이 바뀌었으므로 빠른 열기의 최근 수정 순위도 갱신합니다.
+    state.quick_open.invalidate(user_id);
+
+    Ok(())
 }
 
 /// `GET /documents/:id/export/pdf` — 문서를 pandoc으로 PDF 변환 후 다운로드합니다.
@@ -397,3 +1031,240 @@ pub async fn export_document_pdf(
 
     Ok((headers, pdf_bytes))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 문서의 색인 행(제목, 본문)을 조회합니다. 색인되지 않았으면 None입니다.
+    async fn indexed(state: &AppState, id: &str) -> Option<(String, String)> {
+        sqlx::query_as(
+            "SELECT f.title, f.content FROM documents_fts f JOIN documents d ON d.rowid = f.rowid WHERE d.id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&state.pool)
+        .await
+        .unwrap()
+    }
+
+    async fn create(state: &AppState, user_id: &str, title: &str) -> Document {
+        let req = CreateDocumentRequest { title: Some(title.to_string()), folder_id: None, template_id: None };
+        let auth_user = AuthUser { user_id: user_id.to_string() };
+        let Json(document) = create_document(State(state.clone()), auth_user, Json(req)).await.unwrap();
+        document
+    }
+
+    #[tokio::test]
+    async fn create_indexes_title_before_content_is_saved() {
+        let (state, user_id) = AppState::for_tests().await;
+
+        let document = create(&state, &user_id, "등장인물").await;
+        assert_eq!(indexed(&state, &document.id).await, Some(("등장인물".to_string(), String::new())));
+
+        save_document_content(&state, &document, "민지와 준호", &user_id).await.unwrap();
+        assert_eq!(indexed(&state, &document.id).await.unwrap().1, "민지와 준호");
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+
+    #[tokio::test]
+    async fn title_rename_reindexes_with_file_content() {
+        let (state, user_id) = AppState::for_tests().await;
+        let document = create(&state, &user_id, "초안").await;
+        save_document_content(&state, &document, "첫 문장", &user_id).await.unwrap();
+
+        let req: UpdateDocumentRequest = serde_json::from_value(json!({ "title": "세계관 설정" })).unwrap();
+        let auth_user = AuthUser { user_id: user_id.clone() };
+        let Json(updated) = update_document(State(state.clone()), auth_user, Path(document.id.clone()), Json(req))
+            .await
+            .unwrap();
+        assert_eq!(updated.document.slug, slug::slugify("세계관 설정"));
+        assert!(updated.link_rewrite.is_none());
+
+        assert_eq!(
+            indexed(&state, &document.id).await,
+            Some(("세계관 설정".to_string(), "첫 문장".to_string()))
+        );
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+
+    #[tokio::test]
+    async fn link_rewrite_continues_past_failing_documents() {
+        let (state, user_id) = AppState::for_tests().await;
+        let target = create(&state, &user_id, "인물").await;
+        let mut sources = Vec::new();
+        for title in ["1장", "2장", "3장"] {
+            let source = create(&state, &user_id, title).await;
+            save_document_content(&state, &source, "[[인물]]을 보라", &user_id).await.unwrap();
+            sources.push(source);
+        }
+        // 2장의 파일을 지워 읽기에 실패하게 합니다.
+        std::fs::remove_file(std::path::Path::new(&state.documents_path).join(&sources[1].file_path)).unwrap();
+
+        let req: UpdateDocumentRequest =
+            serde_json::from_value(json!({ "title": "등장인물", "rewrite_links": true })).unwrap();
+        let auth_user = AuthUser { user_id: user_id.clone() };
+        let Json(updated) = update_document(State(state.clone()), auth_user, Path(target.id.clone()), Json(req))
+            .await
+            .unwrap();
+
+        let report = updated.link_rewrite.unwrap();
+        assert_eq!(report.rewritten, 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].id, sources[1].id);
+        for source in [&sources[0], &sources[2]] {
+            let content = services::read_markdown(&state.documents_path, &source.file_path).await.unwrap();
+            assert_eq!(content, "[[등장인물]]을 보라");
+        }
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+
+    #[tokio::test]
+    async fn trashed_documents_leave_search_and_purge_unindexes() {
+        let (state, user_id) = AppState::for_tests().await;
+        let document = create(&state, &user_id, "버릴 글").await;
+        save_document_content(&state, &document, "사라질 문장", &user_id).await.unwrap();
+
+        let search = |q: &str| {
+            let query = services::build_fts_query(q).unwrap();
+            let pool = state.pool.clone();
+            let user_id = user_id.clone();
+            async move {
+                let page = db::SearchPage { limit: 10, offset: 0 };
+                db::search_documents(&pool, &query, &SearchFilters::default(), None, &[], &user_id, page)
+                    .await
+                    .unwrap()
+                    .1
+            }
+        };
+        assert_eq!(search("사라질").await, 1);
+
+        let auth_user = AuthUser { user_id: user_id.clone() };
+        delete_document(State(state.clone()), auth_user, Path(document.id.clone())).await.unwrap();
+        // 휴지통에서는 복원할 수 있도록 색인과 파일을 남겨 두되 검색에서는 빠집니다.
+        assert!(indexed(&state, &document.id).await.is_some());
+        assert!(std::path::Path::new(&state.documents_path).join(&document.file_path).exists());
+        assert_eq!(search("사라질").await, 0);
+
+        let purged = db::purge_trashed_documents(&state.pool, Some(&document.id), &user_id).await.unwrap();
+        assert_eq!(purged, vec![document.file_path.clone()]);
+        let rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM documents_fts")
+            .fetch_one(&state.pool)
+            .await
+            .unwrap();
+        assert_eq!(rows, 0);
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+
+    #[tokio::test]
+    async fn list_hides_archived_documents_unless_asked() {
+        let (state, user_id) = AppState::for_tests().await;
+        create_document_record(&state, Some("진행 중".to_string()), None, &user_id).await.unwrap();
+        let archived = create_document_record(&state, Some("지난 원고".to_string()), None, &user_id).await.unwrap();
+        let archive: UpdateDocumentRequest = serde_json::from_value(json!({ "is_archived": true })).unwrap();
+        db::update_document(&state.pool, &archived.id, &archive, &user_id).await.unwrap();
+
+        let titles = |params: Value| {
+            let state = state.clone();
+            let auth_user = AuthUser { user_id: user_id.clone() };
+            async move {
+                let query = serde_json::from_value(params).unwrap();
+                let Json(response) = list_documents(State(state), auth_user, Query(query)).await.unwrap();
+                let mut titles: Vec<String> = response["documents"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|d| d["title"].as_str().unwrap().to_string())
+                    .collect();
+                titles.sort();
+                titles
+            }
+        };
+        assert_eq!(titles(json!({})).await, ["진행 중"]);
+        assert_eq!(titles(json!({ "include_archived": true })).await, ["지난 원고", "진행 중"]);
+        assert_eq!(titles(json!({ "archived": true })).await, ["지난 원고"]);
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+
+    #[tokio::test]
+    async fn bulk_trash_moves_documents_to_trash() {
+        let (state, user_id) = AppState::for_tests().await;
+        let auth_user = || AuthUser { user_id: user_id.clone() };
+        let first = create(&state, &user_id, "1장").await;
+        let second = create(&state, &user_id, "2장").await;
+
+        let bulk = |body: Value| {
+            let state = state.clone();
+            let auth_user = auth_user();
+            async move {
+                let req: BulkDocumentsRequest = serde_json::from_value(body).unwrap();
+                bulk_documents(State(state), auth_user, Json(req)).await
+            }
+        };
+        let empty = bulk(json!({ "ids": [], "action": "trash" })).await;
+        assert!(matches!(empty, Err(AppError::BadRequest(message)) if message == "ids cannot be empty"));
+
+        let Json(response) =
+            bulk(json!({ "ids": [second.id, "missing", first.id], "action": "trash" })).await.unwrap();
+        assert_eq!(response["succeeded"], 2);
+        assert_eq!(response["results"][1], json!({ "id": "missing", "ok": false, "error": "Document not found" }));
+
+        // 휴지통으로 옮겼을 뿐이므로 파일은 남고, 휴지통 목록에 나옵니다.
+        assert!(db::get_document(&state.pool, &first.id, &user_id).await.unwrap().is_none());
+        assert!(std::path::Path::new(&state.documents_path).join(&first.file_path).exists());
+        assert_eq!(db::list_trashed_documents(&state.pool, &user_id).await.unwrap().len(), 2);
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+
+    #[tokio::test]
+    async fn front_matter_status_follows_document_status() {
+        let (state, user_id) = AppState::for_tests().await;
+        let document = create(&state, &user_id, "1장").await;
+        let status_of = |id: String| {
+            let pool = state.pool.clone();
+            let user_id = user_id.clone();
+            async move { db::get_document(&pool, &id, &user_id).await.unwrap().unwrap().status }
+        };
+
+        // 상태 이름으로 적어도 key로 반영되고 이력에 남습니다.
+        save_document_content(&state, &document, "---\nstatus: 초고\n---\n본문", &user_id).await.unwrap();
+        assert_eq!(status_of(document.id.clone()).await.as_deref(), Some("draft"));
+        assert_eq!(db::list_status_history(&state.pool, &document.id).await.unwrap().len(), 1);
+
+        // 목록에 없는 값은 파일을 쓰기 전에 거절합니다.
+        let unknown = save_document_content(&state, &document, "---\nstatus: 합평\n---\n본문", &user_id).await;
+        assert!(matches!(unknown, Err(AppError::BadRequest(message)) if message == "Unknown status in front matter: 합평"));
+        let content = services::read_markdown(&state.documents_path, &document.file_path).await.unwrap();
+        assert_eq!(content, "---\nstatus: 초고\n---\n본문");
+
+        // `status:` 키가 없으면 상태를 그대로 둡니다.
+        save_document_content(&state, &document, "본문만", &user_id).await.unwrap();
+        assert_eq!(status_of(document.id.clone()).await.as_deref(), Some("draft"));
+
+        // API로 상태를 바꾸면 `status:` 키가 있는 파일에 새 key를 씁니다.
+        save_document_content(&state, &document, "---\nstatus: draft\n---\n\n본문", &user_id).await.unwrap();
+        let req: UpdateDocumentRequest = serde_json::from_value(json!({ "status": "revising" })).unwrap();
+        let auth_user = AuthUser { user_id: user_id.clone() };
+        let Json(updated) = update_document(State(state.clone()), auth_user, Path(document.id.clone()), Json(req)).await.unwrap();
+        let content = services::read_markdown(&state.documents_path, &document.file_path).await.unwrap();
+        assert_eq!(content, "---\nstatus: revising\n---\n\n본문");
+        assert_eq!(updated.document.status.as_deref(), Some("revising"));
+        assert_eq!(db::list_status_history(&state.pool, &document.id).await.unwrap().len(), 2);
+
+        let req: BulkDocumentsRequest =
+            serde_json::from_value(json!({ "ids": [document.id], "action": "set_status", "status": null })).unwrap();
+        let auth_user = AuthUser { user_id: user_id.clone() };
+        let Json(response) = bulk_documents(State(state.clone()), auth_user, Json(req)).await.unwrap();
+        let content = services::read_markdown(&state.documents_path, &document.file_path).await.unwrap();
+        assert_eq!(content, "본문");
+        assert_eq!(response["succeeded"], 1);
+        assert_eq!(status_of(document.id.clone()).await, None);
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+}
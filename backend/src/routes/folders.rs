@@ -7,6 +7,7 @@
 //! - `POST   /api/v1/folders`     → 새 폴더 생성
 //! - `PATCH  /api/v1/folders/:id` → 폴더 수정 (부분 업데이트)
 //! - `DELETE /api/v1/folders/:id` → 폴더 삭제
+//! - `GET    /api/v1/folders/:id/stats` → 폴더 문서들의 단어/글자/원고지 매수 합계
 //!
 //! 폴더는 `parent_id`를 통해 트리(계층) 구조를 형성합니다.
 //! 폴더를 삭제하면 DB 스키마의 `ON DELETE SET NULL`에 의해
@@ -20,12 +21,15 @@ use crate::{
     routes::documents::AppState,
 };
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
+use serde::Deserialize;
 use serde_json::{json, Value};
 
+use super::statuses::status_breakdown;
+
 /// `GET /folders` — 전체 폴더 목록을 조회합니다.
 ///
 /// 정렬 순서(sort_order) → 이름순으로 정렬하여 반환합니다.
@@ -65,6 +69,8 @@ pub async fn update_folder(
     let folder = db::update_folder(&state.pool, &id, &req, &auth_user.user_id)
         .await?
         .ok_or(AppError::NotFound)?;
+    // 폴더 이름/위치가 바뀌면 문서들의 폴더 경로가 바뀝니다.
+    state.quick_open.invalidate(&auth_user.user_id);
     Ok(Json(folder))
 }
 
@@ -82,5 +88,52 @@ pub async fn delete_folder(
     if !deleted {
         return Err(AppError::NotFound);
     }
+    state.quick_open.invalidate(&auth_user.user_id);
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// 폴더 통계 조회용 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct FolderStatsQuery {
+    /// 하위 폴더의 문서까지 합산할지 여부 (기본값: true)
+    pub recursive: Option<bool>,
+}
+
+/// `GET /folders/:id/stats` — 폴더에 속한 문서들의 통계 합계를 조회합니다.
+///
+/// 기본적으로 모든 하위 폴더의 문서까지 합산하며, `?recursive=false`면 해당 폴더만 셉니다.
+/// 응답: `{ "folder_id": "...", "recursive": true, "document_count": 3, "word_count": 1200, ..., "by_status": [...] }`
+/// `by_status`는 상태별 문서 수와 단어 수입니다 (`GET /statuses/stats`와 같은 형식).
+pub async fn get_folder_stats(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Query(query): Query<FolderStatsQuery>,
+) -> Result<Json<Value>, AppError> {
+    let recursive = query.recursive.unwrap_or(true);
+
+    let folder_ids = if recursive {
+        db::list_descendant_folder_ids(&state.pool, &id, &auth_user.user_id).await?
+    } else {
+        db::get_folder(&state.pool, &id, &auth_user.user_id)
+            .await?
+            .map(|f| vec![f.id])
+            .unwrap_or_default()
+    };
+    if folder_ids.is_empty() {
+        return Err(AppError::NotFound);
+    }
+
+    let stats = db::get_folder_stats(&state.pool, &folder_ids, &auth_user.user_id).await?;
+    let by_status = status_breakdown(&state, Some(&folder_ids), None, &auth_user.user_id).await?;
+    Ok(Json(json!({
+        "folder_id": id,
+        "recursive": recursive,
+        "document_count": stats.document_count,
+        "word_count": stats.word_count,
+        "char_count": stats.char_count,
+        "char_count_no_spaces": stats.char_count_no_spaces,
+        "manuscript_pages": stats.manuscript_pages,
+        "by_status": by_status,
+    })))
+}
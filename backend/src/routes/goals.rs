@@ -0,0 +1,344 @@
+//! # 글쓰기 목표 API 라우트 핸들러
+//!
+//! 하루/주간 단어 수 목표와 프로젝트(폴더)별 목표 분량·마감일을 관리하고,
+//! 글쓰기 세션과 문서 단어 수로 진행률을 계산합니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/goals | `get_writing_goals` | 하루/주간 목표 조회 |
+//! | PUT | /api/v1/goals | `update_writing_goals` | 하루/주간 목표 교체 (오늘부터 적용) |
+//! | GET | /api/v1/goals/progress | `get_goal_progress` | 오늘/이번 주/프로젝트 진행률과 달성 이력 |
+//! | GET | /api/v1/goals/projects | `list_project_goals` | 프로젝트 목표 목록 |
+//! | POST | /api/v1/goals/projects | `create_project_goal` | 프로젝트 목표 생성 |
+//! | PATCH | /api/v1/goals/projects/:id | `update_project_goal` | 프로젝트 목표 수정 |
+//! | DELETE | /api/v1/goals/projects/:id | `delete_project_goal` | 프로젝트 목표 삭제 |
+//!
+//! ## 계산 규칙
+//! - 날짜는 UTC 기준이며 한 주는 월요일에 시작합니다 (일지의 `today`와 같음).
+//! - 하루/주간 작성량: 종료된 글쓰기 세션의 단어 수 증가분 합계 (`db::list_daily_words`)
+//! - 프로젝트 분량: 폴더와 하위 폴더 문서의 현재 단어 수 합계 (`GET /folders/:id/stats`와 같음)
+//! - 필요한 하루 분량: 남은 단어 수 ÷ 남은 날 수 (오늘 포함, 올림)
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    models::*,
+    routes::documents::AppState,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// 목표 단어 수의 최댓값
+const MAX_GOAL_WORDS: i64 = 10_000_000;
+/// 달성 이력의 기본 일수
+const DEFAULT_HISTORY_DAYS: i64 = 30;
+/// 달성 이력의 최대 일수
+const MAX_HISTORY_DAYS: i64 = 365;
+/// 프로젝트가 예정대로 가고 있는지 판단할 때 보는 최근 일수
+const RECENT_PACE_DAYS: i64 = 7;
+
+/// 목표 단어 수를 검증합니다 (1 이상 `MAX_GOAL_WORDS` 이하).
+fn validate_goal_words(name: &str, words: i64) -> Result<i64, AppError> {
+    if !(1..=MAX_GOAL_WORDS).contains(&words) {
+        return Err(AppError::BadRequest(format!(
+            "{} must be between 1 and {}",
+            name, MAX_GOAL_WORDS
+        )));
+    }
+    Ok(words)
+}
+
+/// 마감일을 해석합니다 (`YYYY-MM-DD`).
+fn parse_deadline(raw: &str) -> Result<NaiveDate, AppError> {
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .map_err(|_| AppError::BadRequest(format!("Invalid deadline: {} (expected YYYY-MM-DD)", raw)))
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// 남은 단어 수를 남은 날 수에 나눠 하루에 필요한 분량을 계산합니다 (올림).
+///
+/// 이미 다 썼으면 0, 남은 날이 없으면(마감이 지남) None입니다.
+fn required_daily_pace(remaining: i64, days_left: i64) -> Option<i64> {
+    if remaining <= 0 {
+        return Some(0);
+    }
+    (days_left > 0).then(|| (remaining + days_left - 1) / days_left)
+}
+
+/// `GET /api/v1/goals` — 현재 하루/주간 목표 조회
+///
+/// 정한 적이 없으면 `{ "daily_words": null, "weekly_words": null, "effective_from": null }`입니다.
+pub async fn get_writing_goals(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<WritingGoals>, AppError> {
+    let goals = db::get_writing_goals(&state.pool, &auth_user.user_id).await?;
+    Ok(Json(goals))
+}
+
+/// `PUT /api/v1/goals` — 하루/주간 목표 교체
+///
+/// 오늘(UTC)부터 적용됩니다. 지난 날의 달성 여부는 그때의 목표로 판단하므로 바뀌지 않습니다.
+/// 빠지거나 null인 목표는 "목표 없음"이 됩니다.
+pub async fn update_writing_goals(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(req): Json<UpdateWritingGoalsRequest>,
+) -> Result<Json<WritingGoals>, AppError> {
+    let daily_words = req.daily_words.map(|w| validate_goal_words("daily_words", w)).transpose()?;
+    let weekly_words = req.weekly_words.map(|w| validate_goal_words("weekly_words", w)).transpose()?;
+
+    let today = format_date(chrono::Utc::now().date_naive());
+    db::save_writing_goals(&state.pool, &today, daily_words, weekly_words, &auth_user.user_id).await?;
+
+    Ok(Json(WritingGoals { daily_words, weekly_words, effective_from: Some(today) }))
+}
+
+/// `GET /api/v1/goals/projects` — 프로젝트 목표 목록 (마감일이 가까운 순)
+pub async fn list_project_goals(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Value>, AppError> {
+    let goals = db::list_project_goals(&state.pool, &auth_user.user_id).await?;
+    Ok(Json(json!({ "projects": goals })))
+}
+
+/// `POST /api/v1/goals/projects` — 프로젝트 목표 생성
+///
+/// `{ "folder_id": "...", "target_words": 80000, "deadline": "2026-12-31" }`
+/// 폴더가 내 것이 아니면 404, 같은 폴더의 목표가 이미 있으면 409입니다.
+pub async fn create_project_goal(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(req): Json<CreateProjectGoalRequest>,
+) -> Result<Json<ProjectGoal>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    let target_words = validate_goal_words("target_words", req.target_words)?;
+    let deadline = req.deadline.as_deref().map(parse_deadline).transpose()?.map(format_date);
+    db::get_folder(&state.pool, &req.folder_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let goal =
+        db::create_project_goal(&state.pool, &req.folder_id, target_words, deadline.as_deref(), user_id).await?;
+    Ok(Json(goal))
+}
+
+/// `PATCH /api/v1/goals/projects/:id` — 프로젝트 목표 수정
+///
+/// 보낸 필드만 바꿉니다. `deadline: null`은 마감을 없앱니다.
+pub async fn update_project_goal(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateProjectGoalRequest>,
+) -> Result<Json<ProjectGoal>, AppError> {
+    let user_id = &auth_user.user_id;
+    let current = db::get_project_goal(&state.pool, &id, user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let target_words = match req.target_words {
+        Some(words) => validate_goal_words("target_words", words)?,
+        None => current.target_words,
+    };
+    let deadline = match req.deadline {
+        Some(Some(raw)) => Some(format_date(parse_deadline(&raw)?)),
+        Some(None) => None,
+        None => current.deadline,
+    };
+
+    let goal = db::update_project_goal(&state.pool, &id, target_words, deadline.as_deref(), user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    Ok(Json(goal))
+}
+
+/// `DELETE /api/v1/goals/projects/:id` — 프로젝트 목표 삭제 (폴더와 문서는 그대로)
+pub async fn delete_project_goal(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    if !db::delete_project_goal(&state.pool, &id, &auth_user.user_id).await? {
+        return Err(AppError::NotFound);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 진행률 조회용 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct GoalProgressQuery {
+    /// 달성 이력 일수 (어제부터 거슬러 올라감, 기본 30, 최대 365)
+    pub days: Option<i64>,
+}
+
+/// `GET /api/v1/goals/progress` — 오늘/이번 주/프로젝트 진행률과 하루 목표 달성 이력
+///
+/// 응답:
+/// ```json
+/// {
+///   "today": "2026-03-04",
+///   "daily": { "goal": 500, "words": 320, "remaining": 180, "met": false },
+///   "weekly": { "goal": 3000, "week_start": "2026-03-02", "words": 1400, "remaining": 1600, "met": false, "days_left": 5, "required_daily_pace": 320 },
+///   "projects": [{ "id", "folder_id", "folder_name", "target_words", "deadline", "current_words", ... }],
+///   "history": [{ "date": "2026-03-03", "words": 610, "goal": 500, "met": true }],
+///   "met_days": 12, "missed_days": 3
+/// }
+/// ```
+/// 목표가 없으면 `goal`, `remaining`, `met`, `required_daily_pace`는 null입니다.
+/// 이력은 최근 순이며, 하루 목표가 있던 날만 담습니다 (오늘은 아직 끝나지 않았으므로 제외).
+pub async fn get_goal_progress(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<GoalProgressQuery>,
+) -> Result<Json<Value>, AppError> {
+    let user_id = &auth_user.user_id;
+    let today = chrono::Utc::now().date_naive();
+    let days = query.days.unwrap_or(DEFAULT_HISTORY_DAYS).clamp(1, MAX_HISTORY_DAYS);
+
+    // 이번 주(월요일 시작)와 이력 기간을 모두 덮는 날부터 날짜별 작성량을 한 번에 읽습니다.
+    let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let history_start = today - Duration::days(days);
+    let from = format_date(week_start.min(history_start));
+    let words_by_date: HashMap<String, i64> = db::list_daily_words(&state.pool, &from, None, user_id)
+        .await?
+        .into_iter()
+        .map(|d| (d.date, d.words))
+        .collect();
+    let words_on = |date: NaiveDate| words_by_date.get(&format_date(date)).copied().unwrap_or(0);
+
+    let goals = db::get_writing_goals(&state.pool, user_id).await?;
+
+    // ── 오늘 ──
+    let today_words = words_on(today);
+    let daily = json!({
+        "goal": goals.daily_words,
+        "words": today_words,
+        "remaining": goals.daily_words.map(|goal| (goal - today_words).max(0)),
+        "met": goals.daily_words.map(|goal| today_words >= goal),
+    });
+
+    // ── 이번 주 ──
+    let week_words: i64 = week_start.iter_days().take_while(|d| *d <= today).map(words_on).sum();
+    let week_days_left = 7 - today.weekday().num_days_from_monday() as i64;
+    let weekly = json!({
+        "goal": goals.weekly_words,
+        "week_start": format_date(week_start),
+        "words": week_words,
+        "remaining": goals.weekly_words.map(|goal| (goal - week_words).max(0)),
+        "met": goals.weekly_words.map(|goal| week_words >= goal),
+        "days_left": week_days_left,
+        "required_daily_pace": goals.weekly_words.and_then(|goal| required_daily_pace(goal - week_words, week_days_left)),
+    });
+
+    // ── 하루 목표 달성 이력 (어제부터 최근 순) ──
+    // 날마다 그날 적용되던 목표(적용 시작일이 그날 이전인 마지막 기록)와 비교합니다.
+    let goal_history = db::list_writing_goal_history(&state.pool, user_id).await?;
+    let goal_on = |date: &str| {
+        goal_history
+            .iter()
+            .rev()
+            .find(|g| g.effective_from.as_deref().is_some_and(|from| from <= date))
+            .and_then(|g| g.daily_words)
+    };
+    let history: Vec<GoalDay> = (1..=days)
+        .filter_map(|offset| {
+            let date = format_date(today - Duration::days(offset));
+            let goal = goal_on(&date)?;
+            let words = words_by_date.get(&date).copied().unwrap_or(0);
+            Some(GoalDay { date, words, goal, met: words >= goal })
+        })
+        .collect();
+    let met_days = history.iter().filter(|d| d.met).count();
+
+    // ── 프로젝트 ──
+    let mut projects = Vec::new();
+    let recent_from = format_date(today - Duration::days(RECENT_PACE_DAYS - 1));
+    for goal in db::list_project_goals(&state.pool, user_id).await? {
+        projects.push(project_progress(&state, &goal, today, &recent_from, user_id).await?);
+    }
+
+    Ok(Json(json!({
+        "today": format_date(today),
+        "daily": daily,
+        "weekly": weekly,
+        "projects": projects,
+        "history": history,
+        "met_days": met_days,
+        "missed_days": history.len() - met_days,
+    })))
+}
+
+/// 프로젝트 목표 하나의 진행률을 계산합니다.
+///
+/// `status`:
+/// - `met`: 목표 분량에 이름
+/// - `overdue`: 마감일이 지났는데 아직 모자람
+/// - `on_track`: 최근 7일 하루 평균 작성량이 필요한 하루 분량 이상
+/// - `behind`: 최근 7일 하루 평균 작성량이 필요한 하루 분량보다 적음
+/// - `no_deadline`: 마감일 없음
+async fn project_progress(
+    state: &AppState,
+    goal: &ProjectGoal,
+    today: NaiveDate,
+    recent_from: &str,
+    user_id: &str,
+) -> Result<Value, AppError> {
+    let folder = db::get_folder(&state.pool, &goal.folder_id, user_id).await?;
+    let folder_ids = db::list_descendant_folder_ids(&state.pool, &goal.folder_id, user_id).await?;
+    let stats = db::get_folder_stats(&state.pool, &folder_ids, user_id).await?;
+    let words_last_7_days: i64 = db::list_daily_words(&state.pool, recent_from, Some(&folder_ids), user_id)
+        .await?
+        .iter()
+        .map(|d| d.words)
+        .sum();
+
+    let remaining = (goal.target_words - stats.word_count).max(0);
+    let percent = (stats.word_count as f64 / goal.target_words as f64 * 1000.0).round() / 10.0;
+
+    // 마감일까지 남은 날 수 (오늘과 마감일 포함). 마감이 지났으면 0
+    let deadline = goal.deadline.as_deref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+    let days_left = deadline.map(|deadline| ((deadline - today).num_days() + 1).max(0));
+    let pace = days_left.and_then(|days_left| required_daily_pace(remaining, days_left));
+
+    let status = if remaining == 0 {
+        "met"
+    } else {
+        match (days_left, pace) {
+            (None, _) => "no_deadline",
+            (Some(_), None) => "overdue",
+            (Some(_), Some(pace)) if words_last_7_days >= pace * RECENT_PACE_DAYS => "on_track",
+            (Some(_), Some(_)) => "behind",
+        }
+    };
+
+    Ok(json!({
+        "id": goal.id,
+        "folder_id": goal.folder_id,
+        "folder_name": folder.map(|f| f.name),
+        "target_words": goal.target_words,
+        "deadline": goal.deadline,
+        "current_words": stats.word_count,
+        "document_count": stats.document_count,
+        "remaining_words": remaining,
+        "percent": percent,
+        "days_left": days_left,
+        "required_daily_pace": pace,
+        "words_last_7_days": words_last_7_days,
+        "status": status,
+    }))
+}
@@ -0,0 +1,99 @@
+//! # 문서 그래프 API 라우트 핸들러
+//!
+//! 문서/태그/폴더와 그 사이의 관계를 그래프(노드 + 엣지)로 반환합니다.
+//! 프론트엔드에서 세계관 설정집 같은 프로젝트의 지식 그래프를 그리는 데 사용합니다.
+//!
+//! ## 엔드포인트
+//! | 메서드 | 경로 | 설명 |
+//! |--------|------|------|
+//! | GET | /api/v1/graph | 문서 그래프 조회 |
+//!
+//! ## 사용 예시
+//! ```
+//! GET /api/v1/graph                      ← 전체 그래프
+//! GET /api/v1/graph?folder_id=019...     ← 폴더(하위 폴더 포함) 안의 문서만
+//! GET /api/v1/graph?tag_id=019...        ← 태그(와 하위 태그)가 붙은 문서만
+//! ```
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    models::Graph,
+    routes::documents::AppState,
+    services::{self, GraphFilter, GraphSource},
+};
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Deserialize;
+
+/// `GET /graph` 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct GraphQuery {
+    /// 이 폴더와 하위 폴더에 속한 문서만 포함
+    pub folder_id: Option<String>,
+    /// 이 태그나 그 하위 태그가 붙은 문서만 포함
+    pub tag_id: Option<String>,
+    /// 아카이브된 문서도 포함할지 여부 (기본값: false)
+    pub include_archived: Option<bool>,
+}
+
+/// 문서 그래프를 조회합니다.
+///
+/// `GET /api/v1/graph` → `{ "nodes": [...], "edges": [...] }`
+///
+/// 모든 관계는 DB에서 읽어오며 .md 파일은 열지 않습니다.
+/// 위키 링크는 내용 저장 시 `document_links`에 미리 파싱되어 있기 때문입니다.
+///
+/// ## 에러 처리
+/// - 존재하지 않거나 다른 사용자의 폴더/태그로 필터링하면 404 Not Found
+pub async fn get_graph(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<GraphQuery>,
+) -> Result<Json<Graph>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    let folder_ids = match &query.folder_id {
+        Some(folder_id) => {
+            let ids = db::list_descendant_folder_ids(&state.pool, folder_id, user_id).await?;
+            if ids.is_empty() {
+                return Err(AppError::NotFound);
+            }
+            Some(ids.into_iter().collect())
+        }
+        None => None,
+    };
+
+    let tag_ids = match &query.tag_id {
+        Some(tag_id) => {
+            let ids = db::list_descendant_tag_ids(&state.pool, tag_id, user_id).await?;
+            if ids.is_empty() {
+                return Err(AppError::NotFound);
+            }
+            Some(ids.into_iter().collect())
+        }
+        None => None,
+    };
+
+    let source = GraphSource {
+        documents: db::list_graph_documents(
+            &state.pool,
+            user_id,
+            query.include_archived.unwrap_or(false),
+        )
+        .await?,
+        folders: db::list_folders(&state.pool, user_id).await?,
+        tags: db::list_tags(&state.pool, user_id).await?,
+        memberships: db::list_graph_tag_memberships(&state.pool, user_id).await?,
+        links: db::list_graph_links(&state.pool, user_id).await?,
+    };
+    let filter = GraphFilter {
+        folder_ids,
+        tag_ids,
+    };
+
+    Ok(Json(services::build_graph(source, &filter)))
+}
@@ -0,0 +1,234 @@
+//! # 일지(데일리 노트) API 라우트 핸들러
+//!
+//! 날짜마다 문서 하나를 일지로 씁니다. 날짜를 처음 열 때 설정한 폴더에
+//! 설정한 템플릿으로 일지 문서를 만들고, 그 뒤로는 같은 문서를 돌려줍니다.
+//! 일지 문서도 일반 문서이므로 내용 저장, 검색, 태그는 `/documents` API를 그대로 씁니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/journal | `list_journal_days` | 기간 안에서 일지가 있는 날짜와 단어 수 |
+//! | GET | /api/v1/journal/settings | `get_journal_settings` | 일지 설정 조회 |
+//! | PUT | /api/v1/journal/settings | `update_journal_settings` | 일지 설정 교체 |
+//! | GET | /api/v1/journal/:date | `get_journal_entry` | 날짜의 일지 문서 (없으면 만듦) |
+//!
+//! ## 사용 예시
+//! ```
+//! PUT /api/v1/journal/settings
+//! { "folder_id": "<일지 폴더>", "template_id": "<일지 템플릿>", "title_format": "{{date}} {{weekday}}" }
+//!
+//! GET /api/v1/journal/today  → 오늘(UTC) 일지
+//! ```
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    models::*,
+    routes::documents::AppState,
+    services,
+};
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::documents::{create_document_record, save_initial_content};
+
+/// 일지 제목 형식의 최대 길이 (문자 수)
+const MAX_TITLE_FORMAT_LEN: usize = 200;
+
+/// 일지 날짜를 해석합니다. `YYYY-MM-DD` 또는 `today`(UTC 오늘)를 받습니다.
+fn parse_journal_date(raw: &str) -> Result<NaiveDate, AppError> {
+    if raw == "today" {
+        return Ok(chrono::Utc::now().date_naive());
+    }
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .map_err(|_| AppError::BadRequest(format!("Invalid date: {} (expected YYYY-MM-DD or today)", raw)))
+}
+
+/// 일지 목록 조회용 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct JournalRangeQuery {
+    /// 시작 날짜 (포함)
+    pub from: Option<String>,
+    /// 끝 날짜 (포함)
+    pub to: Option<String>,
+}
+
+/// `GET /api/v1/journal?from=2026-03-01&to=2026-03-31` — 일지가 있는 날짜 목록
+///
+/// 캘린더에 일지가 있는 날을 표시하는 용도입니다. 일지가 없는 날은 목록에 없습니다.
+/// 응답: `{ "days": [{ "date", "document_id", "title", "word_count", "char_count", "updated_at" }], "total_days", "total_words" }`
+pub async fn list_journal_days(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<JournalRangeQuery>,
+) -> Result<Json<Value>, AppError> {
+    let from = query.from.as_deref().map(parse_journal_date).transpose()?;
+    let to = query.to.as_deref().map(parse_journal_date).transpose()?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(AppError::BadRequest("from must not be after to".to_string()));
+        }
+    }
+
+    let from = from.map(|d| d.format("%Y-%m-%d").to_string());
+    let to = to.map(|d| d.format("%Y-%m-%d").to_string());
+    let days = db::list_journal_days(&state.pool, from.as_deref(), to.as_deref(), &auth_user.user_id).await?;
+    let total_words: i64 = days.iter().map(|d| d.word_count).sum();
+
+    Ok(Json(json!({
+        "days": days,
+        "total_days": days.len(),
+        "total_words": total_words,
+    })))
+}
+
+/// `GET /api/v1/journal/settings` — 일지 설정 조회
+///
+/// 저장한 적이 없으면 기본값(`folder_id: null`, `template_id: null`, `title_format: "{{date}}"`)을 반환합니다.
+pub async fn get_journal_settings(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<JournalSettings>, AppError> {
+    let settings = db::get_journal_settings(&state.pool, &auth_user.user_id).await?;
+    Ok(Json(settings))
+}
+
+/// `PUT /api/v1/journal/settings` — 일지 설정 교체
+///
+/// 폴더/템플릿이 내 것이 아니면 404, 템플릿으로 표시되지 않은 문서면 400입니다.
+/// 설정을 바꿔도 이미 만든 일지는 그대로입니다 (새로 만드는 일지부터 적용).
+pub async fn update_journal_settings(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(req): Json<UpdateJournalSettingsRequest>,
+) -> Result<Json<JournalSettings>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    if let Some(folder_id) = &req.folder_id {
+        db::get_folder(&state.pool, folder_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+    }
+    if let Some(template_id) = &req.template_id {
+        let template = db::get_document(&state.pool, template_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if template.is_template == 0 {
+            return Err(AppError::BadRequest("Document is not a template".to_string()));
+        }
+    }
+
+    let title_format = match req.title_format {
+        Some(format) => {
+            let format = format.trim().to_string();
+            if format.is_empty() {
+                return Err(AppError::BadRequest("title_format cannot be empty".to_string()));
+            }
+            if format.chars().count() > MAX_TITLE_FORMAT_LEN {
+                return Err(AppError::BadRequest(format!(
+                    "title_format cannot exceed {} characters",
+                    MAX_TITLE_FORMAT_LEN
+                )));
+            }
+            format
+        }
+        None => DEFAULT_JOURNAL_TITLE_FORMAT.to_string(),
+    };
+
+    let settings = JournalSettings {
+        folder_id: req.folder_id,
+        template_id: req.template_id,
+        title_format,
+    };
+    db::save_journal_settings(&state.pool, &settings, user_id).await?;
+    Ok(Json(settings))
+}
+
+/// `GET /api/v1/journal/:date` — 날짜의 일지 문서를 반환합니다. 없으면 만듭니다.
+///
+/// 새 일지는 설정한 폴더에, 설정한 제목 형식과 템플릿으로 만듭니다.
+/// 템플릿의 `{{date}}`, `{{weekday}}`는 요청한 날짜로 치환되므로, 지난 날짜의 일지도 그날 기준으로 만들어집니다.
+/// 응답: `{ "date": "2026-03-02", "created": true, "document": Document }`
+pub async fn get_journal_entry(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(date): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let user_id = &auth_user.user_id;
+    let date = parse_journal_date(&date)?;
+    let date_str = date.format("%Y-%m-%d").to_string();
+
+    if let Some(document) = find_journal_document(&state, &date_str, user_id).await? {
+        return Ok(Json(json!({ "date": date_str, "created": false, "document": document })));
+    }
+
+    let settings = db::get_journal_settings(&state.pool, user_id).await?;
+    let folder = match &settings.folder_id {
+        Some(folder_id) => db::get_folder(&state.pool, folder_id, user_id).await?,
+        None => None,
+    };
+    let folder_name = folder.as_ref().map_or("", |f| f.name.as_str());
+    let now = chrono::Utc::now();
+
+    let title = services::expand_template(
+        &settings.title_format,
+        &services::TemplateContext { title: "", folder: folder_name, date, time: now.time() },
+    );
+    let title = if title.trim().is_empty() { date_str.clone() } else { title.trim().to_string() };
+
+    let document = create_document_record(&state, Some(title), folder.as_ref(), user_id).await?;
+
+    let template = match &settings.template_id {
+        Some(template_id) => db::get_document(&state.pool, template_id, user_id).await?,
+        None => None,
+    };
+    let content = match template {
+        Some(template) => {
+            let content = services::read_markdown(&state.documents_path, &template.file_path).await?;
+            services::expand_template(
+                &content,
+                &services::TemplateContext {
+                    title: &document.title,
+                    folder: folder_name,
+                    date,
+                    time: now.time(),
+                },
+            )
+        }
+        None => String::new(),
+    };
+    let document = save_initial_content(&state, document, &content, user_id).await?;
+
+    // 같은 날짜의 일지를 동시에 만든 요청이 먼저 연결했다면, 방금 만든 문서는 지우고 그쪽을 돌려줍니다.
+    if !db::add_journal_entry(&state.pool, &date_str, &document.id, user_id).await? {
+        db::delete_document(&state.pool, &document.id, user_id).await?;
+        let file_path = std::path::PathBuf::from(&state.documents_path).join(&document.file_path);
+        let _ = tokio::fs::remove_file(file_path).await;
+        state.quick_open.invalidate(user_id);
+
+        let existing = find_journal_document(&state, &date_str, user_id)
+            .await?
+            .ok_or(AppError::Internal("Journal entry disappeared".to_string()))?;
+        return Ok(Json(json!({ "date": date_str, "created": false, "document": existing })));
+    }
+
+    Ok(Json(json!({ "date": date_str, "created": true, "document": document })))
+}
+
+/// 날짜에 연결된 일지 문서를 찾습니다.
+async fn find_journal_document(
+    state: &AppState,
+    date: &str,
+    user_id: &str,
+) -> Result<Option<Document>, AppError> {
+    match db::get_journal_document_id(&state.pool, date, user_id).await? {
+        Some(id) => db::get_document(&state.pool, &id, user_id).await,
+        None => Ok(None),
+    }
+}
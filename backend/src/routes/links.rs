@@ -0,0 +1,58 @@
+//! # 위키 링크 API 라우트 핸들러
+//!
+//! 문서 본문의 `[[문서 제목]]` 링크로 만들어지는 문서 간 연결을 조회합니다.
+//! 링크 목록은 `PUT /documents/:id/content`로 내용을 저장할 때마다 갱신됩니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/documents/:id/backlinks | `get_document_backlinks` | 이 문서를 가리키는 문서 목록 |
+//! | GET | /api/v1/documents/:id/outgoing-links | `get_document_outgoing_links` | 이 문서가 가리키는 링크 목록 |
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    routes::documents::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde_json::{json, Value};
+
+/// 이 문서를 가리키는 다른 문서 목록(백링크)을 조회합니다.
+///
+/// `GET /api/v1/documents/:id/backlinks` → `{ "backlinks": [...] }`
+///
+/// 최근 수정된 문서가 먼저 옵니다.
+pub async fn get_document_backlinks(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let _ = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let backlinks = db::list_backlinks(&state.pool, &id, &auth_user.user_id).await?;
+    Ok(Json(json!({ "backlinks": backlinks })))
+}
+
+/// 이 문서에서 나가는 링크 목록을 조회합니다.
+///
+/// `GET /api/v1/documents/:id/outgoing-links` → `{ "links": [...] }`
+///
+/// 아직 존재하지 않는 문서로의 링크는 `document_id: null`로 반환됩니다.
+pub async fn get_document_outgoing_links(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let _ = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let links = db::list_outgoing_links(&state.pool, &id, &auth_user.user_id).await?;
+    Ok(Json(json!({ "links": links })))
+}
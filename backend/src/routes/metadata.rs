@@ -0,0 +1,91 @@
+//! # 문서 메타데이터(YAML front matter) API 라우트 핸들러
+//!
+//! 문서 맨 앞 front matter의 키/값(status, pov, due, 사용자 정의 필드 ...)을 조회하고 고칩니다.
+//! 파일이 원본이므로 수정은 front matter를 다시 쓰고 내용 저장 경로(`save_document_content`)로 저장하며,
+//! 그 과정에서 `document_metadata` 테이블도 다시 맞춰집니다. 디스크의 .md 파일만 봐도 메타데이터를 알 수 있습니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/documents/:id/metadata | `get_document_metadata` | 메타데이터 조회 |
+//! | PATCH | /api/v1/documents/:id/metadata | `update_document_metadata` | 키 추가/변경/삭제 (front matter 다시 쓰기) |
+//!
+//! ## 사용 예시
+//! ```
+//! PATCH /api/v1/documents/:id/metadata
+//! { "status": "퇴고", "due": "2026-03-31", "pov": null }   ← null은 키 삭제
+//! ```
+//! 메타데이터로 문서를 찾을 때는 `GET /documents?meta=status:퇴고` 또는 `GET /search?meta=...`를 씁니다.
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    routes::documents::AppState,
+    services,
+};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde_json::{json, Map, Value};
+
+use super::documents::save_document_content;
+
+/// `GET /api/v1/documents/:id/metadata` — 문서의 메타데이터 조회
+///
+/// 마지막으로 저장한 내용의 front matter 기준입니다.
+/// 응답: `{ "metadata": { "status": "초고", "characters": ["민지", "현우"] } }` (키 가나다순)
+pub async fn get_document_metadata(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let document = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let metadata = db::get_document_metadata(&state.pool, &document.id).await?;
+    Ok(Json(json!({ "metadata": metadata })))
+}
+
+/// `PATCH /api/v1/documents/:id/metadata` — 메타데이터 키 추가/변경/삭제
+///
+/// 요청 본문의 키만 바뀝니다. 값이 null이면 키를 지웁니다.
+/// front matter를 다시 써서 저장하므로, 버전 스냅샷과 검색 인덱스도 일반 저장처럼 갱신됩니다.
+/// front matter의 YAML이 깨져 있으면 덮어쓰지 않고 400을 반환합니다.
+/// `status`는 문서 상태와 연결되므로 상태 목록에 없는 값이면 400입니다 (`routes::statuses` 참고).
+/// 응답: `{ "metadata": { ... } }` (저장 후 전체 메타데이터)
+pub async fn update_document_metadata(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Json(changes): Json<Map<String, Value>>,
+) -> Result<Json<Value>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    if changes.is_empty() {
+        return Err(AppError::BadRequest("No metadata changes".to_string()));
+    }
+    // 키는 앞뒤 공백 없이, 줄바꿈 같은 제어 문자 없이 써야 합니다.
+    let invalid_key = changes
+        .keys()
+        .find(|k| k.is_empty() || k.trim() != k.as_str() || k.contains(char::is_control));
+    if let Some(key) = invalid_key {
+        return Err(AppError::BadRequest(format!("Invalid metadata key: {:?}", key)));
+    }
+
+    let document = db::get_document(&state.pool, &id, user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let content = services::read_markdown(&state.documents_path, &document.file_path).await?;
+    let updated = services::update_front_matter(&content, &changes)
+        .map_err(|e| AppError::BadRequest(format!("Invalid front matter: {}", e)))?;
+    if updated != content {
+        save_document_content(&state, &document, &updated, user_id).await?;
+    }
+
+    let metadata = db::get_document_metadata(&state.pool, &document.id).await?;
+    Ok(Json(json!({ "metadata": metadata })))
+}
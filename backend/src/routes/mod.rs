@@ -7,26 +7,53 @@
 //! - `auth`: 인증 관련 (회원가입, 로그인, 토큰 갱신, 로그아웃)
 //! - `documents`: 문서 CRUD 핸들러
 //! - `folders`: 폴더 CRUD 핸들러
+//! - `goals`: 글쓰기 목표와 진행률(하루/주간/프로젝트 마감) 핸들러
+//! - `graph`: 문서 그래프(지식 그래프) 핸들러
 //! - `health`: 서버 상태 확인 (헬스체크)
+//! - `journal`: 일지(데일리 노트) 핸들러
+//! - `links`: 문서 간 위키 링크(백링크/나가는 링크) 핸들러
+//! - `metadata`: 문서 메타데이터(YAML front matter) 조회/수정 핸들러
+//! - `outline`: 헤딩 개요 및 섹션 단위 조회/수정 핸들러
+//! - `saved_searches`: 저장된 검색(스마트 폴더) 핸들러
 //! - `search`: 전문검색(FTS5) 핸들러
 //! - `sessions`: 글쓰기 세션 핸들러
+//! - `statuses`: 문서 상태 워크플로(상태 목록, 상태 변경 이력, 상태별 통계) 핸들러
 //! - `tags`: 태그 CRUD 및 문서-태그 관계 핸들러
+//! - `trash`: 휴지통(복원/영구 삭제) 핸들러
 
 pub mod auth;
 pub mod documents;
 pub mod folders;
+pub mod goals;
+pub mod graph;
 pub mod health;
+pub mod journal;
+pub mod links;
+pub mod metadata;
+pub mod outline;
+pub mod saved_searches;
 pub mod search;
 pub mod sessions;
+pub mod statuses;
 pub mod tags;
+pub mod trash;
 pub mod versions;
 
 // 각 모듈의 핸들러 함수들을 재공개하여
 // main.rs에서 `routes::list_documents`처럼 바로 접근 가능하게 합니다.
 pub use documents::*;
 pub use folders::*;
+pub use goals::*;
+pub use graph::*;
 pub use health::*;
+pub use journal::*;
+pub use links::*;
+pub use metadata::*;
+pub use outline::*;
+pub use saved_searches::*;
 pub use search::*;
 pub use sessions::*;
+pub use statuses::*;
 pub use tags::*;
+pub use trash::*;
 pub use versions::*;
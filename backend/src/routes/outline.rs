@@ -0,0 +1,95 @@
+//! # 문서 개요(Outline)와 섹션 API 라우트 핸들러
+//!
+//! 긴 문서를 헤딩 단위로 다루기 위한 엔드포인트입니다.
+//! 섹션 하나만 읽고 쓸 수 있어 전체 파일을 주고받지 않아도 됩니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/documents/:id/outline | `get_document_outline` | 헤딩 개요 |
+//! | GET | /api/v1/documents/:id/sections/:anchor | `get_document_section` | 섹션 내용 조회 |
+//! | PUT | /api/v1/documents/:id/sections/:anchor | `update_document_section` | 섹션 내용 교체 |
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    models::*,
+    routes::documents::{save_document_content, AppState},
+    services,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde_json::{json, Value};
+
+/// 문서의 헤딩 개요를 조회합니다.
+///
+/// `GET /api/v1/documents/:id/outline` → `{ "headings": [...] }`
+///
+/// 각 헤딩에는 레벨, 앵커, 줄 번호, 바이트 오프셋, 섹션별 단어 수가 포함됩니다.
+pub async fn get_document_outline(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let document = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let content = services::read_markdown(&state.documents_path, &document.file_path).await?;
+    let headings = services::parse_outline(&content);
+    Ok(Json(json!({ "headings": headings })))
+}
+
+/// 앵커로 섹션 하나의 내용을 조회합니다.
+///
+/// `GET /api/v1/documents/:id/sections/:anchor` → `{ "heading": {...}, "content": "## ..." }`
+///
+/// `Path((id, anchor))`: 경로 변수 2개를 튜플로 추출합니다.
+/// 앵커는 `GET /outline` 응답의 `anchor` 값을 사용합니다 (URL 인코딩 필요).
+pub async fn get_document_section(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((id, anchor)): Path<(String, String)>,
+) -> Result<Json<DocumentSection>, AppError> {
+    let document = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let content = services::read_markdown(&state.documents_path, &document.file_path).await?;
+    let heading = services::find_section(&content, &anchor).ok_or(AppError::NotFound)?;
+    let section = content[heading.byte_start..heading.section_end].to_string();
+
+    Ok(Json(DocumentSection {
+        heading,
+        content: section,
+    }))
+}
+
+/// 섹션 하나를 새 내용으로 교체합니다.
+///
+/// `PUT /api/v1/documents/:id/sections/:anchor` + `{ "content": "## 제목\n\n새 본문" }` → `204 No Content`
+///
+/// 새 내용은 헤딩 줄을 포함한 섹션 전체(하위 섹션 포함)를 대체합니다.
+/// 저장은 `PUT /documents/:id/content`와 같은 경로를 거치므로
+/// 단어 수, 버전, 검색 인덱스, 위키 링크가 함께 갱신됩니다.
+pub async fn update_document_section(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path((id, anchor)): Path<(String, String)>,
+    Json(req): Json<DocumentContent>,
+) -> Result<StatusCode, AppError> {
+    let document = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let content = services::read_markdown(&state.documents_path, &document.file_path).await?;
+    let heading = services::find_section(&content, &anchor).ok_or(AppError::NotFound)?;
+    let updated = services::replace_section(&content, &heading, &req.content);
+
+    save_document_content(&state, &document, &updated, &auth_user.user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
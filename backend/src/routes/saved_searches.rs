@@ -0,0 +1,194 @@
+//! # 저장된 검색(스마트 폴더) API 라우트 핸들러
+//!
+//! 검색어와 필터를 이름을 붙여 저장하고, 가상의 "스마트 폴더"처럼 열어 볼 수 있게 합니다.
+//! 결과는 저장하지 않고 열 때마다 `GET /search`와 같은 방식으로 다시 검색하므로,
+//! 문서의 태그나 수정일이 바뀌면 스마트 폴더의 내용도 자동으로 바뀝니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/saved-searches | `list_saved_searches` | 저장된 검색 목록 |
+//! | POST | /api/v1/saved-searches | `create_saved_search` | 검색 저장 |
+//! | GET | /api/v1/saved-searches/:id | `get_saved_search` | 저장된 검색 조회 |
+//! | PATCH | /api/v1/saved-searches/:id | `update_saved_search` | 이름/검색어/필터 수정 |
+//! | DELETE | /api/v1/saved-searches/:id | `delete_saved_search` | 삭제 |
+//! | GET | /api/v1/saved-searches/:id/documents | `get_saved_search_documents` | 스마트 폴더 내용 (실시간 검색) |
+//!
+//! ## 사용 예시
+//! ```
+//! POST /api/v1/saved-searches
+//! { "name": "이번 주 챕터 초고", "query": "", "filters": { "tag_ids": ["<#chapter>"], "updated_after": "-7d" } }
+//! ```
+//! 날짜 필터에 `-7d`, `today` 같은 상대 날짜를 쓰면 열 때마다 현재 시각 기준으로 계산됩니다.
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    models::*,
+    routes::documents::AppState,
+    services,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::search::{normalize_dates, run_search, search_page};
+
+/// 스마트 폴더 내용 조회용 페이지네이션 파라미터
+#[derive(Debug, Deserialize)]
+pub struct SavedSearchDocumentsQuery {
+    /// 한 페이지 결과 수 (기본 50, 최대 100)
+    pub limit: Option<i64>,
+    /// 이전 응답의 `next_cursor` 값
+    pub cursor: Option<String>,
+}
+
+/// 저장하기 전에 이름, 검색어, 필터가 올바른지 확인합니다.
+///
+/// 저장할 때 검증해 두면 스마트 폴더를 열 때 400 에러가 나는 일이 없습니다.
+/// (폴더가 나중에 삭제된 경우는 예외 — 열 때 404)
+async fn validate(
+    state: &AppState,
+    user_id: &str,
+    name: &str,
+    query: &str,
+    filters: &SearchFilters,
+) -> Result<(), AppError> {
+    if name.trim().is_empty() {
+        return Err(AppError::BadRequest("Saved search name cannot be empty".to_string()));
+    }
+
+    let fts_query = services::build_fts_query(query).map_err(AppError::BadRequest)?;
+    if fts_query.is_empty() && !filters.has_conditions() {
+        return Err(AppError::BadRequest(
+            "Saved search needs a query or at least one filter".to_string(),
+        ));
+    }
+
+    normalize_dates(&mut filters.clone())?;
+
+    if let Some(folder_id) = &filters.folder_id {
+        db::get_folder(&state.pool, folder_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+    }
+    Ok(())
+}
+
+/// `GET /api/v1/saved-searches` → `{ "saved_searches": [...] }` (이름순)
+pub async fn list_saved_searches(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Value>, AppError> {
+    let saved_searches = db::list_saved_searches(&state.pool, &auth_user.user_id).await?;
+    Ok(Json(json!({ "saved_searches": saved_searches })))
+}
+
+/// `POST /api/v1/saved-searches` + `{ "name", "query", "filters" }` → 생성된 저장된 검색
+///
+/// ## 에러 처리
+/// - 빈 이름, 검색 문법 오류, 검색어와 필터가 모두 없음, 잘못된 날짜: 400
+/// - 존재하지 않는 폴더: 404
+/// - 같은 이름이 이미 있음: 409
+pub async fn create_saved_search(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(req): Json<CreateSavedSearchRequest>,
+) -> Result<Json<SavedSearch>, AppError> {
+    validate(&state, &auth_user.user_id, &req.name, &req.query, &req.filters).await?;
+
+    let saved = db::create_saved_search(
+        &state.pool,
+        req.name.trim(),
+        &req.query,
+        &req.filters,
+        &auth_user.user_id,
+    )
+    .await?;
+    Ok(Json(saved))
+}
+
+/// `GET /api/v1/saved-searches/:id` → 저장된 검색 (검색어와 필터)
+pub async fn get_saved_search(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<SavedSearch>, AppError> {
+    let saved = db::get_saved_search(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    Ok(Json(saved))
+}
+
+/// `PATCH /api/v1/saved-searches/:id` + `{ "name"?, "query"?, "filters"? }`
+///
+/// 보낸 필드만 바꾸며, `filters`는 통째로 교체합니다. 에러는 생성과 같습니다.
+pub async fn update_saved_search(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateSavedSearchRequest>,
+) -> Result<Json<SavedSearch>, AppError> {
+    let current = db::get_saved_search(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    // 기존 값과 합친 결과를 검증합니다. (예: 검색어를 지우면 필터가 남아 있어야 함)
+    let name = req.name.unwrap_or(current.name);
+    let query = req.query.unwrap_or(current.query);
+    let filters = req.filters.unwrap_or(current.filters);
+    validate(&state, &auth_user.user_id, &name, &query, &filters).await?;
+
+    let saved = db::update_saved_search(
+        &state.pool,
+        &id,
+        name.trim(),
+        &query,
+        &filters,
+        &auth_user.user_id,
+    )
+    .await?
+    .ok_or(AppError::NotFound)?;
+    Ok(Json(saved))
+}
+
+/// `DELETE /api/v1/saved-searches/:id` → `204 No Content`
+pub async fn delete_saved_search(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let deleted = db::delete_saved_search(&state.pool, &id, &auth_user.user_id).await?;
+    if !deleted {
+        return Err(AppError::NotFound);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 스마트 폴더를 열어 현재 조건에 맞는 문서를 검색합니다.
+///
+/// `GET /api/v1/saved-searches/:id/documents?limit=50&cursor=...`
+/// → `GET /search`와 같은 `{ "documents": [...], "total": 12, "next_cursor": null }`
+///
+/// ## 에러 처리
+/// - 저장된 검색이 없거나, 필터의 폴더가 삭제됨: 404
+/// - 잘못된 커서: 400
+pub async fn get_saved_search_documents(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Query(query): Query<SavedSearchDocumentsQuery>,
+) -> Result<Json<Value>, AppError> {
+    let saved = db::get_saved_search(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let page = search_page(query.limit, query.cursor.as_deref())?;
+
+    let result = run_search(&state, &auth_user.user_id, &saved.query, &saved.filters, page).await?;
+    Ok(Json(result))
+}
@@ -5,20 +5,30 @@
 //! ## 엔드포인트
 //! | 메서드 | 경로 | 설명 |
 //! |--------|------|------|
-//! | GET | /api/v1/search?q=키워드 | 문서 전문검색 |
+//! | GET | /api/v1/search?q=키워드 | 문서 전문검색 (필터, 정렬, 페이지네이션) |
+//! | POST | /api/v1/search/index/rebuild | 내 문서의 검색 인덱스를 .md 파일에서 다시 만들기 (관리자) |
+//! | GET | /api/v1/search/index/check | 내 문서의 검색 인덱스가 어긋났는지 검사 (관리자) |
+//!
+//! 인덱스 재구성/검사는 모든 파일을 읽는 무거운 작업이므로 `ADMIN_USERNAMES`에 있는 사용자만 쓸 수 있습니다.
+//! 모든 사용자의 인덱스 재구성/검사는 서버 CLI(`tecindo reindex`, `tecindo check-index`)로 합니다.
 //!
 //! ## 사용 예시
 //! ```
 //! GET /api/v1/search?q=프로그래밍
 //! GET /api/v1/search?q=rust+async    ← 공백은 AND 검색
-//! GET /api/v1/search?q=hello*        ← 접두사 검색 (hello로 시작하는 단어)
+//! GET /api/v1/search?q=소설           ← 부분 문자열 검색 ("소설에서", "단편소설"도 일치)
+//! GET /api/v1/search?q="첫 장" -초고 title:노트 단편*   ← 구절, 제외, 제목 한정, 접두사
+//! GET /api/v1/search?q=소설&folder_id=...&tag_ids=a,b&tag_mode=all&sort=updated
+//! GET /api/v1/search?q=소설&cursor=50  ← 이전 응답의 next_cursor로 다음 페이지
 //! ```
 
 use crate::{
     db,
     error::AppError,
     middleware::auth::AuthUser,
+    models::*,
     routes::documents::AppState,
+    services,
 };
 use axum::{
     extract::{Query, State},
@@ -27,41 +37,305 @@ use axum::{
 use serde::Deserialize;
 use serde_json::{json, Value};
 
+/// 한 페이지의 기본 결과 수
+const DEFAULT_PAGE_SIZE: i64 = 50;
+/// 한 페이지의 최대 결과 수
+const MAX_PAGE_SIZE: i64 = 100;
+
 /// URL 쿼리 파라미터를 파싱하는 구조체입니다.
 ///
 /// `GET /api/v1/search?q=키워드`에서 `q=키워드` 부분을 추출합니다.
 /// Axum의 `Query` 추출자가 URL의 쿼리 문자열을 이 구조체로 자동 변환합니다.
 ///
 /// Deserialize만 필요한 이유: 클라이언트 → 서버 방향(URL → 구조체)으로만 변환하므로
+///
+/// 필터 필드는 `SearchFilters`와 같지만, URL에서는 배열을 표현하기 어려워
+/// `tag_ids`를 쉼표로 구분된 문자열로 받습니다.
 #[derive(Debug, Deserialize)]
 pub struct SearchQuery {
-    /// 검색 키워드 (FTS5 검색 문법 사용 가능)
+    /// 검색 키워드 (공백으로 구분된 단어를 모두 포함하는 문서 검색)
+    /// 필터가 하나라도 있으면 생략할 수 있습니다.
+    #[serde(default)]
     pub q: String,
+    pub folder_id: Option<String>,
+    /// 쉼표로 구분된 태그 ID 목록
+    pub tag_ids: Option<String>,
+    pub tag_mode: Option<TagMatchMode>,
+    pub archived: Option<bool>,
+    pub pinned: Option<bool>,
+    pub created_after: Option<String>,
+    pub created_before: Option<String>,
+    pub updated_after: Option<String>,
+    pub updated_before: Option<String>,
+    pub min_words: Option<i64>,
+    /// 쉼표로 구분된 상태 key 목록 (예: `draft,revising`). `none`은 상태가 없는 문서
+    pub status: Option<String>,
+    /// front matter 메타데이터 조건 (예: `status:초고,pov:민지`)
+    pub meta: Option<String>,
+    pub sort: Option<SearchSort>,
+    /// 한 페이지 결과 수 (기본 50, 최대 100)
+    pub limit: Option<i64>,
+    /// 이전 응답의 `next_cursor` 값
+    pub cursor: Option<String>,
+}
+
+impl SearchQuery {
+    fn filters(&self) -> SearchFilters {
+        SearchFilters {
+            folder_id: self.folder_id.clone(),
+            tag_ids: self
+                .tag_ids
+                .as_deref()
+                .unwrap_or("")
+                .split(',')
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+                .collect(),
+            tag_mode: self.tag_mode.unwrap_or_default(),
+            archived: self.archived,
+            pinned: self.pinned,
+            created_after: self.created_after.clone(),
+            created_before: self.created_before.clone(),
+            updated_after: self.updated_after.clone(),
+            updated_before: self.updated_before.clone(),
+            min_words: self.min_words,
+            statuses: self
+                .status
+                .as_deref()
+                .unwrap_or("")
+                .split(',')
+                .map(str::trim)
+                .filter(|status| !status.is_empty())
+                .map(str::to_string)
+                .collect(),
+            metadata: MetadataCondition::parse_list(self.meta.as_deref().unwrap_or("")),
+            sort: self.sort.unwrap_or_default(),
+        }
+    }
+}
+
+/// 페이지네이션 커서를 해석합니다.
+///
+/// 커서는 클라이언트에게는 불투명한(opaque) 문자열이며, 내부적으로는 다음 결과의 시작 위치입니다.
+fn parse_cursor(cursor: Option<&str>) -> Result<i64, AppError> {
+    match cursor {
+        None | Some("") => Ok(0),
+        Some(c) => c
+            .parse::<i64>()
+            .ok()
+            .filter(|offset| *offset >= 0)
+            .ok_or_else(|| AppError::BadRequest("Invalid cursor".to_string())),
+    }
+}
+
+/// `limit`/`cursor` 쿼리 파라미터로 검색 결과 페이지 범위를 만듭니다.
+///
+/// 버전 검색(`routes::versions`)과 문서 목록(`GET /documents`)도 같은 페이지네이션 규칙을 사용합니다.
+pub(crate) fn search_page(limit: Option<i64>, cursor: Option<&str>) -> Result<db::SearchPage, AppError> {
+    Ok(db::SearchPage {
+        limit: limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE),
+        offset: parse_cursor(cursor)?,
+    })
+}
+
+/// 다음 페이지가 있으면 다음 페이지의 커서를 만듭니다.
+pub(crate) fn next_cursor(page: db::SearchPage, returned: usize, total: i64) -> Option<String> {
+    let next_offset = page.offset + returned as i64;
+    (next_offset < total).then(|| next_offset.to_string())
+}
+
+/// 날짜 필터를 검증하고 DB 비교용 형식으로 바꿉니다.
+pub(crate) fn normalize_dates(filters: &mut SearchFilters) -> Result<(), AppError> {
+    let fields = [
+        ("created_after", &mut filters.created_after),
+        ("created_before", &mut filters.created_before),
+        ("updated_after", &mut filters.updated_after),
+        ("updated_before", &mut filters.updated_before),
+    ];
+    for (name, value) in fields {
+        if let Some(raw) = value.as_deref() {
+            let normalized = services::normalize_date_bound(raw).ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Invalid {}: expected YYYY-MM-DD, RFC 3339 date, today, -Nd or -Nw",
+                    name
+                ))
+            })?;
+            *value = Some(normalized);
+        }
+    }
+    Ok(())
+}
+
+/// 검색어와 필터로 검색을 실행하여 `{ documents, total, next_cursor }` 응답을 만듭니다.
+///
+/// `GET /search`와 저장된 검색 실행이 함께 사용합니다.
+///
+/// ## 에러 처리
+/// - 검색 문법 오류 (닫히지 않은 따옴표 등): 400
+/// - 검색어와 필터가 모두 비어 있음: 400
+/// - 날짜 형식 오류: 400
+/// - 존재하지 않는 폴더: 404
+pub(crate) async fn run_search(
+    state: &AppState,
+    user_id: &str,
+    q: &str,
+    filters: &SearchFilters,
+    page: db::SearchPage,
+) -> Result<Value, AppError> {
+    let fts_query = services::build_fts_query(q).map_err(AppError::BadRequest)?;
+    if fts_query.is_empty() && !filters.has_conditions() {
+        return Err(AppError::BadRequest(
+            "Search query cannot be empty".to_string(),
+        ));
+    }
+
+    let mut filters = filters.clone();
+    normalize_dates(&mut filters)?;
+
+    // 폴더 필터는 하위 폴더까지 펼칩니다.
+    let folder_ids = match &filters.folder_id {
+        Some(folder_id) => {
+            let ids = db::list_descendant_folder_ids(&state.pool, folder_id, user_id).await?;
+            if ids.is_empty() {
+                return Err(AppError::NotFound);
+            }
+            Some(ids)
+        }
+        None => None,
+    };
+
+    // 태그 필터는 태그마다 하위 태그까지 펼칩니다. 없는 태그는 아무 문서와도 일치하지 않습니다.
+    let mut tag_groups = Vec::with_capacity(filters.tag_ids.len());
+    for tag_id in &filters.tag_ids {
+        tag_groups.push(db::list_descendant_tag_ids(&state.pool, tag_id, user_id).await?);
+    }
+
+    let (rows, total) = db::search_documents(
+        &state.pool,
+        &fts_query,
+        &filters,
+        folder_ids.as_deref(),
+        &tag_groups,
+        user_id,
+        page,
+    )
+    .await?;
+
+    let next_cursor = next_cursor(page, rows.len(), total);
+
+    let documents: Vec<_> = rows
+        .into_iter()
+        .map(|row| services::build_search_hit(row, &fts_query.terms))
+        .collect();
+
+    Ok(json!({
+        "documents": documents,
+        "total": total,
+        "next_cursor": next_cursor,
+    }))
 }
 
 /// 문서 전문검색을 수행합니다.
 ///
-/// `GET /api/v1/search?q=키워드` → `{ "documents": [...] }`
+/// `GET /api/v1/search?q=키워드` → `{ "documents": [...], "total": 120, "next_cursor": "50" }`
+///
+/// 각 결과는 문서 필드에 더해 `title_highlight`, `snippet`, `match_count`, `matched_in`을 포함합니다.
+/// `next_cursor`가 null이면 마지막 페이지입니다.
 ///
 /// ## 추출자 설명
 /// `Query(query): Query<SearchQuery>`: URL 쿼리 파라미터를 SearchQuery로 파싱합니다.
-/// 예: `?q=hello` → SearchQuery { q: "hello".to_string() }
+/// 예: `?q=hello` → SearchQuery { q: "hello".to_string(), .. }
 ///
 /// ## 에러 처리
-/// - 빈 검색어: 400 Bad Request 반환
-/// - FTS5 문법 에러: SQLite 에러가 전파되어 500 반환
-/// - 정상: 관련도순으로 정렬된 문서 목록 반환 (최대 50건)
+/// - 검색 문법 오류, 빈 검색어 (필터도 없음), 잘못된 날짜/커서: 400 Bad Request 반환
+/// - 존재하지 않는 폴더: 404
+/// - 정상: 정렬된 문서 목록 반환 (기본 50건)
+///
+/// 검색어는 `services::build_fts_query`가 검색 문법(구절, `-`제외, 접두사`*`, `title:`)으로
+/// 해석한 뒤 조건으로 변환하므로, 사용자 입력이 FTS5 MATCH 문법으로 직접 전달되지 않습니다.
 pub async fn search(
     State(state): State<AppState>,
     auth_user: AuthUser,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Value>, AppError> {
-    if query.q.trim().is_empty() {
-        return Err(AppError::BadRequest(
-            "Search query cannot be empty".to_string(),
-        ));
+    let page = search_page(query.limit, query.cursor.as_deref())?;
+
+    let result = run_search(&state, &auth_user.user_id, &query.q, &query.filters(), page).await?;
+    Ok(Json(result))
+}
+
+/// 요청한 사용자가 관리자(`ADMIN_USERNAMES`)인지 확인합니다. 아니면 403입니다.
+async fn ensure_admin(state: &AppState, user_id: &str) -> Result<(), AppError> {
+    let is_admin = match db::users::find_by_id(&state.pool, user_id).await? {
+        Some(user) => state.admin_usernames.contains(&user.username),
+        None => false,
+    };
+    if !is_admin {
+        return Err(AppError::Forbidden("Admin only".to_string()));
     }
+    Ok(())
+}
+
+/// 내 문서의 검색 인덱스를 .md 파일에서 다시 만듭니다 (관리자만).
+///
+/// `POST /api/v1/search/index/rebuild` → `{ "indexed": 120, "failed": [], "orphans_removed": 0 }`
+///
+/// 검색 결과가 실제 내용과 다를 때(`GET /search/index/check`에서 문제가 발견되었을 때) 사용합니다.
+/// 파일을 읽지 못한 문서는 `failed`에 담기고 기존 색인이 유지됩니다.
+pub async fn rebuild_index(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<IndexRebuildReport>, AppError> {
+    ensure_admin(&state, &auth_user.user_id).await?;
+    let report =
+        db::rebuild_search_index(&state.pool, &state.documents_path, Some(&auth_user.user_id))
+            .await?;
+    Ok(Json(report))
+}
 
-    let documents = db::search_documents(&state.pool, &query.q, &auth_user.user_id).await?;
-    Ok(Json(json!({ "documents": documents })))
+/// 내 문서의 검색 인덱스가 DB/파일과 일치하는지 검사합니다 (관리자만).
+///
+/// `GET /api/v1/search/index/check` → `{ "healthy": true, "checked": 120, "missing": [], ... }`
+///
+/// 문제가 있으면 `POST /search/index/rebuild`로 복구할 수 있습니다.
+pub async fn check_index(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Value>, AppError> {
+    ensure_admin(&state, &auth_user.user_id).await?;
+    let report =
+        db::check_search_index(&state.pool, &state.documents_path, Some(&auth_user.user_id))
+            .await?;
+    Ok(Json(json!({
+        "healthy": report.is_healthy(),
+        "checked": report.checked,
+        "missing": report.missing,
+        "stale": report.stale,
+        "unreadable": report.unreadable,
+        "integrity_ok": report.integrity_ok,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn index_maintenance_is_admin_only() {
+        let (mut state, user_id) = AppState::for_tests().await;
+        let auth_user = || AuthUser { user_id: user_id.clone() };
+
+        let rebuild = rebuild_index(State(state.clone()), auth_user()).await;
+        assert!(matches!(rebuild, Err(AppError::Forbidden(_))));
+        let check = check_index(State(state.clone()), auth_user()).await;
+        assert!(matches!(check, Err(AppError::Forbidden(_))));
+
+        // 테스트 사용자(`tester`)를 관리자로 지정하면 허용됩니다.
+        state.admin_usernames = vec!["tester".to_string()];
+        let Json(report) = rebuild_index(State(state.clone()), auth_user()).await.unwrap();
+        assert_eq!(report.indexed, 0);
+        let Json(check) = check_index(State(state.clone()), auth_user()).await.unwrap();
+        assert_eq!(check["healthy"], true);
+    }
 }
@@ -0,0 +1,240 @@
+//! # 문서 상태 워크플로 API 라우트 핸들러
+//!
+//! 문서마다 초고 → 퇴고 → 완성 → 발행 같은 상태를 하나 붙이고, 바뀔 때마다 이력을 남깁니다.
+//! 상태 목록은 사용자마다 바꿀 수 있으며, 저장한 적이 없으면 기본 목록을 씁니다.
+//! 문서의 상태는 `PATCH /documents/:id`의 `status`나 일괄 작업의 `set_status`로 바꿉니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/statuses | `list_document_statuses` | 상태 목록 (순서대로) |
+//! | PUT | /api/v1/statuses | `update_document_statuses` | 상태 목록 교체 |
+//! | GET | /api/v1/statuses/stats | `get_status_stats` | 상태별 문서 수와 단어 수 합계 |
+//! | GET | /api/v1/documents/:id/status-history | `get_document_status_history` | 문서의 상태 변경 이력 |
+//!
+//! ## 사용 예시
+//! ```
+//! PUT /api/v1/statuses
+//! { "statuses": [{ "key": "draft", "name": "초고" }, { "key": "review", "name": "합평", "color": "#f59e0b" }] }
+//!
+//! PATCH /api/v1/documents/:id  { "status": "review" }
+//! GET /api/v1/documents?status=draft,review
+//! ```
+//!
+//! 문서 파일의 front matter에 `status:` 키가 있으면 문서의 `status` 필드와 함께 움직입니다.
+//! - 내용을 저장할 때 `status:` 값(상태 key 또는 이름)을 문서 상태로 반영하고 이력을 남깁니다.
+//!   목록에 없는 값이면 파일을 쓰기 전에 400으로 거절하며, 키가 없으면 상태를 건드리지 않습니다.
+//! - `PATCH /documents/:id`나 `set_status`로 상태를 바꾸면, `status:` 키가 있는 파일에만 새 상태 key를 씁니다.
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    models::*,
+    routes::documents::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde_json::{json, Value};
+
+/// 상태 목록의 최대 개수
+const MAX_STATUSES: usize = 20;
+/// 상태 key의 최대 길이 (문자 수)
+const MAX_STATUS_KEY_LEN: usize = 32;
+/// 상태 이름의 최대 길이 (문자 수)
+const MAX_STATUS_NAME_LEN: usize = 50;
+
+/// 상태 key가 사용자 상태 목록에 있는지 확인합니다. None(상태 지움)은 항상 통과합니다.
+///
+/// `PATCH /documents/:id`와 일괄 작업(`set_status`)이 상태를 바꾸기 전에 사용합니다.
+pub(crate) async fn ensure_known_status(
+    state: &AppState,
+    status: Option<&str>,
+    user_id: &str,
+) -> Result<(), AppError> {
+    let Some(status) = status else {
+        return Ok(());
+    };
+    let statuses = db::list_document_statuses(&state.pool, user_id).await?;
+    if !statuses.iter().any(|s| s.key == status) {
+        return Err(AppError::BadRequest(format!("Unknown status: {}", status)));
+    }
+    Ok(())
+}
+
+/// front matter의 `status:` 값을 문서 상태 key로 바꿉니다.
+///
+/// 상태 key나 상태 이름(`초고`)과 같으면 그 상태의 key이고, null이나 빈 문자열이면 상태 없음(None)입니다.
+/// 이름이 같은 상태가 여럿이면 목록에서 앞선 상태를 고릅니다.
+/// 목록에 없는 값이나 문자열이 아닌 값이면 400입니다.
+pub(crate) fn status_from_front_matter(
+    statuses: &[DocumentStatus],
+    value: &Value,
+) -> Result<Option<String>, AppError> {
+    let text = match value {
+        Value::Null => return Ok(None),
+        Value::String(s) if s.trim().is_empty() => return Ok(None),
+        Value::String(s) => s.trim(),
+        _ => return Err(AppError::BadRequest(format!("Invalid status in front matter: {}", value))),
+    };
+    statuses
+        .iter()
+        .find(|s| s.key == text)
+        .or_else(|| statuses.iter().find(|s| s.name == text))
+        .map(|s| Some(s.key.clone()))
+        .ok_or_else(|| AppError::BadRequest(format!("Unknown status in front matter: {}", text)))
+}
+
+/// 상태별 문서 수와 단어 수를 상태 목록 순서대로 만듭니다 (통계 응답의 `by_status`).
+///
+/// 문서가 없는 상태도 0으로 넣고, 마지막에 상태가 없는 문서(`status: null`)를 붙입니다.
+/// `folder_ids`/`tag_ids`는 `db::list_status_totals`와 같습니다.
+pub(crate) async fn status_breakdown(
+    state: &AppState,
+    folder_ids: Option<&[String]>,
+    tag_ids: Option<&[String]>,
+    user_id: &str,
+) -> Result<Vec<Value>, AppError> {
+    let statuses = db::list_document_statuses(&state.pool, user_id).await?;
+    let totals = db::list_status_totals(&state.pool, folder_ids, tag_ids, user_id).await?;
+    let total_of = |key: Option<&str>| {
+        totals
+            .iter()
+            .find(|t| t.status.as_deref() == key)
+            .map_or((0, 0), |t| (t.document_count, t.word_count))
+    };
+
+    let mut breakdown: Vec<Value> = statuses
+        .iter()
+        .map(|status| {
+            let (document_count, word_count) = total_of(Some(&status.key));
+            json!({
+                "status": status.key,
+                "name": status.name,
+                "color": status.color,
+                "document_count": document_count,
+                "word_count": word_count,
+            })
+        })
+        .collect();
+    let (document_count, word_count) = total_of(None);
+    breakdown.push(json!({
+        "status": null,
+        "name": null,
+        "color": null,
+        "document_count": document_count,
+        "word_count": word_count,
+    }));
+
+    Ok(breakdown)
+}
+
+/// `GET /api/v1/statuses` — 상태 목록 조회
+///
+/// 저장한 적이 없으면 기본 목록(`draft` 초고, `revising` 퇴고, `final` 완성, `published` 발행)을 반환합니다.
+/// 응답: `{ "statuses": [{ "key", "name", "color" }] }`
+pub async fn list_document_statuses(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Value>, AppError> {
+    let statuses = db::list_document_statuses(&state.pool, &auth_user.user_id).await?;
+    Ok(Json(json!({ "statuses": statuses })))
+}
+
+/// `PUT /api/v1/statuses` — 상태 목록 교체
+///
+/// 목록 전체를 교체하며, 보낸 순서가 워크플로 순서가 됩니다.
+/// 문서가 아직 쓰고 있는 상태를 목록에서 빼면 409입니다 (먼저 그 문서들의 상태를 바꿔야 함).
+pub async fn update_document_statuses(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(req): Json<UpdateDocumentStatusesRequest>,
+) -> Result<Json<Value>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    if req.statuses.is_empty() {
+        return Err(AppError::BadRequest("statuses cannot be empty".to_string()));
+    }
+    if req.statuses.len() > MAX_STATUSES {
+        return Err(AppError::BadRequest(format!("At most {} statuses are allowed", MAX_STATUSES)));
+    }
+
+    let mut statuses: Vec<DocumentStatus> = Vec::with_capacity(req.statuses.len());
+    for status in req.statuses {
+        let key = status.key.trim().to_string();
+        let valid_key = !key.is_empty()
+            && key.chars().count() <= MAX_STATUS_KEY_LEN
+            && key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid_key || key == NO_STATUS_FILTER {
+            return Err(AppError::BadRequest(format!(
+                "Invalid status key: {:?} (lowercase letters, digits, - and _; not \"{}\")",
+                status.key, NO_STATUS_FILTER
+            )));
+        }
+        if statuses.iter().any(|s| s.key == key) {
+            return Err(AppError::BadRequest(format!("Duplicate status key: {}", key)));
+        }
+
+        let name = status.name.trim().to_string();
+        if name.is_empty() {
+            return Err(AppError::BadRequest(format!("Status name cannot be empty: {}", key)));
+        }
+        if name.chars().count() > MAX_STATUS_NAME_LEN {
+            return Err(AppError::BadRequest(format!(
+                "Status name cannot exceed {} characters",
+                MAX_STATUS_NAME_LEN
+            )));
+        }
+
+        statuses.push(DocumentStatus { key, name, color: status.color });
+    }
+
+    let removed_in_use: Vec<String> = db::list_used_statuses(&state.pool, user_id)
+        .await?
+        .into_iter()
+        .filter(|(key, _)| !statuses.iter().any(|s| s.key == *key))
+        .map(|(key, count)| format!("{} ({} documents)", key, count))
+        .collect();
+    if !removed_in_use.is_empty() {
+        return Err(AppError::Conflict(format!("Statuses still in use: {}", removed_in_use.join(", "))));
+    }
+
+    db::replace_document_statuses(&state.pool, &statuses, user_id).await?;
+    Ok(Json(json!({ "statuses": statuses })))
+}
+
+/// `GET /api/v1/statuses/stats` — 모든 문서의 상태별 문서 수와 단어 수 합계
+///
+/// 응답: `{ "by_status": [{ "status": "draft", "name": "초고", "color", "document_count": 3, "word_count": 4200 }, ..., { "status": null, ... }] }`
+/// 폴더/태그 통계(`GET /folders/:id/stats`, `GET /tags/:id/stats`)에도 같은 형식의 `by_status`가 있습니다.
+pub async fn get_status_stats(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Value>, AppError> {
+    let by_status = status_breakdown(&state, None, None, &auth_user.user_id).await?;
+    Ok(Json(json!({ "by_status": by_status })))
+}
+
+/// `GET /api/v1/documents/:id/status-history` — 문서의 상태 변경 이력 (최근 순)
+///
+/// 응답: `{ "document_id", "status": "revising", "history": [{ "id", "from_status", "to_status", "changed_at" }] }`
+pub async fn get_document_status_history(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let document = db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let history = db::list_status_history(&state.pool, &document.id).await?;
+    Ok(Json(json!({
+        "document_id": document.id,
+        "status": document.status,
+        "history": history,
+    })))
+}
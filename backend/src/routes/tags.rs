@@ -5,10 +5,12 @@
 //! ## 엔드포인트 목록
 //! | 메서드 | 경로 | 핸들러 | 설명 |
 //! |--------|------|--------|------|
-//! | GET | /api/v1/tags | `list_tags` | 전체 태그 목록 |
+//! | GET | /api/v1/tags | `list_tags` | 전체 태그 목록과 트리 (사용 현황 포함) |
 //! | POST | /api/v1/tags | `create_tag` | 새 태그 생성 |
 //! | PATCH | /api/v1/tags/:id | `update_tag` | 태그 수정 |
 //! | DELETE | /api/v1/tags/:id | `delete_tag` | 태그 삭제 |
+//! | GET | /api/v1/tags/:id/stats | `get_tag_stats` | 태그(와 하위 태그)가 붙은 문서들의 통계 |
+//! | POST | /api/v1/tags/:id/merge | `merge_tags` | 다른 태그들을 이 태그로 합치기 |
 //! | GET | /api/v1/documents/:id/tags | `get_document_tags` | 문서의 태그 목록 |
 //! | POST | /api/v1/documents/:id/tags | `add_tag_to_document` | 문서에 태그 추가 |
 //! | DELETE | /api/v1/documents/:id/tags/:tag_id | `remove_tag_from_document` | 문서에서 태그 제거 |
@@ -18,24 +20,39 @@
 //! - `State(state)`: 애플리케이션 공유 상태 (DB 풀 등)
 //! - `Path(id)`: URL 경로의 변수 (`:id` 부분)
 //! - `Json(req)`: 요청 본문을 구조체로 파싱
+//!
+//! ## 태그 계층
+//! 태그는 `parent_id`로 트리를 이룹니다. 부모 태그로 문서를 필터링하면
+//! (`GET /documents?tag_id=`, `GET /search?tag_ids=`) 하위 태그가 붙은 문서도 함께 나옵니다.
 
 use crate::{
     db,
     error::AppError,
     middleware::auth::AuthUser,
     models::*,
-    routes::documents::AppState,
+    routes::documents::{save_document_content, AppState},
+    services,
 };
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
+use serde::Deserialize;
 use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+
+use super::statuses::status_breakdown;
 
 /// 전체 태그 목록을 조회합니다.
 ///
-/// `GET /api/v1/tags` → `{ "tags": [...] }`
+/// `GET /api/v1/tags` → `{ "tags": [...], "tree": [...] }`
+///
+/// - `tags`: 이름순 평면 목록 (각 태그의 `parent_id` 포함)
+/// - `tree`: 같은 태그들을 최상위 태그부터 `children`으로 중첩한 트리 (각 노드에 `path` 포함)
+///
+/// 각 태그에는 `document_count`, `word_count`, `last_used_at`이 함께 들어 있어
+/// 쓰이지 않는 태그(`document_count == 0`)를 찾아 정리할 수 있습니다.
 ///
 /// Axum에서 핸들러의 반환 타입이 `Result<Json<Value>, AppError>`이면:
 /// - 성공(Ok): JSON 응답을 200 상태로 반환
@@ -44,8 +61,88 @@ pub async fn list_tags(
     State(state): State<AppState>,
     auth_user: AuthUser,
 ) -> Result<Json<Value>, AppError> {
-    let tags = db::list_tags(&state.pool, &auth_user.user_id).await?;
-    Ok(Json(json!({ "tags": tags })))
+    let tags = db::list_tags_with_usage(&state.pool, &auth_user.user_id).await?;
+    let tree = build_tag_tree(&tags);
+    Ok(Json(json!({ "tags": tags, "tree": tree })))
+}
+
+/// 평면 태그 목록(이름순)을 트리로 만듭니다.
+///
+/// 부모별로 자식 목록을 모은 뒤 최상위 태그(부모 없음)부터 재귀적으로 내려가며 노드를 만듭니다.
+/// 입력이 이름순이므로 각 단계의 자식들도 이름순입니다.
+/// 부모가 목록에 없는 태그는 최상위로 취급하며, 순환(A → B → A)에 걸린 태그는 트리에서 빠집니다.
+fn build_tag_tree(tags: &[TagWithUsage]) -> Vec<TagTreeNode> {
+    let ids: HashSet<&str> = tags.iter().map(|t| t.tag.id.as_str()).collect();
+    let mut children: HashMap<Option<&str>, Vec<&TagWithUsage>> = HashMap::new();
+    for tag in tags {
+        let parent = tag.tag.parent_id.as_deref().filter(|p| ids.contains(p));
+        children.entry(parent).or_default().push(tag);
+    }
+
+    fn build(
+        parent: Option<&str>,
+        prefix: &str,
+        children: &HashMap<Option<&str>, Vec<&TagWithUsage>>,
+    ) -> Vec<TagTreeNode> {
+        children
+            .get(&parent)
+            .map(|tags| {
+                tags.iter()
+                    .map(|tag| {
+                        let path = if prefix.is_empty() {
+                            tag.tag.name.clone()
+                        } else {
+                            format!("{}/{}", prefix, tag.tag.name)
+                        };
+                        TagTreeNode {
+                            children: build(Some(&tag.tag.id), &path, children),
+                            tag: (*tag).clone(),
+                            path,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    build(None, "", &children)
+}
+
+/// 태그 이름을 검사합니다. 경로 구분자 `/`는 쓸 수 없습니다.
+fn validate_tag_name(name: &str) -> Result<(), AppError> {
+    if name.trim().is_empty() {
+        return Err(AppError::BadRequest("Tag name cannot be empty".to_string()));
+    }
+    if name.contains('/') {
+        return Err(AppError::BadRequest(
+            "Tag name cannot contain '/'".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 부모로 지정한 태그가 이 사용자의 태그인지 확인합니다.
+///
+/// `tag_id`(수정 중인 태그)를 넘기면 자기 자신이나 자신의 하위 태그를 부모로 삼는 것도 막습니다.
+/// (그러면 트리에 순환이 생겨 어느 태그도 최상위에 닿지 못합니다)
+async fn validate_parent_tag(
+    state: &AppState,
+    parent_id: &str,
+    tag_id: Option<&str>,
+    user_id: &str,
+) -> Result<(), AppError> {
+    if db::get_tag(&state.pool, parent_id, user_id).await?.is_none() {
+        return Err(AppError::BadRequest("Parent tag not found".to_string()));
+    }
+    if let Some(tag_id) = tag_id {
+        let descendants = db::list_descendant_tag_ids(&state.pool, tag_id, user_id).await?;
+        if descendants.iter().any(|id| id == parent_id) {
+            return Err(AppError::BadRequest(
+                "Tag cannot be moved under itself or its descendants".to_string(),
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// 새 태그를 생성합니다.
@@ -59,6 +156,10 @@ pub async fn create_tag(
     auth_user: AuthUser,
     Json(req): Json<CreateTagRequest>,
 ) -> Result<Json<Tag>, AppError> {
+    validate_tag_name(&req.name)?;
+    if let Some(parent_id) = &req.parent_id {
+        validate_parent_tag(&state, parent_id, None, &auth_user.user_id).await?;
+    }
     let tag = db::create_tag(&state.pool, &req, &auth_user.user_id).await?;
     Ok(Json(tag))
 }
@@ -75,12 +176,154 @@ pub async fn update_tag(
     Path(id): Path<String>,
     Json(req): Json<UpdateTagRequest>,
 ) -> Result<Json<Tag>, AppError> {
+    if let Some(name) = &req.name {
+        validate_tag_name(name)?;
+    }
+    if let Some(Some(parent_id)) = &req.parent_id {
+        validate_parent_tag(&state, parent_id, Some(&id), &auth_user.user_id).await?;
+    }
     let tag = db::update_tag(&state.pool, &id, &req, &auth_user.user_id)
         .await?
         .ok_or(AppError::NotFound)?;
+    state.quick_open.invalidate(&auth_user.user_id);
     Ok(Json(tag))
 }
 
+/// 다른 태그들을 이 태그로 합칩니다.
+///
+/// `POST /api/v1/tags/:id/merge` + `{ "source_ids": [...], "rewrite_hashtags": true }`
+/// → `{ "tag": 대상 태그, "report": TagMergeReport }`
+///
+/// "worldbuilding"과 "world-building"처럼 같은 뜻의 태그가 생겼을 때 문서를 일일이 다시 태그하지 않고 정리합니다.
+/// 원래 태그의 문서 연결, 하위 태그, 저장된 검색 필터는 대상 태그로 옮겨지고 원래 태그는 삭제됩니다.
+///
+/// ## 해시태그 다시 쓰기 (`rewrite_hashtags`)
+/// 본문의 `#원래태그`를 `#대상태그`로 바꿉니다. 파일과 DB를 한 트랜잭션으로 묶을 수는 없으므로:
+/// 1. 바꿀 문서를 모두 읽어 새 내용을 미리 만듭니다 (읽기 실패 시 아무것도 바꾸지 않고 중단)
+/// 2. 파일을 저장합니다 (`save_document_content`)
+/// 3. DB의 태그를 합칩니다 (한 트랜잭션)
+///
+/// 2나 3에서 실패하면 이미 저장한 파일을 원래 내용으로 되돌린 뒤 에러를 반환합니다.
+///
+/// ## 에러
+/// - 대상 태그가 없으면 404
+/// - `source_ids`가 비었거나, 원래 태그가 없거나, 대상 자신이나 대상의 상위 태그를 합치려 하면 400
+pub async fn merge_tags(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Json(req): Json<MergeTagsRequest>,
+) -> Result<Json<Value>, AppError> {
+    let user_id = &auth_user.user_id;
+    let target = db::get_tag(&state.pool, &id, user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let mut source_ids: Vec<String> = Vec::with_capacity(req.source_ids.len());
+    for source_id in req.source_ids {
+        if !source_ids.contains(&source_id) {
+            source_ids.push(source_id);
+        }
+    }
+    if source_ids.is_empty() {
+        return Err(AppError::BadRequest("source_ids cannot be empty".to_string()));
+    }
+    if source_ids.contains(&target.id) {
+        return Err(AppError::BadRequest(
+            "Cannot merge a tag into itself".to_string(),
+        ));
+    }
+
+    let mut source_names = Vec::with_capacity(source_ids.len());
+    for source_id in &source_ids {
+        let source = db::get_tag(&state.pool, source_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("Source tag not found: {}", source_id)))?;
+        // 대상이 원래 태그의 하위 태그면, 원래 태그가 지워질 때 대상이 옮겨 갈 부모가 사라집니다.
+        let descendants = db::list_descendant_tag_ids(&state.pool, source_id, user_id).await?;
+        if descendants.contains(&target.id) {
+            return Err(AppError::BadRequest(
+                "Cannot merge a tag into its own descendant".to_string(),
+            ));
+        }
+        source_names.push(source.name);
+    }
+
+    // 1. 해시태그를 다시 쓸 문서와 새 내용을 미리 준비합니다 (아직 아무것도 바꾸지 않음)
+    let rewrites = if req.rewrite_hashtags {
+        plan_hashtag_rewrites(&state, &source_names, &target.name, user_id).await?
+    } else {
+        Vec::new()
+    };
+
+    // 2. 파일 저장. 실패하면 이미 저장한 문서를 되돌립니다.
+    let mut written: Vec<(&Document, &str)> = Vec::with_capacity(rewrites.len());
+    for (document, original, updated) in &rewrites {
+        if let Err(e) = save_document_content(&state, document, updated, user_id).await {
+            restore_contents(&state, &written, user_id).await;
+            return Err(e);
+        }
+        written.push((document, original));
+    }
+
+    // 3. DB의 태그 합치기 (한 트랜잭션). 실패하면 파일도 되돌립니다.
+    let mut report = match db::merge_tags(&state.pool, &target.id, &source_ids, user_id).await {
+        Ok(report) => report,
+        Err(e) => {
+            restore_contents(&state, &written, user_id).await;
+            return Err(e);
+        }
+    };
+    report.documents_rewritten = written.len();
+    state.quick_open.invalidate(user_id);
+
+    tracing::info!(
+        "Merged tags {:?} into '{}' ({} document(s) rewritten)",
+        source_names,
+        target.name,
+        report.documents_rewritten
+    );
+    Ok(Json(json!({ "tag": target, "report": report })))
+}
+
+/// `#원래태그`가 들어 있는 문서를 찾아 해시태그를 바꾼 새 내용을 만듭니다.
+///
+/// 검색 인덱스로 후보 문서만 고른 뒤 실제 파일을 읽어 다시 확인합니다.
+/// 반환값: `(문서, 원래 내용, 새 내용)` 목록 — 실제로 바뀌는 문서만 포함
+async fn plan_hashtag_rewrites(
+    state: &AppState,
+    old_names: &[String],
+    new_name: &str,
+    user_id: &str,
+) -> Result<Vec<(Document, String, String)>, AppError> {
+    let needles: Vec<String> = old_names.iter().map(|name| format!("#{}", name)).collect();
+    let old_names: Vec<&str> = old_names.iter().map(String::as_str).collect();
+
+    let mut rewrites = Vec::new();
+    for document in db::list_documents_containing(&state.pool, &needles, user_id).await? {
+        let content = services::read_markdown(&state.documents_path, &document.file_path).await?;
+        if let Some(updated) = services::rewrite_hashtags(&content, &old_names, new_name) {
+            rewrites.push((document, content, updated));
+        }
+    }
+    Ok(rewrites)
+}
+
+/// 태그 합치기가 실패했을 때 이미 다시 쓴 문서들을 원래 내용으로 되돌립니다.
+///
+/// 되돌리기마저 실패하면 할 수 있는 일이 없으므로 로그만 남깁니다.
+async fn restore_contents(state: &AppState, written: &[(&Document, &str)], user_id: &str) {
+    for (document, original) in written {
+        if let Err(e) = save_document_content(state, document, original, user_id).await {
+            tracing::error!(
+                "Failed to restore {} after failed tag merge: {}",
+                document.file_path,
+                e
+            );
+        }
+    }
+}
+
 /// 태그를 삭제합니다.
 ///
 /// `DELETE /api/v1/tags/:id` → `204 No Content`
@@ -96,9 +339,59 @@ pub async fn delete_tag(
     if !deleted {
         return Err(AppError::NotFound);
     }
+    state.quick_open.invalidate(&auth_user.user_id);
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// 태그 통계 조회용 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct TagStatsQuery {
+    /// 하위 태그가 붙은 문서까지 합산할지 여부 (기본값: true)
+    pub recursive: Option<bool>,
+}
+
+/// `GET /tags/:id/stats` — 태그가 붙은 문서들의 통계 합계를 조회합니다.
+///
+/// 기본적으로 모든 하위 태그가 붙은 문서까지 합산하며, `?recursive=false`면 이 태그만 셉니다.
+/// 응답: `{ "tag_id": "...", "recursive": true, "document_count": 3, "word_count": 1200, ..., "last_used_at": "...", "by_status": [...] }`
+/// `by_status`는 상태별 문서 수와 단어 수입니다 (`GET /statuses/stats`와 같은 형식).
+pub async fn get_tag_stats(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Query(query): Query<TagStatsQuery>,
+) -> Result<Json<Value>, AppError> {
+    let recursive = query.recursive.unwrap_or(true);
+
+    let tag_ids = if recursive {
+        db::list_descendant_tag_ids(&state.pool, &id, &auth_user.user_id).await?
+    } else {
+        db::get_tag(&state.pool, &id, &auth_user.user_id)
+            .await?
+            .map(|t| vec![t.id])
+            .unwrap_or_default()
+    };
+    if tag_ids.is_empty() {
+        return Err(AppError::NotFound);
+    }
+
+    let stats = db::get_tag_stats(&state.pool, &tag_ids, &auth_user.user_id).await?;
+    let by_status = status_breakdown(&state, None, Some(&tag_ids), &auth_user.user_id).await?;
+    Ok(Json(json!({
+        "tag_id": id,
+        "recursive": recursive,
+        "document_count": stats.document_count,
+        "archived_count": stats.archived_count,
+        "word_count": stats.word_count,
+        "char_count": stats.char_count,
+        "char_count_no_spaces": stats.char_count_no_spaces,
+        "manuscript_pages": stats.manuscript_pages,
+        "first_used_at": stats.first_used_at,
+        "last_used_at": stats.last_used_at,
+        "by_status": by_status,
+    })))
+}
+
 /// 특정 문서에 연결된 태그 목록을 조회합니다.
 ///
 /// `GET /api/v1/documents/:id/tags` → `{ "tags": [...] }`
@@ -139,6 +432,7 @@ pub async fn add_tag_to_document(
         .ok_or(AppError::NotFound)?;
 
     db::add_tag_to_document(&state.pool, &id, &req.tag_id).await?;
+    state.quick_open.invalidate(&auth_user.user_id);
     Ok(StatusCode::CREATED)
 }
 
@@ -162,5 +456,121 @@ pub async fn remove_tag_from_document(
     if !removed {
         return Err(AppError::NotFound);
     }
+    state.quick_open.invalidate(&auth_user.user_id);
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(id: &str, parent_id: Option<&str>) -> TagWithUsage {
+        TagWithUsage {
+            tag: Tag {
+                id: id.to_string(),
+                name: id.to_string(),
+                color: None,
+                parent_id: parent_id.map(str::to_string),
+            },
+            document_count: 0,
+            word_count: 0,
+            last_used_at: None,
+        }
+    }
+
+    /// 트리를 `경로` 목록으로 펼칩니다 (깊이 우선).
+    fn paths(nodes: &[TagTreeNode]) -> Vec<String> {
+        nodes.iter().flat_map(|n| std::iter::once(n.path.clone()).chain(paths(&n.children))).collect()
+    }
+
+    #[test]
+    fn tag_tree_nests_children_under_parents() {
+        let tags = [
+            usage("소설", None),
+            usage("장편", Some("소설")),
+            usage("판타지", Some("장편")),
+            usage("일기", None),
+        ];
+        assert_eq!(paths(&build_tag_tree(&tags)), ["소설", "소설/장편", "소설/장편/판타지", "일기"]);
+    }
+
+    #[test]
+    fn tag_tree_promotes_orphans_and_drops_cycles() {
+        let tags = [usage("고아", Some("없는 태그")), usage("a", Some("b")), usage("b", Some("a"))];
+        assert_eq!(paths(&build_tag_tree(&tags)), ["고아"]);
+    }
+
+    #[tokio::test]
+    async fn parent_tag_filter_includes_descendant_tags() {
+        let (pool, user_id) = db::test_pool().await;
+        let tag = |name: &str, parent_id: Option<String>| {
+            let req = CreateTagRequest { name: name.to_string(), color: None, parent_id };
+            let (pool, user_id) = (pool.clone(), user_id.clone());
+            async move { db::create_tag(&pool, &req, &user_id).await.unwrap() }
+        };
+        let novel = tag("소설", None).await;
+        let fantasy = tag("판타지", Some(novel.id.clone())).await;
+        let diary = tag("일기", None).await;
+
+        for (title, tag_id) in [("용의 땅", &fantasy.id), ("오늘", &diary.id)] {
+            let id = uuid::Uuid::now_v7().to_string();
+            let req = CreateDocumentRequest { title: Some(title.to_string()), folder_id: None, template_id: None };
+            db::create_document(&pool, &id, &req, format!("{}.md", id), slug::slugify(title), &user_id)
+                .await
+                .unwrap();
+            db::add_tag_to_document(&pool, &id, tag_id).await.unwrap();
+        }
+
+        let mut ids = db::list_descendant_tag_ids(&pool, &novel.id, &user_id).await.unwrap();
+        ids.sort();
+        let mut expected = vec![novel.id.clone(), fantasy.id.clone()];
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        let filters = DocumentListFilters { tag_groups: vec![ids], ..Default::default() };
+        let (documents, _) = db::list_documents(&pool, &filters, &user_id, None).await.unwrap();
+        let titles: Vec<&str> = documents.iter().map(|d| d.title.as_str()).collect();
+        assert_eq!(titles, ["용의 땅"]);
+    }
+
+    #[tokio::test]
+    async fn merge_rewrites_hashtags_only_when_asked() {
+        for rewrite_hashtags in [false, true] {
+            let (state, user_id) = AppState::for_tests().await;
+            let auth_user = || AuthUser { user_id: user_id.clone() };
+            let req = CreateDocumentRequest { title: Some("지도".to_string()), folder_id: None, template_id: None };
+            let Json(document) =
+                crate::routes::documents::create_document(State(state.clone()), auth_user(), Json(req))
+                    .await
+                    .unwrap();
+            save_document_content(&state, &document, "#world-building 대륙", &user_id).await.unwrap();
+
+            let tag = |name: &str| {
+                let req = CreateTagRequest { name: name.to_string(), color: None, parent_id: None };
+                let (pool, user_id) = (state.pool.clone(), user_id.clone());
+                async move { db::create_tag(&pool, &req, &user_id).await.unwrap() }
+            };
+            let target = tag("worldbuilding").await;
+            // 저장할 때 `#world-building` 해시태그로 태그가 자동으로 만들어져 붙습니다.
+            let source = db::list_tags(&state.pool, &user_id)
+                .await
+                .unwrap()
+                .into_iter()
+                .find(|t| t.name == "world-building")
+                .unwrap();
+
+            let req = MergeTagsRequest { source_ids: vec![source.id.clone()], rewrite_hashtags };
+            let Json(response) =
+                merge_tags(State(state.clone()), auth_user(), Path(target.id.clone()), Json(req)).await.unwrap();
+            assert_eq!(response["report"]["documents_rewritten"], usize::from(rewrite_hashtags));
+
+            let tags = db::get_document_tags(&state.pool, &document.id).await.unwrap();
+            assert_eq!(tags.iter().map(|t| t.tag.id.as_str()).collect::<Vec<_>>(), [target.id.as_str()]);
+            let content = services::read_markdown(&state.documents_path, &document.file_path).await.unwrap();
+            let expected = if rewrite_hashtags { "#worldbuilding 대륙" } else { "#world-building 대륙" };
+            assert_eq!(content, expected);
+
+            let _ = std::fs::remove_dir_all(&state.documents_path);
+        }
+    }
+}
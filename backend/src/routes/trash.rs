@@ -0,0 +1,153 @@
+//! # 휴지통 API 라우트 핸들러
+//!
+//! `DELETE /documents/:id`와 일괄 작업의 `trash`는 문서를 지우지 않고 휴지통으로 옮깁니다.
+//! 휴지통의 문서는 목록/검색/그래프/통계에서 빠지고, 여기서 복원하거나 영구 삭제합니다.
+//!
+//! ## 엔드포인트 목록
+//! | 메서드 | 경로 | 핸들러 | 설명 |
+//! |--------|------|--------|------|
+//! | GET | /api/v1/trash | `list_trash` | 휴지통의 문서 목록 (최근에 버린 순) |
+//! | DELETE | /api/v1/trash | `empty_trash` | 휴지통 비우기 (모두 영구 삭제) |
+//! | POST | /api/v1/trash/:id/restore | `restore_document` | 문서 복원 |
+//! | DELETE | /api/v1/trash/:id | `purge_document` | 문서 하나 영구 삭제 |
+//!
+//! 영구 삭제는 DB 트랜잭션이 커밋된 뒤에 .md 파일을 지웁니다.
+//! 커밋이 실패하면 문서는 휴지통에 그대로 남고 파일도 지워지지 않습니다.
+
+use crate::{
+    db,
+    error::AppError,
+    middleware::auth::AuthUser,
+    routes::documents::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde_json::{json, Value};
+
+/// 영구 삭제한 문서들의 .md 파일을 지웁니다. 이미 없는 파일은 무시합니다.
+async fn remove_document_files(state: &AppState, file_paths: &[String]) {
+    for file_path in file_paths {
+        let path = std::path::PathBuf::from(&state.documents_path).join(file_path);
+        let _ = tokio::fs::remove_file(path).await;
+    }
+}
+
+/// `GET /api/v1/trash` — 휴지통의 문서 목록
+///
+/// 응답: `{ "documents": [{ "id", "folder_id", "title", "word_count", "updated_at", "deleted_at" }] }`
+pub async fn list_trash(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Value>, AppError> {
+    let documents = db::list_trashed_documents(&state.pool, &auth_user.user_id).await?;
+    Ok(Json(json!({ "documents": documents })))
+}
+
+/// `POST /api/v1/trash/:id/restore` — 휴지통의 문서를 되돌립니다.
+///
+/// 원래 폴더가 그사이 삭제되었다면 루트로 돌아옵니다. 응답은 복원된 문서입니다.
+/// 휴지통에 없는 문서면 404입니다.
+pub async fn restore_document(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let user_id = &auth_user.user_id;
+
+    if !db::restore_document(&state.pool, &id, user_id).await? {
+        return Err(AppError::NotFound);
+    }
+    state.quick_open.invalidate(user_id);
+
+    let document = db::get_document(&state.pool, &id, user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    Ok(Json(json!(document)))
+}
+
+/// `DELETE /api/v1/trash/:id` — 휴지통의 문서 하나를 영구 삭제합니다.
+///
+/// 휴지통에 없는 문서면 404입니다 (먼저 `DELETE /documents/:id`로 휴지통에 옮겨야 함).
+pub async fn purge_document(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let file_paths = db::purge_trashed_documents(&state.pool, Some(&id), &auth_user.user_id).await?;
+    if file_paths.is_empty() {
+        return Err(AppError::NotFound);
+    }
+    remove_document_files(&state, &file_paths).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `DELETE /api/v1/trash` — 휴지통을 비웁니다.
+///
+/// 응답: `{ "purged": 3 }` (영구 삭제한 문서 수)
+pub async fn empty_trash(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+) -> Result<Json<Value>, AppError> {
+    let file_paths = db::purge_trashed_documents(&state.pool, None, &auth_user.user_id).await?;
+    remove_document_files(&state, &file_paths).await;
+
+    Ok(Json(json!({ "purged": file_paths.len() })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::documents::{create_document_record, delete_document, save_document_content};
+
+    #[tokio::test]
+    async fn restore_brings_back_a_trashed_document() {
+        let (state, user_id) = AppState::for_tests().await;
+        let auth_user = || AuthUser { user_id: user_id.clone() };
+        let document = create_document_record(&state, Some("초고".to_string()), None, &user_id).await.unwrap();
+
+        delete_document(State(state.clone()), auth_user(), Path(document.id.clone())).await.unwrap();
+        assert!(db::get_document(&state.pool, &document.id, &user_id).await.unwrap().is_none());
+        let Json(trash) = list_trash(State(state.clone()), auth_user()).await.unwrap();
+        assert_eq!(trash["documents"][0]["id"], document.id);
+
+        let Json(restored) = restore_document(State(state.clone()), auth_user(), Path(document.id.clone()))
+            .await
+            .unwrap();
+        assert_eq!(restored["title"], "초고");
+        assert!(db::get_document(&state.pool, &document.id, &user_id).await.unwrap().is_some());
+        // 휴지통에 없는 문서는 복원할 수 없습니다.
+        let again = restore_document(State(state.clone()), auth_user(), Path(document.id.clone())).await;
+        assert!(matches!(again, Err(AppError::NotFound)));
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+
+    #[tokio::test]
+    async fn empty_trash_removes_rows_and_files() {
+        let (state, user_id) = AppState::for_tests().await;
+        let auth_user = || AuthUser { user_id: user_id.clone() };
+        let kept = create_document_record(&state, Some("남길 글".to_string()), None, &user_id).await.unwrap();
+        let trashed = create_document_record(&state, Some("버릴 글".to_string()), None, &user_id).await.unwrap();
+        save_document_content(&state, &trashed, "본문", &user_id).await.unwrap();
+        delete_document(State(state.clone()), auth_user(), Path(trashed.id.clone())).await.unwrap();
+
+        // 휴지통에 없는 문서는 바로 영구 삭제할 수 없습니다.
+        let result = purge_document(State(state.clone()), auth_user(), Path(kept.id.clone())).await;
+        assert!(matches!(result, Err(AppError::NotFound)));
+
+        let Json(response) = empty_trash(State(state.clone()), auth_user()).await.unwrap();
+        assert_eq!(response["purged"], 1);
+        let remaining: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM documents").fetch_all(&state.pool).await.unwrap();
+        assert_eq!(remaining, [kept.id.as_str()]);
+        let root = std::path::Path::new(&state.documents_path);
+        assert!(!root.join(&trashed.file_path).exists());
+        assert!(root.join(&kept.file_path).exists());
+
+        let _ = std::fs::remove_dir_all(&state.documents_path);
+    }
+}
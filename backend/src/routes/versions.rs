@@ -2,16 +2,30 @@ use crate::{
     db,
     error::AppError,
     middleware::auth::AuthUser,
+    models::VersionSearchHit,
     services,
 };
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
+use serde::Deserialize;
 use serde_json::{json, Value};
 
 use super::documents::AppState;
+use super::search::{next_cursor, search_page};
+
+/// 버전 검색 쿼리 파라미터 (`?q=검색어&limit=50&cursor=...`)
+#[derive(Debug, Deserialize)]
+pub struct VersionSearchQuery {
+    /// 검색어 (`title:`을 제외한 `GET /search` 검색 문법)
+    #[serde(default)]
+    pub q: String,
+    pub limit: Option<i64>,
+    /// 이전 응답의 `next_cursor` 값
+    pub cursor: Option<String>,
+}
 
 pub async fn list_document_versions(
     State(state): State<AppState>,
@@ -61,11 +75,80 @@ pub async fn create_version_snapshot(
     }
 
     let content = services::read_markdown(&state.documents_path, &doc.file_path).await?;
-    let word_count = services::count_words(&content) as i64;
-    let char_count = services::count_chars(&content) as i64;
+    let stats = services::analyze_text(&content);
 
-    db::create_version(&state.pool, &id, &content, word_count, char_count).await?;
+    db::create_version(&state.pool, &id, &content, &stats).await?;
     db::prune_versions(&state.pool, &id, state.max_document_versions).await?;
 
     Ok(StatusCode::CREATED)
 }
+
+/// 버전 본문을 검색하여 `{ versions, total, next_cursor }` 응답을 만듭니다.
+async fn run_version_search(
+    state: &AppState,
+    user_id: &str,
+    document_id: Option<&str>,
+    query: &VersionSearchQuery,
+) -> Result<Value, AppError> {
+    let fts_query = services::build_content_query(&query.q).map_err(AppError::BadRequest)?;
+    if fts_query.is_empty() {
+        return Err(AppError::BadRequest(
+            "Search query cannot be empty".to_string(),
+        ));
+    }
+    let page = search_page(query.limit, query.cursor.as_deref())?;
+
+    let (rows, total) =
+        db::search_versions(&state.pool, &fts_query, user_id, document_id, page).await?;
+    let next_cursor = next_cursor(page, rows.len(), total);
+
+    let versions: Vec<VersionSearchHit> = rows
+        .into_iter()
+        .map(|row| {
+            let (snippet, match_count) = services::build_content_snippet(&row.content, &fts_query.terms);
+            VersionSearchHit {
+                version: row.version,
+                document_title: row.document_title,
+                snippet,
+                match_count,
+            }
+        })
+        .collect();
+
+    Ok(json!({
+        "versions": versions,
+        "total": total,
+        "next_cursor": next_cursor,
+    }))
+}
+
+/// `GET /documents/:id/versions/search?q=` — 한 문서의 버전 히스토리에서 본문을 검색합니다.
+///
+/// 지운 문단처럼 현재 본문에는 없는 내용이 어느 버전에 있었는지 찾을 때 사용합니다.
+/// 결과는 최신 버전부터 정렬되며, 각 버전에 스니펫과 일치 횟수가 붙습니다.
+pub async fn search_document_versions(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Path(id): Path<String>,
+    Query(query): Query<VersionSearchQuery>,
+) -> Result<Json<Value>, AppError> {
+    // 소유권 확인
+    db::get_document(&state.pool, &id, &auth_user.user_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let result = run_version_search(&state, &auth_user.user_id, Some(&id), &query).await?;
+    Ok(Json(result))
+}
+
+/// `GET /versions/search?q=` — 내 모든 문서의 버전 히스토리에서 본문을 검색합니다.
+///
+/// 각 결과에는 어느 문서의 버전인지 알 수 있도록 `document_id`와 `document_title`이 포함됩니다.
+pub async fn search_all_versions(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<VersionSearchQuery>,
+) -> Result<Json<Value>, AppError> {
+    let result = run_version_search(&state, &auth_user.user_id, None, &query).await?;
+    Ok(Json(result))
+}
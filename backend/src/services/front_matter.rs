@@ -0,0 +1,209 @@
+//! # YAML front matter 메타데이터 서비스
+//!
+//! 문서 맨 앞의 YAML front matter를 읽어 키/값 메타데이터로 바꾸고,
+//! API로 메타데이터를 고칠 때 front matter를 다시 씁니다.
+//!
+//! ```markdown
+//! ---
+//! status: 초고
+//! pov: 민지
+//! due: 2026-03-31
+//! characters: [민지, 현우]
+//! ---
+//!
+//! # 1장
+//! ```
+//!
+//! ## 규칙
+//! - 첫 줄이 `---`이고, 그 뒤에 `---`(또는 `...`) 줄이 있어야 front matter입니다
+//!   (`services::outline`, `services::text_stats`와 같은 규칙).
+//! - 최상위가 키/값 매핑이어야 합니다. 값은 JSON 값으로 바꿔 저장합니다
+//!   (`2026-03-31` 같은 날짜는 문자열). 문자열이 아닌 키는 건너뜁니다.
+//! - 파일이 원본입니다. 메타데이터를 API로 고쳐도 front matter를 다시 쓴 뒤
+//!   내용 저장 경로에서 다시 읽어 DB를 맞춥니다.
+//! - `status:` 키는 메타데이터이면서 문서 상태(`documents.status`)와도 연결됩니다.
+//!   값을 상태로 바꾸는 규칙은 `routes::statuses::status_from_front_matter`에 있습니다.
+
+use serde_json::{Map, Value};
+
+/// 문서를 front matter의 YAML 부분과 그 뒤의 본문으로 나눕니다.
+///
+/// front matter가 없으면 None을 반환합니다.
+fn split_front_matter(content: &str) -> Option<(&str, &str)> {
+    let mut lines = content.split_inclusive('\n');
+    let first = lines.next()?;
+    if first.trim_end() != "---" {
+        return None;
+    }
+
+    let yaml_start = first.len();
+    let mut offset = yaml_start;
+    for line in lines {
+        if matches!(line.trim_end(), "---" | "...") {
+            return Some((&content[yaml_start..offset], &content[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// YAML 매핑을 JSON 객체로 바꿉니다. 문자열이 아닌 키는 건너뜁니다.
+fn mapping_to_json(mapping: serde_norway::Mapping) -> Result<Map<String, Value>, String> {
+    let mut map = Map::new();
+    for (key, value) in mapping {
+        let serde_norway::Value::String(key) = key else {
+            continue;
+        };
+        let value = serde_json::to_value(value).map_err(|e| format!("{}: {}", key, e))?;
+        map.insert(key, value);
+    }
+    Ok(map)
+}
+
+/// front matter의 YAML을 매핑으로 읽습니다. 비어 있으면 빈 매핑입니다.
+fn parse_mapping(yaml: &str) -> Result<serde_norway::Mapping, String> {
+    if yaml.trim().is_empty() {
+        return Ok(serde_norway::Mapping::new());
+    }
+    match serde_norway::from_str::<serde_norway::Value>(yaml).map_err(|e| e.to_string())? {
+        serde_norway::Value::Mapping(mapping) => Ok(mapping),
+        serde_norway::Value::Null => Ok(serde_norway::Mapping::new()),
+        _ => Err("front matter must be a key/value mapping".to_string()),
+    }
+}
+
+/// 문서의 front matter를 메타데이터로 읽습니다.
+///
+/// # 반환값
+/// - `Ok(None)`: front matter가 없음
+/// - `Ok(Some(map))`: 키/값 메타데이터 (front matter가 비어 있으면 빈 맵)
+/// - `Err(message)`: YAML 문법 오류이거나 매핑이 아님
+pub fn parse_front_matter(content: &str) -> Result<Option<Map<String, Value>>, String> {
+    let Some((yaml, _)) = split_front_matter(content) else {
+        return Ok(None);
+    };
+    mapping_to_json(parse_mapping(yaml)?).map(Some)
+}
+
+/// 메타데이터 변경을 front matter에 반영한 새 문서 내용을 만듭니다.
+///
+/// `changes`의 값이 null이면 그 키를 지우고, 아니면 추가하거나 바꿉니다.
+/// 기존 키의 순서는 유지하고 새 키는 끝에 붙입니다.
+/// 키가 하나도 남지 않으면 front matter 블록을 통째로 지우고, front matter가 없던 문서에는 새로 만듭니다.
+///
+/// YAML을 다시 직렬화하므로 front matter 안의 주석과 따옴표 모양은 유지되지 않습니다 (본문은 그대로).
+pub fn update_front_matter(content: &str, changes: &Map<String, Value>) -> Result<String, String> {
+    let (mut mapping, body) = match split_front_matter(content) {
+        Some((yaml, body)) => (parse_mapping(yaml)?, body),
+        None => (serde_norway::Mapping::new(), content),
+    };
+
+    for (key, value) in changes {
+        let key = serde_norway::Value::String(key.clone());
+        if value.is_null() {
+            mapping.remove(&key);
+        } else {
+            let value = serde_norway::to_value(value).map_err(|e| e.to_string())?;
+            mapping.insert(key, value);
+        }
+    }
+
+    if mapping.is_empty() {
+        // front matter 뒤에 띄워 둔 빈 줄도 함께 지웁니다.
+        return Ok(body.trim_start_matches(['\r', '\n']).to_string());
+    }
+
+    let yaml = serde_norway::to_string(&mapping).map_err(|e| e.to_string())?;
+    let separator = if body.is_empty() || body.starts_with(['\r', '\n']) { "" } else { "\n" };
+    Ok(format!("---\n{}---\n{}{}", yaml, separator, body))
+}
+
+/// 메타데이터 값을 필터 비교용 문자열로 바꿉니다.
+///
+/// 문자열/숫자/불리언만 해당하며, 목록/객체/null은 None입니다
+/// (목록은 DB에서 항목마다 비교합니다).
+pub fn metadata_value_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn changes(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn parses_front_matter_as_json_values() {
+        // 문자열이 아닌 키는 빠지고, `...`로도 닫을 수 있습니다.
+        let content = concat!(
+            "---\npov: 민지\ndue: 2026-03-31\ncharacters: [민지, 현우]\nchapter: 3\ndone: false\n",
+            "1: 숫자 키\n...\n# 1장\n",
+        );
+        assert_eq!(
+            parse_front_matter(content).unwrap(),
+            Some(changes(json!({
+                "pov": "민지",
+                "due": "2026-03-31",
+                "characters": ["민지", "현우"],
+                "chapter": 3,
+                "done": false,
+            })))
+        );
+        assert_eq!(parse_front_matter("---\n---\n본문").unwrap(), Some(Map::new()));
+    }
+
+    #[test]
+    fn front_matter_needs_first_line_and_closing_line() {
+        assert_eq!(parse_front_matter("# 제목\n---\npov: 민지\n---\n").unwrap(), None);
+        // 닫는 `---`가 없으면 front matter가 아닙니다 (본문의 구분선일 수 있음).
+        assert_eq!(parse_front_matter("---\npov: 민지\n\n# 1장\n").unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_yaml_and_non_mappings() {
+        assert!(parse_front_matter("---\npov: [민지\n---\n").is_err());
+        assert!(parse_front_matter("---\n- 민지\n- 현우\n---\n").is_err());
+        assert!(update_front_matter("---\npov: [민지\n---\n본문", &changes(json!({ "due": "내일" }))).is_err());
+    }
+
+    #[test]
+    fn update_keeps_key_order_and_body() {
+        let content = "---\npov: 민지\ndue: 2026-03-31\n---\n\n# 1장\n";
+        let updated =
+            update_front_matter(content, &changes(json!({ "due": null, "pov": "현우", "part": 1 }))).unwrap();
+        assert_eq!(updated, "---\npov: 현우\npart: 1\n---\n\n# 1장\n");
+
+        let removed = update_front_matter(&updated, &changes(json!({ "pov": null, "part": null }))).unwrap();
+        assert_eq!(removed, "# 1장\n");
+    }
+
+    #[test]
+    fn update_adds_front_matter_to_plain_documents() {
+        assert_eq!(
+            update_front_matter("# 1장\n", &changes(json!({ "pov": "민지" }))).unwrap(),
+            "---\npov: 민지\n---\n\n# 1장\n"
+        );
+        // 닫히지 않은 `---`는 본문이므로 새 블록을 앞에 붙입니다.
+        assert_eq!(
+            update_front_matter("---\n본문", &changes(json!({ "pov": "민지" }))).unwrap(),
+            "---\npov: 민지\n---\n\n---\n본문"
+        );
+    }
+
+    #[test]
+    fn value_text_covers_scalars_only() {
+        assert_eq!(metadata_value_text(&json!("민지")).as_deref(), Some("민지"));
+        assert_eq!(metadata_value_text(&json!(3)).as_deref(), Some("3"));
+        assert_eq!(metadata_value_text(&json!(true)).as_deref(), Some("true"));
+        assert_eq!(metadata_value_text(&json!(["민지"])), None);
+        assert_eq!(metadata_value_text(&Value::Null), None);
+    }
+}
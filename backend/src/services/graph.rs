@@ -0,0 +1,274 @@
+//! # 문서 그래프 조립 서비스
+//!
+//! DB에서 읽어온 문서/폴더/태그/링크 관계를 그래프(노드 + 엣지)로 조립합니다.
+//! 폴더/태그 필터도 여기서 적용합니다.
+//!
+//! ## 필터 규칙
+//! - 문서: 필터(폴더 하위 트리, 태그)를 모두 만족하는 문서만 포함
+//! - 태그: 포함된 문서에 하나 이상 붙어 있는 태그만 포함
+//! - 폴더: 필터가 없으면 전체, 있으면 포함된 문서를 담은 폴더와 그 상위 폴더들
+//!   (폴더 필터가 있으면 해당 하위 트리 안으로 한정)
+//! - 엣지: 양 끝 노드가 모두 포함된 경우만 포함
+
+use crate::models::*;
+use std::collections::{HashMap, HashSet};
+
+/// 그래프를 만들기 위한 원본 데이터 (모두 한 사용자의 데이터)
+pub struct GraphSource {
+    pub documents: Vec<GraphDocumentRow>,
+    pub folders: Vec<Folder>,
+    pub tags: Vec<Tag>,
+    pub memberships: Vec<GraphTagMembership>,
+    pub links: Vec<GraphLinkRow>,
+}
+
+/// 그래프 필터
+#[derive(Default)]
+pub struct GraphFilter {
+    /// 폴더 필터: 지정한 폴더와 모든 하위 폴더의 ID 집합
+    pub folder_ids: Option<HashSet<String>>,
+    /// 태그 필터: 지정한 태그와 모든 하위 태그의 ID 집합 (이 중 하나라도 붙은 문서만 포함)
+    pub tag_ids: Option<HashSet<String>>,
+}
+
+impl GraphFilter {
+    fn is_empty(&self) -> bool {
+        self.folder_ids.is_none() && self.tag_ids.is_none()
+    }
+}
+
+/// 원본 데이터와 필터로 그래프를 조립합니다.
+pub fn build_graph(source: GraphSource, filter: &GraphFilter) -> Graph {
+    // 태그 필터를 적용하기 위해, 해당 태그(와 하위 태그)가 붙은 문서 ID를 먼저 모읍니다.
+    let tagged_docs: Option<HashSet<&str>> = filter.tag_ids.as_ref().map(|tag_ids| {
+        source
+            .memberships
+            .iter()
+            .filter(|m| tag_ids.contains(&m.tag_id))
+            .map(|m| m.document_id.as_str())
+            .collect()
+    });
+
+    // ── 문서 노드 ──
+    let documents: Vec<&GraphDocumentRow> = source
+        .documents
+        .iter()
+        .filter(|d| match &filter.folder_ids {
+            Some(ids) => d.folder_id.as_ref().is_some_and(|f| ids.contains(f)),
+            None => true,
+        })
+        .filter(|d| match &tagged_docs {
+            Some(ids) => ids.contains(d.id.as_str()),
+            None => true,
+        })
+        .collect();
+    let doc_ids: HashSet<&str> = documents.iter().map(|d| d.id.as_str()).collect();
+
+    let mut nodes: Vec<GraphNode> = documents
+        .iter()
+        .map(|d| GraphNode {
+            id: d.id.clone(),
+            kind: GraphNodeKind::Document,
+            label: d.title.clone(),
+            weight: d.word_count,
+        })
+        .collect();
+    let mut edges = Vec::new();
+
+    // ── 위키 링크 엣지 ──
+    for link in &source.links {
+        if doc_ids.contains(link.source_id.as_str()) && doc_ids.contains(link.target_id.as_str()) {
+            edges.push(GraphEdge {
+                source: link.source_id.clone(),
+                target: link.target_id.clone(),
+                kind: GraphEdgeKind::Link,
+                weight: link.link_count,
+            });
+        }
+    }
+
+    // ── 태그 노드 + 태그 소속 엣지 ──
+    let mut tag_counts: HashMap<&str, i64> = HashMap::new();
+    for m in &source.memberships {
+        if doc_ids.contains(m.document_id.as_str()) {
+            *tag_counts.entry(m.tag_id.as_str()).or_default() += 1;
+            edges.push(GraphEdge {
+                source: m.document_id.clone(),
+                target: m.tag_id.clone(),
+                kind: GraphEdgeKind::Tag,
+                weight: 1,
+            });
+        }
+    }
+    for tag in &source.tags {
+        if let Some(&count) = tag_counts.get(tag.id.as_str()) {
+            nodes.push(GraphNode {
+                id: tag.id.clone(),
+                kind: GraphNodeKind::Tag,
+                label: tag.name.clone(),
+                weight: count,
+            });
+        }
+    }
+
+    // ── 폴더 노드 + 폴더 포함 엣지 ──
+    let parents: HashMap<&str, Option<&str>> = source
+        .folders
+        .iter()
+        .map(|f| (f.id.as_str(), f.parent_id.as_deref()))
+        .collect();
+    let in_scope = |id: &str| match &filter.folder_ids {
+        Some(ids) => ids.contains(id),
+        None => parents.contains_key(id),
+    };
+
+    let mut folder_counts: HashMap<&str, i64> = HashMap::new();
+    for d in &documents {
+        if let Some(folder_id) = d.folder_id.as_deref() {
+            *folder_counts.entry(folder_id).or_default() += 1;
+        }
+    }
+
+    let mut included_folders: HashSet<&str> = HashSet::new();
+    if filter.is_empty() {
+        included_folders.extend(parents.keys().copied());
+    } else {
+        // 문서를 담은 폴더에서 시작해 범위 안의 상위 폴더까지 거슬러 올라갑니다.
+        for &start in folder_counts.keys() {
+            let mut current = Some(start);
+            while let Some(id) = current {
+                if !in_scope(id) || !included_folders.insert(id) {
+                    break;
+                }
+                current = parents.get(id).copied().flatten();
+            }
+        }
+    }
+
+    for folder in &source.folders {
+        if !included_folders.contains(folder.id.as_str()) {
+            continue;
+        }
+        nodes.push(GraphNode {
+            id: folder.id.clone(),
+            kind: GraphNodeKind::Folder,
+            label: folder.name.clone(),
+            weight: folder_counts.get(folder.id.as_str()).copied().unwrap_or(0),
+        });
+        if let Some(parent_id) = folder.parent_id.as_deref() {
+            if included_folders.contains(parent_id) {
+                edges.push(GraphEdge {
+                    source: parent_id.to_string(),
+                    target: folder.id.clone(),
+                    kind: GraphEdgeKind::Folder,
+                    weight: 1,
+                });
+            }
+        }
+    }
+    for d in &documents {
+        if let Some(folder_id) = d.folder_id.as_deref() {
+            if included_folders.contains(folder_id) {
+                edges.push(GraphEdge {
+                    source: folder_id.to_string(),
+                    target: d.id.clone(),
+                    kind: GraphEdgeKind::Folder,
+                    weight: 1,
+                });
+            }
+        }
+    }
+
+    Graph { nodes, edges }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 폴더 `novel`(하위 `ch`)과 `notes`, 문서 `d1`(ch) `d2`(notes) `d3`(루트),
+    /// 태그 `hero`(d1) `idea`(d2), 링크 d1 → d2, d1 → d3
+    fn source() -> GraphSource {
+        let folder = |id: &str, parent_id: Option<&str>| Folder {
+            id: id.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            name: id.to_string(),
+            slug: id.to_string(),
+            sort_order: 0,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        let document = |id: &str, folder_id: Option<&str>| GraphDocumentRow {
+            id: id.to_string(),
+            title: id.to_string(),
+            folder_id: folder_id.map(str::to_string),
+            word_count: 10,
+        };
+        let tag = |id: &str| Tag { id: id.to_string(), name: id.to_string(), color: None, parent_id: None };
+        let member = |document_id: &str, tag_id: &str| GraphTagMembership {
+            document_id: document_id.to_string(),
+            tag_id: tag_id.to_string(),
+        };
+        let link = |source_id: &str, target_id: &str| GraphLinkRow {
+            source_id: source_id.to_string(),
+            target_id: target_id.to_string(),
+            link_count: 1,
+        };
+        GraphSource {
+            documents: vec![document("d1", Some("ch")), document("d2", Some("notes")), document("d3", None)],
+            folders: vec![folder("novel", None), folder("ch", Some("novel")), folder("notes", None)],
+            tags: vec![tag("hero"), tag("idea")],
+            memberships: vec![member("d1", "hero"), member("d2", "idea")],
+            links: vec![link("d1", "d2"), link("d1", "d3")],
+        }
+    }
+
+    fn node_ids(graph: &Graph, kind: GraphNodeKind) -> Vec<&str> {
+        let mut ids: Vec<&str> = graph.nodes.iter().filter(|n| n.kind == kind).map(|n| n.id.as_str()).collect();
+        ids.sort();
+        ids
+    }
+
+    fn edges(graph: &Graph, kind: GraphEdgeKind) -> Vec<(&str, &str)> {
+        let mut edges: Vec<(&str, &str)> = graph
+            .edges
+            .iter()
+            .filter(|e| e.kind == kind)
+            .map(|e| (e.source.as_str(), e.target.as_str()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    fn ids(ids: &[&str]) -> Option<HashSet<String>> {
+        Some(ids.iter().map(|id| id.to_string()).collect())
+    }
+
+    #[test]
+    fn unfiltered_graph_has_everything() {
+        let graph = build_graph(source(), &GraphFilter::default());
+        assert_eq!(node_ids(&graph, GraphNodeKind::Document), ["d1", "d2", "d3"]);
+        assert_eq!(node_ids(&graph, GraphNodeKind::Folder), ["ch", "notes", "novel"]);
+        assert_eq!(edges(&graph, GraphEdgeKind::Link), [("d1", "d2"), ("d1", "d3")]);
+        assert_eq!(edges(&graph, GraphEdgeKind::Folder), [("ch", "d1"), ("notes", "d2"), ("novel", "ch")]);
+    }
+
+    #[test]
+    fn folder_filter_keeps_subtree_and_drops_dangling_edges() {
+        let filter = GraphFilter { folder_ids: ids(&["novel", "ch"]), tag_ids: None };
+        let graph = build_graph(source(), &filter);
+        assert_eq!(node_ids(&graph, GraphNodeKind::Document), ["d1"]);
+        assert_eq!(node_ids(&graph, GraphNodeKind::Tag), ["hero"]);
+        assert_eq!(node_ids(&graph, GraphNodeKind::Folder), ["ch", "novel"]);
+        assert!(edges(&graph, GraphEdgeKind::Link).is_empty());
+    }
+
+    #[test]
+    fn tag_filter_includes_only_folders_holding_matches() {
+        let filter = GraphFilter { folder_ids: None, tag_ids: ids(&["idea"]) };
+        let graph = build_graph(source(), &filter);
+        assert_eq!(node_ids(&graph, GraphNodeKind::Document), ["d2"]);
+        assert_eq!(node_ids(&graph, GraphNodeKind::Folder), ["notes"]);
+        assert_eq!(edges(&graph, GraphEdgeKind::Tag), [("d2", "idea")]);
+    }
+}
@@ -0,0 +1,177 @@
+//! # 인라인 해시태그(`#태그`) 파싱 서비스
+//!
+//! 마크다운 본문에 직접 쓴 `#초고`, `#인물` 같은 해시태그를 찾고,
+//! 태그를 합칠 때 본문의 해시태그를 새 이름으로 다시 쓰는 함수들을 제공합니다.
+//! 내용을 저장할 때마다 `extract_hashtags`의 결과로 문서의 태그를 맞춥니다 (`db::sync_hashtag_tags`).
+//!
+//! ## 해시태그로 인정하는 조건
+//! - `#`이 줄의 맨 앞이거나 공백 바로 뒤에 있어야 합니다.
+//!   (`https://a.com/#top`, `C#`, `&#39;`는 해시태그가 아닙니다)
+//! - `#` 뒤에는 글자/숫자와 `_`, `-`, `/`가 이어집니다. 그 외의 문자(공백, 문장부호)에서 끝납니다.
+//!   `/`는 계층 태그 경로(`#소설/판타지`)에 쓰며, 끝에 붙은 `/`는 빼고 봅니다.
+//! - 글자가 하나 이상 있어야 합니다 (`#1`, `#2024`는 번호로 보고 건너뜁니다).
+//! - 16진수 숫자만 3, 6, 8자리인 이름(`#fff`, `#FF5733`, `#ff573380`)은 색상 코드로 보고 건너뜁니다.
+//! - `# 제목`처럼 `#` 뒤가 공백이면 헤딩이므로 해당하지 않습니다.
+//!
+//! 위키 링크와 마찬가지로 코드 블록과 인라인 코드 안은 무시합니다.
+
+use super::links::map_prose_lines;
+
+/// 해시태그 이름에 쓸 수 있는 문자인지 확인합니다.
+fn is_hashtag_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '/')
+}
+
+/// `FF5733`, `fff`처럼 CSS 색상 코드 모양인지 확인합니다.
+fn is_hex_color(name: &str) -> bool {
+    matches!(name.len(), 3 | 6 | 8) && name.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// 한 줄에서 해시태그의 위치를 찾습니다.
+///
+/// 반환값은 `(이름 시작 바이트, 이름 끝 바이트)` 목록입니다. 시작은 `#` 바로 뒤입니다.
+/// 인라인 코드(백틱으로 둘러싸인 구간) 안의 해시태그는 건너뜁니다.
+fn find_hashtags_in_line(line: &str) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    let mut in_code = false;
+    let mut prev: Option<char> = None;
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c == '`' {
+            in_code = !in_code;
+        } else if c == '#' && !in_code && prev.is_none_or(char::is_whitespace) {
+            let start = i + 1;
+            let mut end = start;
+            while let Some(&(j, next)) = chars.peek() {
+                if !is_hashtag_char(next) {
+                    break;
+                }
+                end = j + next.len_utf8();
+                chars.next();
+            }
+            let name = line[start..end].trim_end_matches('/');
+            if name.chars().any(char::is_alphabetic) && !is_hex_color(name) {
+                found.push((start, start + name.len()));
+            }
+            prev = line[..end].chars().last();
+            continue;
+        }
+        prev = Some(c);
+    }
+
+    found
+}
+
+/// 본문의 해시태그를 등장 순서대로, 중복 없이 추출합니다.
+///
+/// 각 해시태그는 `/`로 나뉜 경로로 반환합니다. 예: `#소설/판타지` → `["소설", "판타지"]`
+/// 빈 부분(`#a//b`)은 버립니다.
+pub fn extract_hashtags(content: &str) -> Vec<Vec<String>> {
+    let mut tags: Vec<Vec<String>> = Vec::new();
+    map_prose_lines(content, |line| {
+        for (start, end) in find_hashtags_in_line(line) {
+            let path: Vec<String> = line[start..end]
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(str::to_string)
+                .collect();
+            if !path.is_empty() && !tags.contains(&path) {
+                tags.push(path);
+            }
+        }
+        String::new()
+    });
+    tags
+}
+
+/// 해시태그 이름의 `/`로 나뉜 각 부분 중 `old_names`에 있는 것을 `new_name`으로 바꿉니다.
+///
+/// 바뀐 부분이 없으면 None을 반환합니다.
+fn rename_segments(name: &str, old_names: &[&str], new_name: &str) -> Option<String> {
+    let mut changed = false;
+    let segments: Vec<&str> = name
+        .split('/')
+        .map(|segment| {
+            if old_names.contains(&segment) {
+                changed = true;
+                new_name
+            } else {
+                segment
+            }
+        })
+        .collect();
+    changed.then(|| segments.join("/"))
+}
+
+/// 본문에서 `old_names` 중 하나를 가리키는 해시태그를 `new_name`으로 다시 씁니다.
+///
+/// 이름은 대소문자까지 정확히 일치해야 합니다 (태그 이름과 같은 규칙).
+/// 계층 경로 해시태그(`#소설/world-building`)는 `/`로 나뉜 부분마다 비교하여 일치하는 부분만 바꿉니다.
+/// 바뀐 해시태그가 하나도 없으면 None을 반환하여, 호출자가 파일 쓰기를 건너뛸 수 있게 합니다.
+pub fn rewrite_hashtags(content: &str, old_names: &[&str], new_name: &str) -> Option<String> {
+    let mut changed = false;
+
+    let rewritten = map_prose_lines(content, |line| {
+        let tags = find_hashtags_in_line(line);
+        if tags.is_empty() {
+            return line.to_string();
+        }
+
+        let mut out = String::with_capacity(line.len());
+        let mut cursor = 0;
+        for (start, end) in tags {
+            out.push_str(&line[cursor..start]);
+            match rename_segments(&line[start..end], old_names, new_name) {
+                Some(renamed) => {
+                    out.push_str(&renamed);
+                    changed = true;
+                }
+                None => out.push_str(&line[start..end]),
+            }
+            cursor = end;
+        }
+        out.push_str(&line[cursor..]);
+        out
+    });
+
+    changed.then_some(rewritten)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(content: &str) -> Vec<String> {
+        extract_hashtags(content).iter().map(|path| path.join("/")).collect()
+    }
+
+    #[test]
+    fn hashtags_need_whitespace_before_and_a_letter() {
+        let content = "#초고 https://a.com/#top C# &#39; # 제목 #1 #2024년 (#괄호)\n#소설/판타지/ #a//b #초고";
+        assert_eq!(names(content), ["초고", "2024년", "소설/판타지", "a/b"]);
+        assert_eq!(extract_hashtags("#소설/판타지"), vec![vec!["소설".to_string(), "판타지".to_string()]]);
+    }
+
+    #[test]
+    fn skips_code_fences_and_inline_code() {
+        let content = "#앞\n```\n#코드\n```\n`#인라인` #뒤\n~~~md\n#물결\n";
+        assert_eq!(names(content), ["앞", "뒤"]);
+    }
+
+    #[test]
+    fn rewrite_renames_matching_path_segments() {
+        let content = "#world-building/maps #worldbuilding #World-building\n```\n#world-building\n```\n";
+        assert_eq!(
+            rewrite_hashtags(content, &["world-building", "세계관"], "worldbuilding").as_deref(),
+            Some("#worldbuilding/maps #worldbuilding #World-building\n```\n#world-building\n```\n")
+        );
+        assert_eq!(rewrite_hashtags(content, &["없는 태그"], "worldbuilding"), None);
+    }
+
+    #[test]
+    fn hex_colors_are_not_hashtags() {
+        let content = "배경은 #FF5733, 글자는 #fff, 반투명 #ff573380\n#초고 #cafe #abcd #add/에피소드";
+        assert_eq!(names(content), ["초고", "cafe", "abcd", "add/에피소드"]);
+    }
+}
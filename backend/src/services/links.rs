@@ -0,0 +1,283 @@
+//! # 위키 링크(`[[문서 제목]]`) 파싱 서비스
+//!
+//! 마크다운 본문에서 `[[다른 문서]]` 형태의 위키 링크를 찾아내고,
+//! 문서 이름이 바뀌었을 때 링크 텍스트를 새 제목으로 다시 쓰는 함수들을 제공합니다.
+//!
+//! ## 지원하는 문법
+//! - `[[제목]]` → "제목" 문서로 링크
+//! - `[[제목#헤딩]]` → "제목" 문서의 특정 헤딩으로 링크 (헤딩은 해석에 사용하지 않음)
+//! - `[[제목|표시 텍스트]]` → 다른 텍스트로 표시되는 링크
+//!
+//! 코드 블록(```)과 인라인 코드(`` ` ``) 안의 `[[...]]`는 링크로 취급하지 않습니다.
+//!
+//! 링크 대상은 `slug::slugify(제목)`으로 정규화하여 문서의 `slug`와 비교합니다.
+//! 따라서 `[[나의 첫 글]]`과 `[[나의-첫-글]]`은 같은 문서를 가리킵니다.
+
+/// 본문에서 찾은 위키 링크 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiLink {
+    /// 링크 대상 문서 제목 (`#헤딩`, `|별칭` 부분 제외, 앞뒤 공백 제거)
+    pub target: String,
+    /// `[[제목#헤딩]]`의 헤딩 부분
+    pub heading: Option<String>,
+    /// `[[제목|별칭]]`의 별칭 부분
+    pub alias: Option<String>,
+}
+
+impl WikiLink {
+    /// 링크 대상을 문서 slug와 비교할 수 있는 형태로 정규화합니다.
+    pub fn target_slug(&self) -> String {
+        slug::slugify(&self.target)
+    }
+
+    /// 링크를 다시 `[[...]]` 문법으로 직렬화합니다.
+    fn to_markup(&self) -> String {
+        let mut out = String::from("[[");
+        out.push_str(&self.target);
+        if let Some(heading) = &self.heading {
+            out.push('#');
+            out.push_str(heading);
+        }
+        if let Some(alias) = &self.alias {
+            out.push('|');
+            out.push_str(alias);
+        }
+        out.push_str("]]");
+        out
+    }
+}
+
+/// 대상 문서별로 묶은 링크 (DB의 `document_links` 한 행에 대응)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkTarget {
+    /// 본문에 처음 등장한 형태의 제목
+    pub title: String,
+    /// 정규화된 대상 slug
+    pub slug: String,
+    /// 같은 대상을 가리키는 링크 개수
+    pub count: i64,
+}
+
+/// `[[...]]` 안쪽 텍스트를 `WikiLink`로 해석합니다.
+///
+/// 대상이 비어 있으면 (`[[]]`, `[[|별칭]]` 등) None을 반환합니다.
+fn parse_inner(inner: &str) -> Option<WikiLink> {
+    let (target_part, alias) = match inner.split_once('|') {
+        Some((t, a)) => (t, Some(a.trim().to_string()).filter(|a| !a.is_empty())),
+        None => (inner, None),
+    };
+    let (target, heading) = match target_part.split_once('#') {
+        Some((t, h)) => (t, Some(h.trim().to_string()).filter(|h| !h.is_empty())),
+        None => (target_part, None),
+    };
+    let target = target.trim();
+    if target.is_empty() || slug::slugify(target).is_empty() {
+        return None;
+    }
+    Some(WikiLink {
+        target: target.to_string(),
+        heading,
+        alias,
+    })
+}
+
+/// 한 줄에서 위키 링크의 위치를 찾습니다.
+///
+/// 반환값은 `(시작 바이트, 끝 바이트, 링크)` 목록이며, 끝 바이트는 `]]` 바로 뒤입니다.
+/// 인라인 코드(백틱으로 둘러싸인 구간) 안의 링크는 건너뜁니다.
+fn find_links_in_line(line: &str) -> Vec<(usize, usize, WikiLink)> {
+    let bytes = line.as_bytes();
+    let mut found = Vec::new();
+    let mut in_code = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'`' {
+            in_code = !in_code;
+            i += 1;
+            continue;
+        }
+        if !in_code && bytes[i] == b'[' && bytes.get(i + 1) == Some(&b'[') {
+            let start = i;
+            let inner_start = i + 2;
+            if let Some(rel_end) = line[inner_start..].find("]]") {
+                let inner = &line[inner_start..inner_start + rel_end];
+                // 중첩된 대괄호나 코드가 섞인 경우는 링크로 보지 않습니다
+                if !inner.contains('[') && !inner.contains('`') {
+                    if let Some(link) = parse_inner(inner) {
+                        let end = inner_start + rel_end + 2;
+                        found.push((start, end, link));
+                        i = end;
+                        continue;
+                    }
+                }
+            }
+        }
+        i += 1;
+    }
+
+    found
+}
+
+/// 줄 단위로 순회하며 코드 블록 바깥의 줄에만 `f`를 적용합니다.
+///
+/// `f`는 줄 내용(줄바꿈 제외)을 받아 새 줄 내용을 반환하며,
+/// 코드 블록 안의 줄과 줄바꿈 문자는 그대로 유지됩니다.
+pub(super) fn map_prose_lines(content: &str, mut f: impl FnMut(&str) -> String) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut fence: Option<&str> = None;
+
+    for line in content.split_inclusive('\n') {
+        let body = line.trim_end_matches(['\n', '\r']);
+        let ending = &line[body.len()..];
+        let trimmed = body.trim_start();
+
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            out.push_str(line);
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            fence = Some("```");
+            out.push_str(line);
+            continue;
+        }
+        if trimmed.starts_with("~~~") {
+            fence = Some("~~~");
+            out.push_str(line);
+            continue;
+        }
+
+        out.push_str(&f(body));
+        out.push_str(ending);
+    }
+
+    out
+}
+
+/// 마크다운 본문에서 모든 위키 링크를 등장 순서대로 추출합니다.
+pub fn extract_wiki_links(content: &str) -> Vec<WikiLink> {
+    let mut links = Vec::new();
+    map_prose_lines(content, |line| {
+        links.extend(find_links_in_line(line).into_iter().map(|(_, _, link)| link));
+        String::new()
+    });
+    links
+}
+
+/// 위키 링크를 대상 slug별로 묶어 개수를 셉니다.
+///
+/// 결과는 본문에 처음 등장한 순서를 유지합니다.
+pub fn collect_link_targets(content: &str) -> Vec<LinkTarget> {
+    let mut targets: Vec<LinkTarget> = Vec::new();
+    for link in extract_wiki_links(content) {
+        let slug = link.target_slug();
+        match targets.iter_mut().find(|t| t.slug == slug) {
+            Some(existing) => existing.count += 1,
+            None => targets.push(LinkTarget {
+                title: link.target,
+                slug,
+                count: 1,
+            }),
+        }
+    }
+    targets
+}
+
+/// `old_title`을 가리키는 위키 링크를 `new_title`로 다시 씁니다.
+///
+/// `#헤딩`과 `|별칭` 부분은 그대로 보존합니다.
+/// 바뀐 링크가 하나도 없으면 None을 반환하여, 호출자가 파일 쓰기를 건너뛸 수 있게 합니다.
+pub fn rewrite_wiki_links(content: &str, old_title: &str, new_title: &str) -> Option<String> {
+    let old_slug = slug::slugify(old_title);
+    let mut changed = false;
+
+    let rewritten = map_prose_lines(content, |line| {
+        let links = find_links_in_line(line);
+        if links.is_empty() {
+            return line.to_string();
+        }
+
+        let mut out = String::with_capacity(line.len());
+        let mut cursor = 0;
+        for (start, end, mut link) in links {
+            out.push_str(&line[cursor..start]);
+            if link.target_slug() == old_slug {
+                link.target = new_title.to_string();
+                out.push_str(&link.to_markup());
+                changed = true;
+            } else {
+                out.push_str(&line[start..end]);
+            }
+            cursor = end;
+        }
+        out.push_str(&line[cursor..]);
+        out
+    });
+
+    changed.then_some(rewritten)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets(content: &str) -> Vec<String> {
+        extract_wiki_links(content).into_iter().map(|link| link.target).collect()
+    }
+
+    #[test]
+    fn parses_heading_and_alias() {
+        let links = extract_wiki_links("[[ 세계관 # 대륙 | 지도 ]] [[민지]]");
+        assert_eq!(
+            links[0],
+            WikiLink {
+                target: "세계관".to_string(),
+                heading: Some("대륙".to_string()),
+                alias: Some("지도".to_string()),
+            }
+        );
+        assert_eq!(links[1].heading, None);
+        assert_eq!(links[1].alias, None);
+    }
+
+    #[test]
+    fn ignores_empty_nested_and_unclosed_links() {
+        assert_eq!(targets("[[]] [[|별칭]] [[#헤딩]]"), Vec::<String>::new());
+        // 바깥 `[[`는 안에 `[`가 있어 링크가 아니고, 안쪽 `[[b]]`만 링크입니다.
+        assert_eq!(targets("[[a [[b]] c"), ["b"]);
+        assert_eq!(targets("[[닫히지 않은 링크"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn skips_code_fences_and_inline_code() {
+        let content = "[[앞]]\n```rust\n[[코드 안]]\n```\n`[[인라인]]` [[뒤]]\n~~~\n[[물결 펜스]]\n~~~\n";
+        assert_eq!(targets(content), ["앞", "뒤"]);
+        // 닫히지 않은 코드 블록은 문서 끝까지 코드입니다.
+        assert_eq!(targets("[[앞]]\n```\n[[코드]]\n"), ["앞"]);
+    }
+
+    #[test]
+    fn groups_targets_by_slug() {
+        let targets = collect_link_targets("[[나의 첫 글]] [[나의-첫-글|다른 이름]] [[민지]]");
+        assert_eq!(
+            targets,
+            vec![
+                LinkTarget { title: "나의 첫 글".to_string(), slug: slug::slugify("나의 첫 글"), count: 2 },
+                LinkTarget { title: "민지".to_string(), slug: slug::slugify("민지"), count: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn rewrite_keeps_heading_alias_and_code() {
+        let content = "[[초안#1장|처음]] [[초안]] [[다른 글]]\r\n```\n[[초안]]\n```\n";
+        assert_eq!(
+            rewrite_wiki_links(content, "초안", "최종본").as_deref(),
+            Some("[[최종본#1장|처음]] [[최종본]] [[다른 글]]\r\n```\n[[초안]]\n```\n")
+        );
+        assert_eq!(rewrite_wiki_links(content, "없는 글", "최종본"), None);
+    }
+}
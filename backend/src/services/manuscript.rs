@@ -0,0 +1,186 @@
+//! # 원고지 매수 계산 서비스
+//!
+//! 출판사나 공모전에서 분량 기준으로 쓰는 200자 원고지(한 줄 20칸 × 10줄) 매수를 계산합니다.
+//! 본문을 실제 원고지에 옮겨 적는다고 가정하고 칸을 하나씩 채워 나가며 줄 수를 셉니다.
+//!
+//! ## 원고지 작성 규칙 (표준 규칙 중 매수에 영향을 주는 것)
+//! - 문단은 새 줄에서 시작하고, 첫 칸은 비워 둡니다 (들여쓰기).
+//! - 한글, 한자, 문장부호, 띄어쓰기는 각각 한 칸을 차지합니다.
+//! - 알파벳 소문자와 아라비아 숫자는 한 칸에 두 자씩 씁니다 (대문자는 한 칸에 한 자).
+//! - 줄의 첫 칸에 와야 하는 띄어쓰기는 쓰지 않습니다.
+//! - 줄의 첫 칸에 와야 하는 닫는 문장부호(`.`, `,`, `?`, `」` 등)는
+//!   앞 줄 마지막 칸 옆(여백)에 함께 써서 새 칸을 쓰지 않습니다.
+//! - 강제 줄바꿈은 들여쓰기 없이 새 줄에서 이어 씁니다.
+//!
+//! 매수는 사용한 줄 수를 10으로 나누어 올림합니다. 본문이 없으면 0매입니다.
+
+/// 원고지 한 줄의 칸 수
+pub const MANUSCRIPT_COLUMNS: usize = 20;
+/// 원고지 한 장의 줄 수
+pub const MANUSCRIPT_ROWS: usize = 10;
+
+/// 줄의 첫 칸에 올 수 없어 앞 줄 여백에 붙여 쓰는 닫는 문장부호인지 확인합니다.
+fn is_closing_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '.' | ',' | '!' | '?' | ':' | ';' | ')' | ']' | '}' | '\'' | '"' | '’' | '”'
+            | '」' | '』' | '》' | '〉' | '…' | '。' | '、' | '·'
+    )
+}
+
+/// 한 칸에 두 자씩 쓰는 문자(알파벳 소문자, 아라비아 숫자)인지 확인합니다.
+fn is_half_width(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit()
+}
+
+/// 원고지에 글을 옮겨 적으며 줄 수를 세는 상태
+struct ManuscriptWriter {
+    /// 지금까지 사용한 줄 수 (현재 줄 포함)
+    lines: usize,
+    /// 현재 줄에서 사용한 칸 수
+    column: usize,
+    /// 두 자씩 쓰는 문자가 한 칸에 한 자만 들어가 있는지 여부
+    half_cell_open: bool,
+    /// 직전에 쓴 것이 띄어쓰기인지 (연속 공백은 한 칸만 차지)
+    after_space: bool,
+}
+
+impl ManuscriptWriter {
+    fn new() -> Self {
+        Self {
+            lines: 0,
+            column: 0,
+            half_cell_open: false,
+            after_space: false,
+        }
+    }
+
+    fn new_line(&mut self) {
+        self.lines += 1;
+        self.column = 0;
+        self.half_cell_open = false;
+    }
+
+    /// 칸 하나를 채웁니다. 줄이 가득 찼으면 다음 줄로 넘어갑니다.
+    fn fill_cell(&mut self) {
+        if self.column == MANUSCRIPT_COLUMNS {
+            self.new_line();
+        }
+        self.column += 1;
+    }
+
+    /// 새 문단을 시작합니다 (새 줄 + 첫 칸 들여쓰기).
+    fn start_paragraph(&mut self) {
+        self.new_line();
+        self.column = 1;
+        self.after_space = true;
+    }
+
+    fn write_char(&mut self, c: char) {
+        if c == '\n' {
+            self.new_line();
+            self.after_space = true;
+            return;
+        }
+
+        if c.is_whitespace() {
+            self.half_cell_open = false;
+            // 줄 첫 칸의 띄어쓰기와 연속된 공백은 칸을 차지하지 않습니다.
+            if !self.after_space && self.column != 0 && self.column != MANUSCRIPT_COLUMNS {
+                self.fill_cell();
+            }
+            self.after_space = true;
+            return;
+        }
+        self.after_space = false;
+
+        if is_half_width(c) {
+            if self.half_cell_open {
+                self.half_cell_open = false;
+            } else {
+                self.fill_cell();
+                self.half_cell_open = true;
+            }
+            return;
+        }
+        self.half_cell_open = false;
+
+        if is_closing_punctuation(c) && self.column == MANUSCRIPT_COLUMNS {
+            // 줄 끝 여백에 붙여 씁니다.
+            return;
+        }
+        self.fill_cell();
+    }
+}
+
+/// 문단 목록을 원고지에 옮겨 적었을 때 필요한 줄 수를 계산합니다.
+///
+/// 문단 안의 `\n`은 강제 줄바꿈으로 취급합니다.
+pub fn manuscript_lines(paragraphs: &[String]) -> usize {
+    let mut writer = ManuscriptWriter::new();
+    for paragraph in paragraphs {
+        writer.start_paragraph();
+        for c in paragraph.chars() {
+            writer.write_char(c);
+        }
+    }
+    writer.lines
+}
+
+/// 문단 목록의 200자 원고지 매수를 계산합니다 (올림).
+///
+/// # 예시
+/// ```text
+/// ["가나다"]              → 1줄 → 1매
+/// 문단 11개 (각 1줄)      → 11줄 → 2매
+/// ```
+pub fn manuscript_pages(paragraphs: &[String]) -> usize {
+    manuscript_lines(paragraphs).div_ceil(MANUSCRIPT_ROWS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(paragraph: &str) -> usize {
+        manuscript_lines(&[paragraph.to_string()])
+    }
+
+    #[test]
+    fn paragraph_indent_uses_the_first_cell() {
+        assert_eq!(lines(&"가".repeat(19)), 1);
+        assert_eq!(lines(&"가".repeat(20)), 2);
+        assert_eq!(manuscript_lines(&[]), 0);
+    }
+
+    #[test]
+    fn closing_punctuation_hangs_in_the_margin() {
+        assert_eq!(lines(&format!("{}.", "가".repeat(19))), 1);
+        assert_eq!(lines(&format!("{}」", "가".repeat(19))), 1);
+        assert_eq!(lines(&format!("{}「", "가".repeat(19))), 2);
+    }
+
+    #[test]
+    fn space_at_line_start_is_dropped() {
+        // 둘째 줄의 첫 칸을 띄어쓰기가 차지하면 20자가 넘쳐 셋째 줄이 생깁니다.
+        assert_eq!(lines(&format!("{} {}", "가".repeat(19), "나".repeat(20))), 2);
+        // 연속된 공백은 한 칸입니다.
+        assert_eq!(lines(&format!("가   {}", "나".repeat(17))), 1);
+    }
+
+    #[test]
+    fn lowercase_and_digits_take_half_a_cell() {
+        assert_eq!(lines(&"a1".repeat(19)), 1);
+        assert_eq!(lines(&format!("{}b", "a1".repeat(19))), 2);
+        assert_eq!(lines(&"A".repeat(20)), 2);
+    }
+
+    #[test]
+    fn hard_breaks_and_page_rounding() {
+        assert_eq!(lines("가\n나"), 2);
+        let paragraphs = |n: usize| vec!["가나다".to_string(); n];
+        assert_eq!(manuscript_pages(&paragraphs(10)), 1);
+        assert_eq!(manuscript_pages(&paragraphs(11)), 2);
+        assert_eq!(manuscript_pages(&[]), 0);
+    }
+}
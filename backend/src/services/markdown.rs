@@ -5,15 +5,15 @@
 //! 이 모듈의 함수들:
 //! - `read_markdown()`: 디스크에서 .md 파일을 읽어 문자열로 반환
 //! - `write_markdown()`: 문자열을 .md 파일로 디스크에 저장
-//! - `count_words()`: 텍스트의 단어 수 계산
-//! - `count_chars()`: 텍스트의 문자 수 계산
+//! - `count_words()`: 마크다운 텍스트의 단어 수 계산 (글자 수 등 전체 통계는 `text_stats::analyze_text()`)
 //! - `generate_file_path()`: 제목으로부터 파일 경로 생성
 
+use super::text_stats::analyze_text;
 use crate::error::AppError;
 // Path: 파일 경로를 나타내는 불변 참조 타입 (&str과 비슷한 역할)
 // PathBuf: 소유된 파일 경로 타입 (String과 비슷한 역할)
 //   Path : PathBuf = &str : String
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 // tokio::fs: 비동기 파일 시스템 모듈
 // 일반 std::fs는 동기(블로킹)이므로, 비동기 서버에서는 tokio::fs를 사용해야 합니다.
 // 그렇지 않으면 파일 I/O 중에 다른 요청을 처리할 수 없습니다.
@@ -68,54 +68,62 @@ pub async fn write_markdown(
     Ok(())
 }
 
-/// 텍스트의 단어 수를 계산합니다.
+/// 마크다운 텍스트의 단어 수를 계산합니다.
 ///
-/// 공백(스페이스, 탭, 줄바꿈)으로 분리하여 단어를 셉니다.
+/// 마크다운 기호, 링크 URL, 코드 블록, front matter는 제외하고 본문만 셉니다.
+/// 한자/가나는 글자 하나를 한 단어로 셉니다. 자세한 규칙은 `text_stats` 모듈을 참고하세요.
 ///
 /// # 매개변수
-/// - `text`: 단어 수를 셀 텍스트 (&str: 문자열의 불변 참조)
+/// - `text`: 단어 수를 셀 마크다운 텍스트 (&str: 문자열의 불변 참조)
 ///
 /// # 반환값
 /// - `usize`: 단어 수 (부호 없는 정수, 양수만 가능)
 pub fn count_words(text: &str) -> usize {
-    // .split_whitespace(): 공백으로 문자열을 분리하여 이터레이터를 반환
-    // .count(): 이터레이터의 항목 수를 셉니다
     // 이 함수는 async가 아닙니다 — CPU 작업만 하므로 비동기가 필요 없습니다.
-    text.split_whitespace().count()
-}
-
-/// 텍스트의 문자 수를 계산합니다.
-///
-/// 유니코드 문자 단위로 셉니다 (한글 1자 = 1문자).
-/// `.len()`은 바이트 수를 반환하므로 한글 등에는 부적합합니다.
-pub fn count_chars(text: &str) -> usize {
-    // .chars(): 유니코드 문자(char) 단위 이터레이터
-    // .count(): 문자 수를 셉니다
-    // 참고: text.len()은 바이트 수를 반환합니다 (한글 1자 = 3바이트)
-    text.chars().count()
+    analyze_text(text).words
 }
 
 /// 문서 제목과 폴더 정보로 파일 저장 경로를 생성합니다.
 ///
+/// 문서를 만들 때 한 번만 부르며, 결과는 `documents.file_path`에 저장됩니다.
+/// 이 규칙이 바뀌어도 기존 문서의 경로는 그대로입니다 (예전 문서는 ID 앞 8자를 씀).
+///
 /// # 매개변수
 /// - `title`: 문서 제목
 /// - `folder_slug`: 폴더의 slug (None이면 루트에 저장)
+/// - `id`: 문서 ID (끝 8자가 파일명에 들어감)
 ///
 /// # 반환값
-/// 파일 경로 문자열 (예: "my-folder/my-title.md" 또는 "my-title.md")
+/// 파일 경로 문자열 (예: "my-folder/my-title-4c3f9a21.md" 또는 "my-title-4c3f9a21.md")
 ///
 /// # 예시
 /// ```
-/// generate_file_path("나의 첫 글", Some("일기")) → "일기/나의-첫-글.md"
-/// generate_file_path("나의 첫 글", None) → "나의-첫-글.md"
+/// generate_file_path("나의 첫 글", Some("ilgi"), id) → "ilgi/nayi-ceos-geul-4c3f9a21.md"
+/// generate_file_path("나의 첫 글", None, id) → "nayi-ceos-geul-4c3f9a21.md"
 /// ```
 pub fn generate_file_path(title: &str, folder_slug: Option<&str>, id: &str) -> String {
     let slug = slug::slugify(title);
-    // UUID 앞 8자를 파일명에 포함시켜 같은 제목의 문서도 고유한 경로를 갖게 합니다.
-    let short_id = &id[..8];
+    // UUID 끝 8자를 파일명에 포함시켜 같은 제목의 문서도 고유한 경로를 갖게 합니다.
+    // UUIDv7의 앞부분은 생성 시각(밀리초)이라 1분 안에 만든 문서끼리 겹치므로, 무작위인 끝부분을 씁니다.
+    let short_id = &id[id.len().saturating_sub(8)..];
     if let Some(folder) = folder_slug {
         format!("{}/{}-{}.md", folder, slug, short_id)
     } else {
         format!("{}-{}.md", slug, short_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_path_uses_the_random_end_of_the_id() {
+        // 같은 밀리초에 만든 UUIDv7은 앞 8자(시각)가 같고 끝부분만 다릅니다.
+        let first = "019a2b3c-4d5e-7f60-8123-4c3f9a21b7e0";
+        let second = "019a2b3c-4d5e-7f60-9abc-0d1e2f3a4b5c";
+        assert_eq!(generate_file_path("나의 첫 글", None, first), "nayi-ceos-geul-9a21b7e0.md");
+        assert_eq!(generate_file_path("나의 첫 글", Some("ilgi"), second), "ilgi/nayi-ceos-geul-2f3a4b5c.md");
+        assert_ne!(generate_file_path("메모", None, first), generate_file_path("메모", None, second));
+    }
+}
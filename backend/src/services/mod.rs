@@ -3,9 +3,42 @@
 //! 라우트 핸들러에서 사용하는 비즈니스 로직 함수들을 모아둔 모듈입니다.
 //! 데이터베이스가 아닌 파일 시스템 작업이나 데이터 변환 등을 담당합니다.
 //!
+//! - `front_matter`: YAML front matter를 메타데이터로 읽기, 메타데이터 변경을 front matter에 다시 쓰기
+//! - `graph`: 문서/태그/폴더/링크 관계를 그래프(노드 + 엣지)로 조립
+//! - `hashtags`: 본문의 인라인 해시태그(`#태그`) 찾기 및 태그 합치기 시 다시 쓰기
+//! - `links`: 위키 링크(`[[문서 제목]]`) 추출 및 이름 변경 시 링크 다시 쓰기
+//! - `manuscript`: 200자 원고지 매수 계산 (들여쓰기, 문장부호, 줄바꿈 규칙 적용)
 //! - `markdown`: 마크다운(.md) 파일 읽기/쓰기, 단어/글자 수 계산, 파일 경로 생성
+//! - `outline`: 헤딩(ATX/setext) 개요 추출, 섹션 단위 조회/교체
+//! - `quick_open`: 빠른 열기(Ctrl+P)용 제목/폴더/태그 퍼지 매칭(초성, 오타 허용)과 메모리 인덱스
+//! - `search_query`: 검색어를 trigram FTS5 조건(MATCH 식 + 짧은 단어 LIKE)으로 변환
+//! - `search_snippet`: 검색 결과의 제목 하이라이트, 본문 스니펫, 일치 횟수/위치 계산
+//! - `templates`: 템플릿 문서의 `{{date}}`, `{{title}}` 같은 자리표시자 치환
+//! - `text_stats`: 마크다운을 파싱하여 본문만 세는 단어/어절/글자 수 통계
 
+pub mod front_matter;
+pub mod graph;
+pub mod hashtags;
+pub mod links;
+pub mod manuscript;
 pub mod markdown;
+pub mod outline;
+pub mod quick_open;
+pub mod search_query;
+pub mod search_snippet;
+pub mod templates;
+pub mod text_stats;
 
 // 하위 모듈의 모든 공개 함수를 재공개(re-export)합니다.
+pub use front_matter::*;
+pub use graph::*;
+pub use hashtags::*;
+pub use links::*;
+pub use manuscript::*;
 pub use markdown::*;
+pub use outline::*;
+pub use quick_open::*;
+pub use search_query::*;
+pub use search_snippet::*;
+pub use templates::*;
+pub use text_stats::*;
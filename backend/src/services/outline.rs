@@ -0,0 +1,327 @@
+//! # 마크다운 헤딩 개요(Outline) 서비스
+//!
+//! 마크다운 본문에서 헤딩을 찾아 문서 개요를 만들고,
+//! 헤딩 앵커로 섹션 하나만 꺼내거나 교체하는 함수들을 제공합니다.
+//!
+//! ## 지원하는 헤딩 문법
+//! - ATX: `# 제목`, `## 제목 ##` (레벨 1~6, `#` 뒤에 공백 필요)
+//! - Setext: 문단 아래 `===`(레벨 1) 또는 `---`(레벨 2) 밑줄
+//!
+//! 코드 블록(``` / ~~~) 안의 `#`과 문서 맨 앞 YAML front matter(`---`)는 헤딩으로 보지 않습니다.
+//!
+//! ## 섹션 범위
+//! 섹션은 헤딩 줄부터 같은 레벨 이하의 다음 헤딩 직전까지입니다.
+//! 예: `## 1장` 섹션은 그 아래 `### 1절`을 포함하고, 다음 `## 2장`이나 `# 2부` 직전에서 끝납니다.
+
+use super::markdown::count_words;
+use crate::models::OutlineHeading;
+use std::collections::HashMap;
+
+/// 한 줄의 위치 정보
+struct Line<'a> {
+    /// 줄 내용 (줄바꿈 문자 제외)
+    text: &'a str,
+    /// 줄이 시작하는 바이트 오프셋
+    start: usize,
+    /// 다음 줄이 시작하는 바이트 오프셋 (줄바꿈 문자 포함)
+    end: usize,
+}
+
+fn split_lines(content: &str) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for raw in content.split_inclusive('\n') {
+        let text = raw.trim_end_matches(['\n', '\r']);
+        lines.push(Line {
+            text,
+            start,
+            end: start + raw.len(),
+        });
+        start += raw.len();
+    }
+    lines
+}
+
+/// 줄 앞의 들여쓰기 칸 수 (탭은 4칸으로 계산)
+fn indent_width(line: &str) -> usize {
+    let mut width = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => width += 1,
+            '\t' => width += 4,
+            _ => break,
+        }
+    }
+    width
+}
+
+/// 코드 펜스 여는 줄이면 (펜스 문자, 길이)를 반환합니다.
+fn fence_marker(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start();
+    let c = trimmed.chars().next()?;
+    if c != '`' && c != '~' {
+        return None;
+    }
+    let len = trimmed.chars().take_while(|&ch| ch == c).count();
+    (len >= 3).then_some((c, len))
+}
+
+/// ATX 헤딩이면 (레벨, 텍스트)를 반환합니다.
+fn parse_atx(line: &str) -> Option<(u8, String)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    // 닫는 `#`들은 앞에 공백이 있을 때만 제거합니다 (`# C#`의 `#`은 텍스트)
+    let mut text = rest.trim();
+    let without_closing = text.trim_end_matches('#');
+    if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        text = without_closing.trim_end();
+    }
+    Some((level as u8, text.to_string()))
+}
+
+/// Setext 밑줄(`===` 또는 `---`)이면 레벨을 반환합니다.
+fn parse_setext_underline(line: &str) -> Option<u8> {
+    let trimmed = line.trim();
+    let c = trimmed.chars().next()?;
+    if (c == '=' || c == '-') && trimmed.chars().all(|ch| ch == c) {
+        Some(if c == '=' { 1 } else { 2 })
+    } else {
+        None
+    }
+}
+
+/// 목록/인용처럼 setext 헤딩이 될 수 없는 블록의 시작 줄인지 확인합니다.
+fn starts_non_paragraph_block(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with(['>', '-', '*', '+', '|']) {
+        return true;
+    }
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    digits > 0 && trimmed[digits..].starts_with(['.', ')'])
+}
+
+/// 문서 맨 앞의 YAML front matter가 끝나는 줄 인덱스(다음 줄)를 반환합니다.
+fn front_matter_end(lines: &[Line]) -> usize {
+    if lines.first().map(|l| l.text.trim_end()) != Some("---") {
+        return 0;
+    }
+    lines
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, l)| matches!(l.text.trim_end(), "---" | "..."))
+        .map(|(i, _)| i + 1)
+        .unwrap_or(0)
+}
+
+/// 헤딩 텍스트로 GitHub 방식의 앵커를 만듭니다.
+///
+/// 소문자로 바꾸고, 공백은 `-`로, 글자/숫자/`-`/`_` 이외의 문자는 제거합니다.
+/// 한글 등 유니코드 글자는 그대로 유지됩니다.
+/// 예: "1장: 시작 (초고)" → "1장-시작-초고"
+pub fn heading_anchor(text: &str) -> String {
+    let mut anchor = String::with_capacity(text.len());
+    for c in text.trim().chars() {
+        if c.is_alphanumeric() || c == '_' || c == '-' {
+            anchor.extend(c.to_lowercase());
+        } else if c.is_whitespace() {
+            anchor.push('-');
+        }
+    }
+    anchor
+}
+
+/// 마크다운 본문의 헤딩 목록(개요)을 만듭니다.
+///
+/// 각 헤딩에는 줄 번호, 바이트 오프셋, 섹션 범위, 단어 수가 포함됩니다.
+pub fn parse_outline(content: &str) -> Vec<OutlineHeading> {
+    let lines = split_lines(content);
+    let mut headings: Vec<OutlineHeading> = Vec::new();
+
+    let mut fence: Option<(char, usize)> = None;
+    // 현재 문단이 시작된 줄 인덱스 (setext 헤딩 텍스트를 만들 때 사용)
+    let mut paragraph: Option<usize> = None;
+
+    let mut push = |level: u8, text: String, first: usize, last: usize| {
+        headings.push(OutlineHeading {
+            level,
+            text,
+            anchor: String::new(),
+            line: first + 1,
+            end_line: last + 1,
+            byte_start: lines[first].start,
+            body_start: lines[last].end,
+            section_end: content.len(),
+            word_count: 0,
+            total_word_count: 0,
+        });
+    };
+
+    for (i, line) in lines.iter().enumerate().skip(front_matter_end(&lines)) {
+        if let Some((marker, len)) = fence {
+            if let Some((c, l)) = fence_marker(line.text) {
+                if c == marker && l >= len && line.text.trim()[l..].trim().is_empty() {
+                    fence = None;
+                }
+            }
+            continue;
+        }
+
+        if line.text.trim().is_empty() {
+            paragraph = None;
+            continue;
+        }
+
+        let indent = indent_width(line.text);
+        if indent <= 3 {
+            if let Some(marker) = fence_marker(line.text) {
+                fence = Some(marker);
+                paragraph = None;
+                continue;
+            }
+            if let Some((level, text)) = parse_atx(line.text) {
+                push(level, text, i, i);
+                paragraph = None;
+                continue;
+            }
+            if let (Some(start), Some(level)) = (paragraph, parse_setext_underline(line.text)) {
+                let text = lines[start..i]
+                    .iter()
+                    .map(|l| l.text.trim())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                push(level, text, start, i);
+                paragraph = None;
+                continue;
+            }
+        }
+
+        if paragraph.is_none() && indent <= 3 && !starts_non_paragraph_block(line.text) {
+            paragraph = Some(i);
+        }
+    }
+
+    // 섹션 끝, 단어 수, 중복 없는 앵커를 채웁니다.
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for i in 0..headings.len() {
+        let level = headings[i].level;
+        let next_any = headings.get(i + 1).map(|h| h.byte_start).unwrap_or(content.len());
+        let section_end = headings[i + 1..]
+            .iter()
+            .find(|h| h.level <= level)
+            .map(|h| h.byte_start)
+            .unwrap_or(content.len());
+
+        let heading = &mut headings[i];
+        heading.section_end = section_end;
+        heading.word_count = count_words(&content[heading.body_start..next_any]);
+        heading.total_word_count = count_words(&content[heading.body_start..section_end]);
+
+        let base = heading_anchor(&heading.text);
+        let n = seen.entry(base.clone()).or_insert(0);
+        heading.anchor = if *n == 0 { base } else { format!("{}-{}", base, n) };
+        *n += 1;
+    }
+
+    headings
+}
+
+/// 앵커로 섹션 헤딩을 찾습니다.
+pub fn find_section(content: &str, anchor: &str) -> Option<OutlineHeading> {
+    parse_outline(content).into_iter().find(|h| h.anchor == anchor)
+}
+
+/// 섹션(헤딩 줄 ~ 섹션 끝)을 새 내용으로 교체한 전체 본문을 반환합니다.
+///
+/// 뒤에 이어지는 내용이 있으면 새 섹션이 줄바꿈으로 끝나도록 보정합니다.
+pub fn replace_section(content: &str, heading: &OutlineHeading, new_section: &str) -> String {
+    let rest = &content[heading.section_end..];
+    let mut out = String::with_capacity(content.len() + new_section.len());
+    out.push_str(&content[..heading.byte_start]);
+    out.push_str(new_section);
+    if !rest.is_empty() && !new_section.is_empty() && !new_section.ends_with('\n') {
+        out.push('\n');
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (레벨, 텍스트) 목록
+    fn headings(content: &str) -> Vec<(u8, String)> {
+        parse_outline(content).into_iter().map(|h| (h.level, h.text)).collect()
+    }
+
+    fn h(level: u8, text: &str) -> (u8, String) {
+        (level, text.to_string())
+    }
+
+    #[test]
+    fn parses_atx_headings() {
+        let content = "# 1부\n## 1장 ##\n### C#\n#해시태그\n####### 일곱\n   #### 들여쓴 헤딩\n    # 코드 들여쓰기\n";
+        assert_eq!(headings(content), [h(1, "1부"), h(2, "1장"), h(3, "C#"), h(4, "들여쓴 헤딩")]);
+    }
+
+    #[test]
+    fn parses_setext_headings() {
+        let content = "1부\n===\n\n첫 줄\n둘째 줄\n---\n\n- 목록\n---\n\n> 인용\n===\n";
+        assert_eq!(headings(content), [h(1, "1부"), h(2, "첫 줄 둘째 줄")]);
+
+        let outline = parse_outline(content);
+        assert_eq!((outline[1].line, outline[1].end_line), (4, 6));
+        assert_eq!(outline[1].byte_start, content.find("첫 줄").unwrap());
+    }
+
+    #[test]
+    fn ignores_headings_in_code_fences() {
+        let content = "# 앞\n```sh\n# 주석\n```\n~~~~\n# 물결\n~~~\n# 아직 코드\n~~~~\n# 뒤\n";
+        assert_eq!(headings(content), [h(1, "앞"), h(1, "뒤")]);
+        // 닫히지 않은 코드 블록은 문서 끝까지 이어집니다.
+        assert_eq!(headings("# 앞\n```\n# 코드\n"), [h(1, "앞")]);
+    }
+
+    #[test]
+    fn skips_front_matter() {
+        assert_eq!(headings("---\ntitle: 초안\n---\n# 1장\n"), [h(1, "1장")]);
+        // 닫는 `---`가 없으면 front matter가 아니지만, 첫 `---`는 헤딩 밑줄이 아닙니다.
+        assert_eq!(headings("---\ntitle: 초안\n\n# 1장\n"), [h(1, "1장")]);
+    }
+
+    #[test]
+    fn anchors_are_unique() {
+        assert_eq!(heading_anchor("1장: 시작 (초고)"), "1장-시작-초고");
+        let outline = parse_outline("# 장면\n## 장면\n# Scene A\n");
+        let anchors: Vec<&str> = outline.iter().map(|h| h.anchor.as_str()).collect();
+        assert_eq!(anchors, ["장면", "장면-1", "scene-a"]);
+    }
+
+    #[test]
+    fn sections_include_deeper_headings() {
+        let content = "# 1부\n서문\n## 1장\n하나 둘\n### 1절\n셋\n## 2장\n넷\n# 2부\n";
+        let outline = parse_outline(content);
+        let chapter = &outline[1];
+        assert_eq!(&content[chapter.byte_start..chapter.section_end], "## 1장\n하나 둘\n### 1절\n셋\n");
+        // 하위 섹션까지 센 단어 수에는 하위 헤딩 텍스트(`1절`)도 들어갑니다.
+        assert_eq!((chapter.word_count, chapter.total_word_count), (2, 4));
+        assert_eq!(outline[0].section_end, content.find("# 2부").unwrap());
+    }
+
+    #[test]
+    fn replace_section_keeps_following_content() {
+        let content = "# 1장\n옛 내용\n# 2장\n그대로\n";
+        let section = find_section(content, "1장").unwrap();
+        assert_eq!(replace_section(content, &section, "# 1장\n새 내용"), "# 1장\n새 내용\n# 2장\n그대로\n");
+        assert_eq!(replace_section(content, &section, ""), "# 2장\n그대로\n");
+        assert!(find_section(content, "3장").is_none());
+    }
+}
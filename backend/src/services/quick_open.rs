@@ -0,0 +1,414 @@
+//! # 빠른 열기(Quick Open) 퍼지 매칭 서비스
+//!
+//! Ctrl+P 문서 전환기를 위한 가벼운 제목 검색입니다.
+//! 전문검색(FTS5)은 본문까지 뒤지므로 키를 누를 때마다 호출하기에는 무겁습니다.
+//! 대신 사용자별로 문서 제목/폴더 경로/태그만 메모리에 올려 두고 퍼지 매칭합니다.
+//!
+//! ## 메모리 인덱스 (`QuickOpenIndex`)
+//! - 사용자별 항목 목록을 처음 검색할 때 DB에서 읽어 캐시합니다.
+//! - 문서/폴더/태그가 바뀌면 라우트 핸들러가 `invalidate()`를 호출하여 그 사용자의 캐시를 버리고,
+//!   다음 검색 때 다시 읽습니다.
+//! - 세대(generation) 번호로, 다시 읽는 도중에 변경이 있었으면 옛 목록을 캐시하지 않습니다.
+//!
+//! ## 매칭 규칙 (검색어를 공백으로 나눈 단어마다, 모든 단어가 일치해야 함)
+//! | 종류 | 예 | 점수 |
+//! |------|----|------|
+//! | 연속 일치 | "소설" → "단편소설 모음" | 높음 (맨 앞이면 더 높음, 단어 시작이면 가산) |
+//! | 초성 일치 | "ㅅㅅ" → "소설", "ㄷㅍㅅ" → "단편소설" | 연속 일치보다 조금 낮음 |
+//! | 입력 중인 글자 | "소서" → "소설" (마지막 글자의 받침 입력 전) | 연속 일치와 같음 |
+//! | 순서대로 흩어진 글자 | "ntbk" → "notebook" | 중간 |
+//! | 오타 (3글자 이상) | "noteboko" → "notebook" | 낮음 |
+//!
+//! 제목 일치가 가장 중요하고, 태그와 폴더 경로 일치는 낮은 가중치로 더합니다.
+//! 마지막으로 최근에 수정한 문서일수록 가산점을 줍니다.
+
+use crate::models::{QuickOpenEntry, QuickOpenHit};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// 필드별 가중치: 제목이 가장 중요합니다.
+const TITLE_WEIGHT: f64 = 1.0;
+const TAG_WEIGHT: f64 = 0.6;
+const FOLDER_WEIGHT: f64 = 0.5;
+/// 최근 수정 가산점의 최댓값 (오늘 수정한 문서)
+const RECENCY_BONUS: f64 = 20.0;
+/// 아카이브된 문서는 점수를 깎아 아래로 보냅니다.
+const ARCHIVED_PENALTY: f64 = 30.0;
+
+// ──────────────────────────────────────────────
+// 한글 초성 처리
+// ──────────────────────────────────────────────
+
+/// 한글 음절(가~힣)의 초성 순서대로 나열한 호환용 자모
+const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+const HANGUL_FIRST: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+/// 초성 하나당 음절 수 (중성 21 × 종성 28)
+const SYLLABLES_PER_CHOSEONG: u32 = 588;
+/// 종성 경우의 수 (받침 없음 포함)
+const JONGSEONG_COUNT: u32 = 28;
+
+/// 한글 음절이면 음절 인덱스(0부터)를 반환합니다.
+fn syllable_index(c: char) -> Option<u32> {
+    let code = c as u32;
+    (HANGUL_FIRST..=HANGUL_LAST)
+        .contains(&code)
+        .then(|| code - HANGUL_FIRST)
+}
+
+/// 한글 음절의 초성을 호환용 자모로 반환합니다. (예: '소' → 'ㅅ')
+fn choseong(c: char) -> Option<char> {
+    syllable_index(c).map(|i| CHOSEONG[(i / SYLLABLES_PER_CHOSEONG) as usize])
+}
+
+/// 검색어 글자 `q`가 대상 글자 `t`와 일치하는지 확인합니다.
+///
+/// - 같은 글자
+/// - `q`가 초성(ㄱ~ㅎ)이고 `t`의 초성과 같음
+/// - `last`(검색어의 마지막 글자)일 때, `q`가 받침 없는 음절이고 `t`가 같은 초성+중성인 음절
+///   (한글 입력기에서 "설"을 치는 도중에는 "서"까지만 입력되어 있기 때문)
+///
+/// 반환값: 일치 여부와, 글자가 그대로 같은지(정확 일치) 여부
+fn char_match(q: char, t: char, last: bool) -> Option<bool> {
+    if q == t {
+        return Some(true);
+    }
+    if CHOSEONG.contains(&q) && choseong(t) == Some(q) {
+        return Some(false);
+    }
+    if last {
+        if let (Some(qi), Some(ti)) = (syllable_index(q), syllable_index(t)) {
+            if qi % JONGSEONG_COUNT == 0 && ti - ti % JONGSEONG_COUNT == qi {
+                return Some(true);
+            }
+        }
+    }
+    None
+}
+
+// ──────────────────────────────────────────────
+// 퍼지 매칭
+// ──────────────────────────────────────────────
+
+/// 비교용으로 소문자로 바꾼 글자 목록을 만듭니다.
+fn fold(text: &str) -> Vec<char> {
+    text.chars().flat_map(char::to_lowercase).collect()
+}
+
+/// 단어 구분 문자인지 확인합니다.
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '-' | '_' | '/' | '.' | ',' | '(' | ')' | '[' | ']' | '·')
+}
+
+/// `text[pos]`가 단어의 시작인지 확인합니다.
+fn is_word_start(text: &[char], pos: usize) -> bool {
+    pos == 0 || is_separator(text[pos - 1])
+}
+
+/// 검색어가 `text` 안에 연속으로 나타나는지 찾아 점수를 매깁니다.
+fn contiguous_score(query: &[char], text: &[char]) -> Option<f64> {
+    if query.len() > text.len() {
+        return None;
+    }
+    let mut best: Option<f64> = None;
+    for start in 0..=text.len() - query.len() {
+        let mut exact = true;
+        let matched = query.iter().enumerate().all(|(i, &q)| {
+            match char_match(q, text[start + i], i + 1 == query.len()) {
+                Some(is_exact) => {
+                    exact &= is_exact;
+                    true
+                }
+                None => false,
+            }
+        });
+        if !matched {
+            continue;
+        }
+        let mut score = 100.0;
+        if start == 0 {
+            score += if query.len() == text.len() { 50.0 } else { 30.0 };
+        } else if is_word_start(text, start) {
+            score += 15.0;
+        }
+        if !exact {
+            // 초성 검색은 후보가 많아지므로 정확한 글자 일치보다 조금 낮게 봅니다.
+            score -= 10.0;
+        }
+        best = Some(best.map_or(score, |b: f64| b.max(score)));
+    }
+    best
+}
+
+/// 검색어 글자가 `text`에 순서대로(떨어져 있어도) 나타나는지 찾아 점수를 매깁니다.
+///
+/// 일치한 글자들이 가까이 모여 있을수록, 단어 시작에서 일치할수록 점수가 높습니다.
+fn subsequence_score(query: &[char], text: &[char]) -> Option<f64> {
+    let mut positions = Vec::with_capacity(query.len());
+    let mut from = 0;
+    for (i, &q) in query.iter().enumerate() {
+        let last = i + 1 == query.len();
+        let pos = (from..text.len()).find(|&p| char_match(q, text[p], last).is_some())?;
+        positions.push(pos);
+        from = pos + 1;
+    }
+    let span = (positions[positions.len() - 1] - positions[0] + 1) as f64;
+    let word_starts = positions.iter().filter(|&&p| is_word_start(text, p)).count() as f64;
+    Some(30.0 + 30.0 * query.len() as f64 / span + 5.0 * word_starts)
+}
+
+/// 두 글자 목록의 편집 거리(인접 글자 바꿈 포함, Damerau–Levenshtein)를 계산합니다.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let mut d = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[n][m]
+}
+
+/// 오타를 허용하여 `text`의 단어 중 검색어와 비슷한 것이 있는지 찾아 점수를 매깁니다.
+///
+/// 3글자 이상 검색어만 검사하며, 5글자까지는 1개, 그보다 길면 2개까지 틀려도 됩니다.
+/// 입력 중인 단어도 찾을 수 있도록 단어의 앞부분(검색어 길이만큼)과도 비교합니다.
+fn typo_score(query: &[char], text: &[char]) -> Option<f64> {
+    if query.len() < 3 {
+        return None;
+    }
+    let allowed = if query.len() <= 5 { 1 } else { 2 };
+    text.split(|&c| is_separator(c))
+        .filter(|word| !word.is_empty())
+        .filter_map(|word| {
+            let whole = edit_distance(query, word);
+            let prefix = if word.len() > query.len() {
+                edit_distance(query, &word[..query.len()])
+            } else {
+                whole
+            };
+            let distance = whole.min(prefix);
+            (distance <= allowed).then_some(30.0 - 10.0 * distance as f64)
+        })
+        .reduce(f64::max)
+}
+
+/// 검색어 단어 하나를 필드 텍스트 하나와 비교한 점수. 일치하지 않으면 None.
+fn field_score(query: &[char], text: &[char]) -> Option<f64> {
+    contiguous_score(query, text)
+        .or_else(|| subsequence_score(query, text))
+        .or_else(|| typo_score(query, text))
+}
+
+/// 최근 수정 가산점: 오늘 수정했으면 `RECENCY_BONUS`, 1주 전이면 절반, 오래될수록 0에 가까워집니다.
+fn recency_bonus(updated_at: &str, now: chrono::DateTime<chrono::Utc>) -> f64 {
+    let Ok(updated) = chrono::DateTime::parse_from_rfc3339(updated_at) else {
+        return 0.0;
+    };
+    let days = (now - updated.with_timezone(&chrono::Utc)).num_hours().max(0) as f64 / 24.0;
+    RECENCY_BONUS / (1.0 + days / 7.0)
+}
+
+/// 검색어로 항목들을 점수순으로 정렬하여 상위 `limit`개를 반환합니다.
+///
+/// 검색어가 비어 있으면 최근 수정순으로 반환합니다 (Ctrl+P를 막 열었을 때).
+pub fn quick_open_search(entries: &[QuickOpenEntry], query: &str, limit: usize) -> Vec<QuickOpenHit> {
+    let now = chrono::Utc::now();
+    let terms: Vec<Vec<char>> = query.split_whitespace().map(fold).collect();
+
+    let mut hits: Vec<QuickOpenHit> = entries
+        .iter()
+        .filter_map(|entry| {
+            let title = fold(&entry.title);
+            let folder = fold(&entry.folder_path);
+            let tags: Vec<Vec<char>> = entry.tags.iter().map(|t| fold(t)).collect();
+
+            let mut score = 0.0;
+            for term in &terms {
+                let candidates = [
+                    field_score(term, &title).map(|s| s * TITLE_WEIGHT),
+                    tags.iter()
+                        .filter_map(|tag| field_score(term, tag))
+                        .reduce(f64::max)
+                        .map(|s| s * TAG_WEIGHT),
+                    field_score(term, &folder).map(|s| s * FOLDER_WEIGHT),
+                ];
+                // 모든 검색어 단어가 어딘가에서 일치해야 합니다.
+                score += candidates.into_iter().flatten().reduce(f64::max)?;
+            }
+
+            score += recency_bonus(&entry.updated_at, now);
+            if entry.is_archived {
+                score -= ARCHIVED_PENALTY;
+            }
+            Some(QuickOpenHit {
+                entry: entry.clone(),
+                score,
+            })
+        })
+        .collect();
+
+    // 점수가 같으면 최근 수정순
+    hits.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| b.entry.updated_at.cmp(&a.entry.updated_at))
+    });
+    hits.truncate(limit);
+    hits
+}
+
+// ──────────────────────────────────────────────
+// 메모리 인덱스
+// ──────────────────────────────────────────────
+
+/// 한 사용자의 캐시 상태
+#[derive(Default)]
+struct UserIndex {
+    /// `invalidate()`될 때마다 1씩 증가
+    generation: u64,
+    /// 캐시된 항목 목록 (None이면 다음 검색 때 DB에서 다시 읽어야 함)
+    entries: Option<Arc<Vec<QuickOpenEntry>>>,
+}
+
+/// 사용자별 빠른 열기 항목을 메모리에 보관하는 인덱스
+///
+/// `AppState`에 담겨 모든 요청이 공유합니다. 내부가 `Arc`이므로 clone해도 같은 인덱스를 가리킵니다.
+/// 잠금은 항목 목록을 꺼내거나 바꿀 때만 잠깐 잡으며, `.await` 동안에는 잡지 않습니다.
+#[derive(Clone, Default)]
+pub struct QuickOpenIndex {
+    users: Arc<RwLock<HashMap<String, UserIndex>>>,
+}
+
+impl QuickOpenIndex {
+    /// 캐시된 항목 목록과 현재 세대 번호를 반환합니다.
+    ///
+    /// 목록이 None이면 DB에서 읽은 뒤 같은 세대 번호로 `store()`를 호출합니다.
+    pub fn get(&self, user_id: &str) -> (u64, Option<Arc<Vec<QuickOpenEntry>>>) {
+        // 다른 스레드가 잠금을 잡은 채 패닉했더라도 캐시 데이터는 여전히 쓸 수 있으므로 into_inner로 복구합니다.
+        let users = self.users.read().unwrap_or_else(|e| e.into_inner());
+        users
+            .get(user_id)
+            .map(|u| (u.generation, u.entries.clone()))
+            .unwrap_or((0, None))
+    }
+
+    /// DB에서 읽은 항목 목록을 캐시합니다.
+    ///
+    /// 읽는 동안 `invalidate()`가 호출되어 세대가 바뀌었으면 옛 목록이므로 캐시하지 않습니다.
+    pub fn store(&self, user_id: &str, generation: u64, entries: Arc<Vec<QuickOpenEntry>>) {
+        let mut users = self.users.write().unwrap_or_else(|e| e.into_inner());
+        let user = users.entry(user_id.to_string()).or_default();
+        if user.generation == generation {
+            user.entries = Some(entries);
+        }
+    }
+
+    /// 사용자의 캐시를 버립니다. 문서/폴더/태그가 바뀔 때마다 호출합니다.
+    pub fn invalidate(&self, user_id: &str) {
+        let mut users = self.users.write().unwrap_or_else(|e| e.into_inner());
+        let user = users.entry(user_id.to_string()).or_default();
+        user.generation += 1;
+        user.entries = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 같은 시각에 수정한 항목 (최근 수정 가산점이 순위에 영향을 주지 않도록)
+    fn entry(title: &str) -> QuickOpenEntry {
+        QuickOpenEntry {
+            id: title.to_string(),
+            title: title.to_string(),
+            folder_id: None,
+            folder_path: String::new(),
+            tags: Vec::new(),
+            is_archived: false,
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn ranked(entries: &[QuickOpenEntry], query: &str) -> Vec<String> {
+        quick_open_search(entries, query, 10).into_iter().map(|hit| hit.entry.id).collect()
+    }
+
+    #[test]
+    fn exact_and_prefix_matches_rank_first() {
+        let entries = [entry("단편소설 모음"), entry("소설 쓰기"), entry("소설"), entry("소 설"), entry("일기")];
+        assert_eq!(ranked(&entries, "소설"), ["소설", "소설 쓰기", "단편소설 모음", "소 설"]);
+    }
+
+    #[test]
+    fn matches_choseong_and_syllables_being_typed() {
+        let entries = [entry("단편소설"), entry("동화")];
+        assert_eq!(ranked(&entries, "ㄷㅍㅅ"), ["단편소설"]);
+        // "설"을 입력하는 도중에는 "서"까지만 입력되어 있습니다 (마지막 글자에만 적용).
+        assert_eq!(ranked(&entries, "단편소서"), ["단편소설"]);
+        assert!(ranked(&[entry("설소")], "서소").is_empty());
+    }
+
+    #[test]
+    fn matches_scattered_letters_and_typos() {
+        let entries = [entry("notebook"), entry("novel")];
+        assert_eq!(ranked(&entries, "ntbk"), ["notebook"]);
+        assert_eq!(ranked(&entries, "NOTEBOKO"), ["notebook"]);
+        // 2글자 이하는 오타를 허용하지 않습니다.
+        assert!(ranked(&entries, "xz").is_empty());
+    }
+
+    #[test]
+    fn every_term_must_match_and_title_outweighs_tags() {
+        let mut tagged = entry("설정 노트");
+        tagged.tags = vec!["인물".to_string()];
+        tagged.folder_path = "장편/초고".to_string();
+        let entries = [tagged, entry("인물 소개")];
+        assert_eq!(ranked(&entries, "인물"), ["인물 소개", "설정 노트"]);
+        assert_eq!(ranked(&entries, "인물 초고"), ["설정 노트"]);
+    }
+
+    #[test]
+    fn archived_and_older_documents_sink() {
+        let mut archived = entry("초고");
+        archived.id = "보관된 초고".to_string();
+        archived.is_archived = true;
+        let mut recent = entry("초고");
+        recent.id = "최근 초고".to_string();
+        recent.updated_at = chrono::Utc::now().to_rfc3339();
+        let entries = [archived, entry("초고"), recent];
+        assert_eq!(ranked(&entries, "초고"), ["최근 초고", "초고", "보관된 초고"]);
+        // 검색어가 없으면 최근 수정순입니다.
+        assert_eq!(quick_open_search(&entries, " ", 1)[0].entry.id, "최근 초고");
+    }
+
+    #[test]
+    fn stale_reload_is_not_cached_after_invalidate() {
+        let index = QuickOpenIndex::default();
+        let (generation, cached) = index.get("user");
+        assert!(cached.is_none());
+
+        index.invalidate("user");
+        index.store("user", generation, Arc::new(vec![entry("옛 목록")]));
+        assert!(index.get("user").1.is_none());
+
+        let (generation, _) = index.get("user");
+        index.store("user", generation, Arc::new(vec![entry("새 목록")]));
+        assert_eq!(index.get("user").1.unwrap()[0].title, "새 목록");
+    }
+}
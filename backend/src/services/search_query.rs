@@ -0,0 +1,390 @@
+//! # 검색어 파서 및 FTS5 조건 변환 서비스
+//!
+//! 사용자가 입력한 검색어를 작은 검색 문법으로 해석한 뒤,
+//! `documents_fts`(trigram 토크나이저)에 맞는 SQL 조건으로 바꿉니다.
+//! 사용자 입력이 FTS5 MATCH 문법으로 직접 들어가지 않으므로,
+//! 따옴표나 `AND`, `-` 같은 문자를 입력해도 SQLite 에러(500)가 나지 않습니다.
+//!
+//! ## 검색 문법
+//! | 입력 | 의미 |
+//! |------|------|
+//! | `소설 주인공` | 두 단어를 모두 포함 (AND) |
+//! | `"첫 번째 장"` | 공백을 포함한 구절을 그대로 포함 |
+//! | `-초고` | 이 단어를 포함하지 않음 (`-"구절"`도 가능) |
+//! | `소설*` | 공백이나 줄 시작 바로 뒤에서 시작하는 단어 (접두사) |
+//! | `title:소설` | 제목에만 포함 (`title:"구절"`, `-title:단어`도 가능) |
+//!
+//! 이 외의 문자(`AND`, `OR`, `(`, 단어 중간의 `"` 등)는 모두 일반 글자로 검색합니다.
+//! 닫히지 않은 따옴표, 빈 구절(`""`), 단독 `-`/`*`, 단어 없는 `title:`은 400 에러입니다.
+//!
+//! ## trigram 토크나이저의 제약
+//! trigram은 텍스트를 3글자 단위로 색인하므로, 3글자 이상인 검색어는 `MATCH`로
+//! 부분 문자열 검색이 됩니다 ("소설에" → "소설에서"와 일치).
+//! 하지만 2글자 이하 검색어는 trigram을 만들 수 없어 `MATCH`로는 아무것도 찾지 못합니다.
+//! 한국어는 "소설", "문학"처럼 두 글자 명사가 많으므로, 짧은 검색어는 `LIKE '%소설%'`로 검색합니다.
+//! 접두사 검색도 "단어의 시작"을 trigram으로 표현할 수 없으므로 LIKE로 검사합니다.
+
+/// trigram으로 MATCH 검색이 가능한 최소 글자 수
+pub const TRIGRAM_MIN_CHARS: usize = 3;
+
+/// 검색어가 적용되는 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryField {
+    /// 제목 또는 본문
+    Any,
+    /// 제목만 (`title:`)
+    Title,
+}
+
+/// 파싱된 검색어 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryTerm {
+    /// 검색할 텍스트 (따옴표, `-`, `*`, `title:` 제외)
+    pub text: String,
+    pub field: QueryField,
+    /// `-`로 제외하는 검색어인지
+    pub negated: bool,
+    /// `*`로 끝나는 접두사 검색인지
+    pub prefix: bool,
+}
+
+/// LIKE로 검사하는 조건 하나
+///
+/// `field`의 컬럼 중 어느 것이든 `patterns` 중 하나와 일치하면 참이고,
+/// `negated`면 전체 결과를 뒤집습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LikeCondition {
+    pub field: QueryField,
+    /// `LIKE ? ESCAPE '\'`에 바인딩할 패턴들
+    pub patterns: Vec<String>,
+    pub negated: bool,
+}
+
+/// 검색어를 변환한 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FtsQuery {
+    /// `documents_fts MATCH ?`에 바인딩할 식 (3글자 이상 포함 검색어가 없으면 None)
+    pub match_expr: Option<String>,
+    /// 이 식과 일치하는 문서는 제외 (3글자 이상 제외 검색어를 OR로 묶은 식)
+    pub exclude_expr: Option<String>,
+    /// MATCH로 표현할 수 없는 조건들 (짧은 검색어, 접두사)
+    pub like_conditions: Vec<LikeCondition>,
+    /// 검색 결과에서 강조 표시할 단어 (제외 검색어는 포함하지 않음)
+    pub terms: Vec<String>,
+}
+
+impl FtsQuery {
+    /// 결과를 좁히는 포함 조건이 하나도 없는지 확인합니다.
+    ///
+    /// 제외 검색어만 있으면 "거의 모든 문서"가 결과가 되므로 비어 있는 것으로 봅니다.
+    pub fn is_empty(&self) -> bool {
+        self.match_expr.is_none() && self.like_conditions.iter().all(|c| c.negated)
+    }
+}
+
+/// 단어를 FTS5 구문(phrase) 문자열로 감쌉니다. 내부의 `"`는 `""`로 이스케이프합니다.
+pub fn fts_phrase(term: &str) -> String {
+    format!("\"{}\"", term.replace('"', "\"\""))
+}
+
+/// LIKE 패턴용으로 특수 문자(`%`, `_`, `\`)를 이스케이프합니다.
+///
+/// SQL에서는 `LIKE ? ESCAPE '\'`와 함께 사용해야 합니다.
+fn escape_like(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+    for c in term.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// 부분 문자열 검색용 LIKE 패턴 (`%단어%`)을 만듭니다.
+pub fn like_pattern(term: &str) -> String {
+    format!("%{}%", escape_like(term))
+}
+
+/// 접두사 검색용 LIKE 패턴들을 만듭니다.
+///
+/// 컬럼 맨 앞, 공백 뒤, 줄바꿈 뒤에서 시작하는 경우를 각각 검사합니다.
+fn prefix_patterns(term: &str) -> Vec<String> {
+    let escaped = escape_like(term);
+    vec![
+        format!("{}%", escaped),
+        format!("% {}%", escaped),
+        format!("%\n{}%", escaped),
+    ]
+}
+
+/// 검색어를 문법에 따라 검색어 목록으로 나눕니다.
+///
+/// # 에러
+/// 문법이 잘못된 경우 사용자에게 보여줄 수 있는 영문 메시지를 반환합니다.
+pub fn parse_search_query(input: &str) -> Result<Vec<QueryTerm>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut terms = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        // ── 제외 표시 `-` ──
+        let negated = chars[i] == '-';
+        if negated {
+            i += 1;
+            if i >= chars.len() || chars[i].is_whitespace() {
+                return Err("Missing term after '-' (use -word to exclude a word)".to_string());
+            }
+        }
+
+        // ── 필드 지정 `title:` (대소문자 무시) ──
+        let mut field = QueryField::Any;
+        let rest: String = chars[i..].iter().take(6).collect();
+        if rest.eq_ignore_ascii_case("title:") {
+            field = QueryField::Title;
+            i += 6;
+            if i >= chars.len() || chars[i].is_whitespace() {
+                return Err("Missing term after 'title:' (use title:word)".to_string());
+            }
+        }
+
+        // ── 구절 `"..."` 또는 단어 ──
+        let (mut text, phrase) = if chars[i] == '"' {
+            let start = i + 1;
+            let Some(len) = chars[start..].iter().position(|&c| c == '"') else {
+                return Err("Unterminated quote in search query (close it with \")".to_string());
+            };
+            i = start + len + 1;
+            (chars[start..start + len].iter().collect::<String>(), true)
+        } else {
+            let start = i;
+            while i < chars.len() && !chars[i].is_whitespace() {
+                i += 1;
+            }
+            (chars[start..i].iter().collect::<String>(), false)
+        };
+
+        // ── 접두사 표시 `*` ──
+        let mut prefix = false;
+        if phrase {
+            while i < chars.len() && chars[i] == '*' {
+                prefix = true;
+                i += 1;
+            }
+        } else if text.ends_with('*') {
+            prefix = true;
+            text = text.trim_end_matches('*').to_string();
+        }
+
+        if phrase && text.trim().is_empty() {
+            return Err("Empty phrase in search query".to_string());
+        }
+        if text.is_empty() {
+            return Err("Missing term before '*' (use word* for prefix search)".to_string());
+        }
+
+        terms.push(QueryTerm {
+            text,
+            field,
+            negated,
+            prefix,
+        });
+    }
+
+    Ok(terms)
+}
+
+/// 검색어 하나를 FTS5 MATCH 식 조각으로 만듭니다. (예: `title : "소설에"`)
+fn match_fragment(term: &QueryTerm) -> String {
+    match term.field {
+        QueryField::Any => fts_phrase(&term.text),
+        QueryField::Title => format!("title : {}", fts_phrase(&term.text)),
+    }
+}
+
+/// 사용자 검색어를 파싱하여 FTS5 조건으로 변환합니다.
+///
+/// # 에러
+/// 검색 문법이 잘못되었으면 사용자에게 보여줄 메시지를 반환합니다.
+pub fn build_fts_query(input: &str) -> Result<FtsQuery, String> {
+    let mut query = FtsQuery::default();
+    let mut includes = Vec::new();
+    let mut excludes = Vec::new();
+
+    for term in parse_search_query(input)? {
+        let matchable = term.text.chars().count() >= TRIGRAM_MIN_CHARS;
+
+        if !term.negated {
+            query.terms.push(term.text.clone());
+        }
+
+        // 3글자 이상이면 MATCH로 검색합니다. 접두사 검색도 인덱스를 활용하기 위해
+        // 포함 조건에는 MATCH를 함께 걸어 후보를 줄인 뒤 LIKE로 단어 시작을 확인합니다.
+        // (제외 조건은 MATCH만으로 제외하면 단어 중간에 나온 문서까지 빠지므로 LIKE만 사용)
+        if matchable && !(term.prefix && term.negated) {
+            if term.negated {
+                excludes.push(match_fragment(&term));
+            } else {
+                includes.push(match_fragment(&term));
+            }
+        }
+        if !matchable || term.prefix {
+            query.like_conditions.push(LikeCondition {
+                field: term.field,
+                patterns: if term.prefix {
+                    prefix_patterns(&term.text)
+                } else {
+                    vec![like_pattern(&term.text)]
+                },
+                negated: term.negated,
+            });
+        }
+    }
+
+    if !includes.is_empty() {
+        query.match_expr = Some(includes.join(" "));
+    }
+    if !excludes.is_empty() {
+        query.exclude_expr = Some(excludes.join(" OR "));
+    }
+    Ok(query)
+}
+
+/// 제목이 없는 대상(버전 본문)을 검색하기 위해 검색어를 변환합니다.
+///
+/// `title:` 검색어는 쓸 수 없으며, 나머지 문법은 `build_fts_query`와 같습니다.
+pub fn build_content_query(input: &str) -> Result<FtsQuery, String> {
+    let terms = parse_search_query(input)?;
+    if terms.iter().any(|term| term.field == QueryField::Title) {
+        return Err("'title:' is not supported when searching version history".to_string());
+    }
+    build_fts_query(input)
+}
+
+/// 날짜 필터 값을 DB의 `created_at`/`updated_at`과 문자열로 비교할 수 있는 형태로 바꿉니다.
+///
+/// - `YYYY-MM-DD` → 그대로 (`"2026-01-15" <= "2026-01-15T09:30:45.123Z"`이므로 그날 0시로 취급)
+/// - RFC 3339 (`2026-01-15T18:00:00+09:00`) → UTC로 바꾼 `YYYY-MM-DDTHH:MM:SS.mmmZ`
+/// - 상대 날짜: `today` (오늘 0시, UTC), `-7d` (7일 전 지금), `-2w` (2주 전 지금)
+///
+/// 상대 날짜는 호출할 때마다 현재 시각 기준으로 계산되므로,
+/// 저장된 검색에 `updated_after: "-7d"`를 넣으면 항상 "최근 7일"이 됩니다.
+///
+/// 형식이 잘못되었으면 None을 반환합니다.
+pub fn normalize_date_bound(value: &str) -> Option<String> {
+    let value = value.trim();
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date.format("%Y-%m-%d").to_string());
+    }
+    if value.eq_ignore_ascii_case("today") {
+        return Some(chrono::Utc::now().format("%Y-%m-%d").to_string());
+    }
+    if let Some(relative) = value.strip_prefix('-') {
+        let unit = relative.chars().last()?;
+        let amount: i64 = relative[..relative.len() - unit.len_utf8()].parse().ok()?;
+        let days = match unit {
+            'd' => amount,
+            'w' => amount.checked_mul(7)?,
+            _ => return None,
+        };
+        let at = chrono::Utc::now().checked_sub_signed(chrono::TimeDelta::try_days(days)?)?;
+        return Some(at.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string());
+    }
+    chrono::DateTime::parse_from_rfc3339(value).ok().map(|dt| {
+        dt.with_timezone(&chrono::Utc)
+            .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+            .to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terms_shorter_than_trigram_use_like() {
+        let query = build_fts_query("소설").unwrap();
+        assert_eq!(query.match_expr, None);
+        assert_eq!(
+            query.like_conditions,
+            [LikeCondition { field: QueryField::Any, patterns: vec!["%소설%".to_string()], negated: false }]
+        );
+        assert_eq!(query.terms, ["소설"]);
+        assert!(!query.is_empty());
+    }
+
+    #[test]
+    fn terms_of_trigram_length_use_match() {
+        assert_eq!("세계관".chars().count(), TRIGRAM_MIN_CHARS);
+        let query = build_fts_query("세계관").unwrap();
+        assert_eq!(query.match_expr.as_deref(), Some("\"세계관\""));
+        assert!(query.like_conditions.is_empty());
+    }
+
+    #[test]
+    fn mixed_lengths_combine_match_and_like() {
+        let query = build_fts_query("민지 세계관 -초고 -title:메모장").unwrap();
+        assert_eq!(query.match_expr.as_deref(), Some("\"세계관\""));
+        assert_eq!(query.exclude_expr.as_deref(), Some("title : \"메모장\""));
+        assert_eq!(query.like_conditions.len(), 2);
+        assert_eq!(query.like_conditions[0].patterns, ["%민지%"]);
+        assert!(query.like_conditions[1].negated);
+        assert_eq!(query.terms, ["민지", "세계관"]);
+    }
+
+    #[test]
+    fn only_negated_terms_is_empty() {
+        assert!(build_fts_query("-초고").unwrap().is_empty());
+        assert!(build_fts_query("-초고본문").unwrap().is_empty());
+    }
+
+    #[test]
+    fn like_special_characters_are_escaped() {
+        assert_eq!(like_pattern("100%_a\\b"), "%100\\%\\_a\\\\b%");
+    }
+
+    #[test]
+    fn prefix_terms_check_word_start() {
+        let query = build_fts_query("소설*").unwrap();
+        assert_eq!(query.match_expr, None);
+        assert_eq!(query.like_conditions[0].patterns, ["소설%", "% 소설%", "%\n소설%"]);
+
+        // 3글자 이상이면 MATCH로 후보를 줄이고 LIKE로 단어 시작을 확인합니다.
+        let query = build_fts_query("세계관*").unwrap();
+        assert_eq!(query.match_expr.as_deref(), Some("\"세계관\""));
+        assert_eq!(query.like_conditions.len(), 1);
+    }
+
+    #[test]
+    fn invalid_syntax_is_rejected() {
+        assert!(parse_search_query("\"닫히지 않은").is_err());
+        assert!(parse_search_query("\"\"").is_err());
+        assert!(parse_search_query("- 소설").is_err());
+        assert!(parse_search_query("*").is_err());
+        assert!(parse_search_query("title:").is_err());
+        assert!(build_content_query("title:소설").is_err());
+    }
+
+    #[test]
+    fn fts_syntax_is_treated_as_text() {
+        let query = build_fts_query("AND OR (괄호)").unwrap();
+        assert_eq!(query.terms, ["AND", "OR", "(괄호)"]);
+        assert_eq!(query.match_expr.as_deref(), Some("\"AND\" \"(괄호)\""));
+        assert_eq!(fts_phrase("a\"b"), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn date_bounds_are_normalized_to_utc() {
+        assert_eq!(normalize_date_bound("2026-01-15").as_deref(), Some("2026-01-15"));
+        assert_eq!(
+            normalize_date_bound("2026-01-15T18:00:00+09:00").as_deref(),
+            Some("2026-01-15T09:00:00.000Z")
+        );
+        assert_eq!(normalize_date_bound("2026-13-01"), None);
+    }
+}
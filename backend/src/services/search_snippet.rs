@@ -0,0 +1,247 @@
+//! # 검색 결과 스니펫/하이라이트 서비스
+//!
+//! 검색 결과마다 "왜 이 문서가 검색되었는지" 보여주기 위해
+//! 일치한 부분을 `<mark>…</mark>`로 감싼 제목과 본문 스니펫을 만들고, 일치 횟수를 셉니다.
+//!
+//! ## FTS5 `snippet()`/`highlight()`를 쓰지 않는 이유
+//! FTS5 보조 함수는 MATCH 식에 들어간 단어만 표시할 수 있는데,
+//! 2글자 이하 단어는 trigram으로 MATCH할 수 없어 LIKE로 검색하므로 표시되지 않습니다.
+//! 또한 FTS5에는 행별 일치 횟수를 돌려주는 내장 함수가 없습니다.
+//! 그래서 `documents_fts`에 저장된 제목/본문을 읽어 같은 형식의 결과를 직접 만듭니다.
+//!
+//! ## 출력 형식
+//! - 원문은 HTML 이스케이프(`&`, `<`, `>`, `"`)되고, 일치 부분만 `<mark>`로 감쌉니다.
+//!   따라서 프론트엔드는 결과를 그대로 `innerHTML`로 렌더링해도 안전합니다.
+//! - 스니펫 앞뒤가 잘렸으면 `…`를 붙입니다. 줄바꿈 등 연속 공백은 공백 하나로 바꿉니다.
+//! - 대소문자를 구분하지 않습니다 (trigram 토크나이저와 동일).
+
+use crate::models::{MatchedIn, SearchHit, SearchRow};
+
+/// 스니펫에서 첫 일치 위치 앞에 보여줄 글자 수
+const SNIPPET_BEFORE: usize = 30;
+/// 스니펫 전체 최대 글자 수
+const SNIPPET_LENGTH: usize = 120;
+
+const MARK_OPEN: &str = "<mark>";
+const MARK_CLOSE: &str = "</mark>";
+
+/// 대소문자 구분 없이 비교하기 위해 글자마다 소문자 하나로 바꿉니다.
+///
+/// `to_lowercase()`가 여러 글자를 돌려주는 경우에도 첫 글자만 사용하여
+/// 원문과 글자 위치가 1:1로 대응되도록 합니다.
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// `text`에서 검색어들이 나타나는 위치를 (시작, 끝) 글자 인덱스로 찾습니다.
+///
+/// 반환값의 첫 번째는 총 일치 횟수, 두 번째는 겹치는 구간을 합친 정렬된 구간 목록입니다.
+fn find_matches(text: &[char], terms: &[Vec<char>]) -> (usize, Vec<(usize, usize)>) {
+    let folded: Vec<char> = text.iter().map(|&c| fold(c)).collect();
+    let mut count = 0;
+    let mut ranges = Vec::new();
+
+    for term in terms {
+        if term.is_empty() || term.len() > folded.len() {
+            continue;
+        }
+        for start in 0..=folded.len() - term.len() {
+            if folded[start..start + term.len()] == term[..] {
+                count += 1;
+                ranges.push((start, start + term.len()));
+            }
+        }
+    }
+
+    // 겹치거나 맞닿은 구간을 합칩니다.
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    (count, merged)
+}
+
+fn push_escaped(out: &mut String, c: char) {
+    match c {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '"' => out.push_str("&quot;"),
+        _ => out.push(c),
+    }
+}
+
+/// `text[from..to]`를 이스케이프하면서 `ranges` 구간을 `<mark>`로 감쌉니다.
+fn render(text: &[char], from: usize, to: usize, ranges: &[(usize, usize)]) -> String {
+    let mut out = String::new();
+    let mut ranges = ranges.iter().peekable();
+    let mut open_until: Option<usize> = None;
+
+    for (i, &c) in text.iter().enumerate().take(to).skip(from) {
+        if open_until == Some(i) {
+            out.push_str(MARK_CLOSE);
+            open_until = None;
+        }
+        while ranges.peek().is_some_and(|r| r.1 <= i) {
+            ranges.next();
+        }
+        if open_until.is_none() {
+            if let Some(&&(start, end)) = ranges.peek() {
+                if start <= i {
+                    out.push_str(MARK_OPEN);
+                    open_until = Some(end);
+                }
+            }
+        }
+        push_escaped(&mut out, c);
+    }
+    if open_until.is_some() {
+        out.push_str(MARK_CLOSE);
+    }
+    out
+}
+
+/// 연속된 공백(줄바꿈 포함)을 공백 하나로 바꾼 글자 목록을 만듭니다.
+fn collapse_whitespace(text: &str) -> Vec<char> {
+    let mut chars = Vec::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        if !chars.is_empty() {
+            chars.push(' ');
+        }
+        chars.extend(word.chars());
+    }
+    chars
+}
+
+/// 검색어를 비교용(소문자) 글자 목록으로 바꿉니다.
+fn fold_terms(terms: &[String]) -> Vec<Vec<char>> {
+    terms.iter().map(|t| t.chars().map(fold).collect()).collect()
+}
+
+/// 본문에서 첫 일치 위치 주변을 잘라 스니펫을 만들고, 본문의 일치 횟수를 함께 반환합니다.
+fn snippet_with_count(text: &str, terms: &[Vec<char>]) -> (Option<String>, usize) {
+    let content = collapse_whitespace(text);
+    let (count, ranges) = find_matches(&content, terms);
+    let snippet = ranges.first().map(|&(first, _)| {
+        let from = first.saturating_sub(SNIPPET_BEFORE);
+        let to = (from + SNIPPET_LENGTH).min(content.len());
+        let mut snippet = String::new();
+        if from > 0 {
+            snippet.push('…');
+        }
+        // 잘린 경계의 공백은 `…` 옆에 남지 않도록 제거합니다.
+        snippet.push_str(render(&content, from, to, &ranges).trim());
+        if to < content.len() {
+            snippet.push('…');
+        }
+        snippet
+    });
+    (snippet, count)
+}
+
+/// 본문 스니펫과 일치 횟수를 만듭니다. 버전 검색처럼 제목이 없는 결과에 사용합니다.
+///
+/// 본문에서 일치하지 않았으면 스니펫은 None입니다.
+pub fn build_content_snippet(text: &str, terms: &[String]) -> (Option<String>, usize) {
+    snippet_with_count(text, &fold_terms(terms))
+}
+
+/// 검색 결과 한 행에 하이라이트된 제목, 스니펫, 일치 정보를 붙입니다.
+///
+/// `terms`는 강조할 검색어 목록입니다 (`FtsQuery::terms`).
+pub fn build_search_hit(row: SearchRow, terms: &[String]) -> SearchHit {
+    let terms = fold_terms(terms);
+
+    let title: Vec<char> = row.fts_title.chars().collect();
+    let (title_count, title_ranges) = find_matches(&title, &terms);
+    let title_highlight = render(&title, 0, title.len(), &title_ranges);
+
+    let (snippet, content_count) = snippet_with_count(&row.fts_content, &terms);
+
+    let matched_in = match (title_count > 0, content_count > 0) {
+        (true, true) => MatchedIn::Both,
+        (true, false) => MatchedIn::Title,
+        // 일치 위치를 찾지 못한 경우(이론상 없음)도 본문 일치로 취급합니다.
+        (false, _) => MatchedIn::Content,
+    };
+
+    SearchHit {
+        document: row.document,
+        title_highlight,
+        snippet,
+        match_count: title_count + content_count,
+        matched_in,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Document;
+
+    fn hit(title: &str, content: &str, terms: &[&str]) -> SearchHit {
+        let document = Document {
+            id: "doc".to_string(),
+            folder_id: None,
+            title: title.to_string(),
+            slug: "doc".to_string(),
+            file_path: "doc.md".to_string(),
+            word_count: 0,
+            char_count: 0,
+            char_count_no_spaces: 0,
+            manuscript_pages: 0,
+            excerpt: None,
+            is_pinned: 0,
+            is_archived: 0,
+            is_template: 0,
+            status: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        let row = SearchRow { document, fts_title: title.to_string(), fts_content: content.to_string() };
+        let terms: Vec<String> = terms.iter().map(|t| t.to_string()).collect();
+        build_search_hit(row, &terms)
+    }
+
+    #[test]
+    fn escapes_html_and_marks_matches() {
+        let hit = hit("민지 이야기", "<b>민지</b> & \"현우\"", &["민지"]);
+        assert_eq!(hit.title_highlight, "<mark>민지</mark> 이야기");
+        assert_eq!(
+            hit.snippet.as_deref(),
+            Some("&lt;b&gt;<mark>민지</mark>&lt;/b&gt; &amp; &quot;현우&quot;")
+        );
+        assert_eq!((hit.match_count, hit.matched_in), (2, MatchedIn::Both));
+    }
+
+    #[test]
+    fn merges_overlapping_matches_case_insensitively() {
+        // 일치 횟수는 검색어마다 세고, 표시는 겹친 구간을 하나로 합칩니다.
+        let hit = hit("제목", "xABCx abc", &["ab", "bc"]);
+        assert_eq!(hit.snippet.as_deref(), Some("x<mark>ABC</mark>x <mark>abc</mark>"));
+        assert_eq!((hit.match_count, hit.matched_in), (4, MatchedIn::Content));
+    }
+
+    #[test]
+    fn long_content_is_cut_around_the_first_match() {
+        let text = format!("{}\n\n민지가 나타났다 {}", "가".repeat(100), "나".repeat(200));
+        let hit = hit("제목", &text, &["민지"]);
+        let snippet = hit.snippet.unwrap();
+        assert_eq!(hit.match_count, 1);
+        assert!(snippet.starts_with(&format!("…{} <mark>민지</mark>가", "가".repeat(SNIPPET_BEFORE - 1))));
+        assert!(snippet.ends_with("나…"));
+        let marks = MARK_OPEN.len() + MARK_CLOSE.len();
+        assert_eq!(snippet.chars().filter(|&c| c != '…').count(), SNIPPET_LENGTH + marks);
+    }
+
+    #[test]
+    fn title_only_match_has_no_snippet() {
+        let hit = hit("민지 이야기", "현우가 돌아왔다", &["민지"]);
+        assert_eq!(hit.snippet, None);
+        assert_eq!(hit.matched_in, MatchedIn::Title);
+    }
+}
@@ -0,0 +1,110 @@
+//! # 문서 템플릿 자리표시자 치환 서비스
+//!
+//! 템플릿 문서로 새 문서를 만들 때 본문의 `{{자리표시자}}`를 실제 값으로 바꿉니다.
+//!
+//! ## 지원하는 자리표시자
+//! | 자리표시자 | 값 | 예 |
+//! |-----------|-----|-----|
+//! | `{{title}}` | 새 문서의 제목 | `김민지` |
+//! | `{{folder}}` | 새 문서가 들어갈 폴더 이름 (루트면 빈 문자열) | `인물` |
+//! | `{{date}}` | 날짜 (`YYYY-MM-DD`) | `2026-03-02` |
+//! | `{{weekday}}` | 요일 | `월요일` |
+//! | `{{time}}` | 만든 시각 (`HH:MM`, UTC) | `09:30` |
+//!
+//! 중괄호 안의 공백은 무시합니다 (`{{ date }}`도 같음).
+//! 모르는 자리표시자는 그대로 남겨서, 본문에 쓴 다른 `{{...}}` 문법을 망가뜨리지 않습니다.
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+
+/// 자리표시자에 넣을 값들
+pub struct TemplateContext<'a> {
+    pub title: &'a str,
+    pub folder: &'a str,
+    /// `{{date}}`/`{{weekday}}`의 날짜. 보통 오늘이지만, 일지는 해당 날짜를 넣습니다.
+    pub date: NaiveDate,
+    pub time: NaiveTime,
+}
+
+/// 요일의 한국어 이름
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "월요일",
+        Weekday::Tue => "화요일",
+        Weekday::Wed => "수요일",
+        Weekday::Thu => "목요일",
+        Weekday::Fri => "금요일",
+        Weekday::Sat => "토요일",
+        Weekday::Sun => "일요일",
+    }
+}
+
+/// 자리표시자 이름에 해당하는 값을 반환합니다. 모르는 이름이면 None입니다.
+fn placeholder_value(name: &str, ctx: &TemplateContext) -> Option<String> {
+    match name {
+        "title" => Some(ctx.title.to_string()),
+        "folder" => Some(ctx.folder.to_string()),
+        "date" => Some(ctx.date.format("%Y-%m-%d").to_string()),
+        "weekday" => Some(weekday_name(ctx.date.weekday()).to_string()),
+        "time" => Some(ctx.time.format("%H:%M").to_string()),
+        _ => None,
+    }
+}
+
+/// 템플릿 본문의 자리표시자를 값으로 바꿉니다.
+pub fn expand_template(template: &str, ctx: &TemplateContext) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            // 닫히지 않은 `{{`는 그대로 둡니다.
+            rest = &rest[start..];
+            break;
+        };
+        match placeholder_value(after[..end].trim(), ctx) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(template: &str, title: &str) -> String {
+        let ctx = TemplateContext {
+            title,
+            folder: "인물",
+            date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            time: NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+        };
+        expand_template(template, &ctx)
+    }
+
+    #[test]
+    fn expands_known_placeholders() {
+        assert_eq!(
+            expand("# {{title}}\n{{folder}} / {{ date }} ({{weekday}}) {{time}}", "김민지"),
+            "# 김민지\n인물 / 2026-03-02 (월요일) 09:30"
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_and_unclosed_placeholders() {
+        assert_eq!(expand("{{author}} {{title}}", "초안"), "{{author}} 초안");
+        assert_eq!(expand("{{title}} 그리고 {{date", "초안"), "초안 그리고 {{date");
+        assert_eq!(expand("{{", "초안"), "{{");
+        assert_eq!(expand("}} {title} {{}}", "초안"), "}} {title} {{}}");
+    }
+
+    #[test]
+    fn values_are_not_expanded_again() {
+        assert_eq!(expand("{{title}}", "{{date}}"), "{{date}}");
+    }
+}
@@ -0,0 +1,248 @@
+//! # 마크다운 인식 텍스트 통계 서비스
+//!
+//! 마크다운 본문을 파싱하여 실제 글(prose)만 골라낸 뒤 단어 수/글자 수를 셉니다.
+//! 단순히 공백으로 나누면 `#`, `**`, 링크 URL, 코드 블록, front matter까지 세어지므로
+//! pulldown-cmark로 문서를 파싱해 화면에 보이는 텍스트만 사용합니다.
+//!
+//! ## 세지 않는 것
+//! - 문서 맨 앞 YAML front matter (`---` ~ `---`)
+//! - 코드 블록(``` / ~~~ / 들여쓰기), HTML 블록/태그
+//! - 링크/이미지 URL, 이미지 대체 텍스트, 마크다운 기호(`#`, `*`, `>`, `-` 등)
+//!
+//! 인라인 코드(`` `코드` ``)와 링크 텍스트는 본문에 보이므로 셉니다.
+//!
+//! ## 단어 세는 규칙
+//! - 한글/영문 등: 공백으로 구분된 덩어리 하나가 한 단어 (한국어의 어절)
+//! - 한자/가나(중국어, 일본어): 띄어쓰기를 하지 않으므로 글자 하나가 한 단어
+//! - 글자나 숫자가 하나도 없는 덩어리(`—`, `...` 등)는 단어로 세지 않음
+//!
+//! ## 글자 세는 규칙
+//! - `chars`: 공백 포함 글자 수 (줄바꿈은 제외)
+//! - `chars_no_spaces`: 공백 제외 글자 수
+//!
+//! 원고지 매수(`manuscript_pages`)는 문단 구조가 필요하므로 `extract_paragraphs()`로 따로 계산합니다.
+
+use super::manuscript_pages;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::Serialize;
+
+/// 본문 텍스트 통계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TextStats {
+    /// 단어 수 (한자/가나는 글자 단위)
+    pub words: usize,
+    /// 어절 수: 공백으로 구분된 덩어리 수 (한자/가나도 덩어리 단위)
+    pub eojeols: usize,
+    /// 공백 포함 글자 수 (줄바꿈 제외)
+    pub chars: usize,
+    /// 공백 제외 글자 수
+    pub chars_no_spaces: usize,
+    /// 200자 원고지 매수 (`manuscript` 모듈 참고)
+    pub manuscript_pages: usize,
+}
+
+/// 띄어쓰기 없이 쓰는 문자(한자, 히라가나, 가타카나)인지 확인합니다.
+///
+/// 이 문자들은 글자 하나를 한 단어로 셉니다. 한글은 띄어쓰기를 하므로 포함하지 않습니다.
+fn is_cjk_ideograph_or_kana(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF     // 히라가나, 가타카나
+        | 0x31F0..=0x31FF   // 가타카나 음성 확장
+        | 0x3400..=0x4DBF   // CJK 통합 한자 확장 A
+        | 0x4E00..=0x9FFF   // CJK 통합 한자
+        | 0xF900..=0xFAFF   // CJK 호환 한자
+        | 0xFF66..=0xFF9F   // 반각 가타카나
+        | 0x20000..=0x2FA1F // CJK 통합 한자 확장 B~F, 호환 보충
+    )
+}
+
+/// 본문 추출 중에 발생하는 사건 (pulldown-cmark 이벤트를 단순화한 것)
+enum ProseEvent<'a> {
+    /// 화면에 보이는 텍스트 조각
+    Text(pulldown_cmark::CowStr<'a>),
+    /// 문단 안의 줄바꿈 (렌더링 시 공백으로 보임)
+    SoftBreak,
+    /// 문단 안의 강제 줄바꿈 (줄 끝 공백 2개 또는 `\`)
+    HardBreak,
+    /// 문단, 헤딩, 목록 항목, 표의 행 등 블록의 끝
+    BlockEnd,
+    /// 표의 칸 끝
+    CellEnd,
+}
+
+/// 마크다운을 파싱하여 본문에 해당하는 사건만 순서대로 넘겨줍니다.
+///
+/// front matter/코드 블록/HTML 블록/이미지 안의 텍스트는 건너뜁니다.
+fn walk_prose<'a>(content: &'a str, mut f: impl FnMut(ProseEvent<'a>)) {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+
+    // 이미지 대체 텍스트 안에 다시 이미지가 올 수 있으므로 깊이로 관리합니다.
+    let mut skip_depth = 0usize;
+
+    for event in Parser::new_ext(content, options) {
+        match event {
+            Event::Start(
+                Tag::MetadataBlock(_) | Tag::CodeBlock(_) | Tag::HtmlBlock | Tag::Image { .. },
+            ) => skip_depth += 1,
+            Event::End(TagEnd::Image) => skip_depth = skip_depth.saturating_sub(1),
+            Event::End(TagEnd::MetadataBlock(_) | TagEnd::CodeBlock | TagEnd::HtmlBlock) => {
+                skip_depth = skip_depth.saturating_sub(1);
+                f(ProseEvent::BlockEnd);
+            }
+            Event::Text(text) | Event::Code(text) if skip_depth == 0 => f(ProseEvent::Text(text)),
+            Event::SoftBreak => f(ProseEvent::SoftBreak),
+            Event::HardBreak => f(ProseEvent::HardBreak),
+            Event::End(TagEnd::TableCell) => f(ProseEvent::CellEnd),
+            // 강조/링크 같은 인라인 요소는 단어 중간에 올 수 있으므로 블록 끝이 아닙니다.
+            Event::End(
+                TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Superscript
+                | TagEnd::Subscript
+                | TagEnd::Link,
+            ) => {}
+            Event::End(_) => f(ProseEvent::BlockEnd),
+            _ => {}
+        }
+    }
+}
+
+/// 마크다운을 파싱하여 화면에 보이는 글만 이어 붙인 문자열을 만듭니다.
+///
+/// 블록(문단, 헤딩, 목록 항목 등) 사이와 줄바꿈 위치에는 `\n`을 넣어
+/// 서로 다른 블록의 단어가 붙지 않도록 합니다.
+pub fn extract_prose(content: &str) -> String {
+    let mut prose = String::with_capacity(content.len());
+    walk_prose(content, |event| match event {
+        ProseEvent::Text(text) => prose.push_str(&text),
+        ProseEvent::SoftBreak | ProseEvent::HardBreak | ProseEvent::BlockEnd => prose.push('\n'),
+        // 표의 칸 사이도 단어가 붙지 않도록 구분합니다.
+        ProseEvent::CellEnd => prose.push(' '),
+    });
+    prose
+}
+
+/// 마크다운을 파싱하여 본문을 문단 단위로 나눕니다.
+///
+/// 문단 안의 일반 줄바꿈은 공백으로, 강제 줄바꿈은 `\n`으로 바뀝니다.
+/// 원고지 매수처럼 문단 구조가 필요한 계산에 사용합니다.
+pub fn extract_paragraphs(content: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    walk_prose(content, |event| match event {
+        ProseEvent::Text(text) => current.push_str(&text),
+        ProseEvent::SoftBreak | ProseEvent::CellEnd => current.push(' '),
+        ProseEvent::HardBreak => current.push('\n'),
+        ProseEvent::BlockEnd => {
+            // 목록/인용처럼 블록이 중첩되면 빈 블록 끝이 연달아 오므로 빈 문단은 버립니다.
+            if !current.trim().is_empty() {
+                paragraphs.push(current.trim().to_string());
+            }
+            current.clear();
+        }
+    });
+    if !current.trim().is_empty() {
+        paragraphs.push(current.trim().to_string());
+    }
+    paragraphs
+}
+
+/// 이미 추출된 글(prose)의 통계를 계산합니다.
+pub fn count_prose(prose: &str) -> TextStats {
+    let mut stats = TextStats::default();
+
+    for token in prose.split_whitespace() {
+        if !token.chars().any(char::is_alphanumeric) {
+            continue;
+        }
+        stats.eojeols += 1;
+
+        // 한자/가나는 한 글자씩, 나머지는 연속된 덩어리를 한 단어로 셉니다.
+        let mut in_run = false;
+        for c in token.chars() {
+            if is_cjk_ideograph_or_kana(c) {
+                stats.words += 1;
+                in_run = false;
+            } else if c.is_alphanumeric() {
+                if !in_run {
+                    stats.words += 1;
+                    in_run = true;
+                }
+            } else if in_run && !matches!(c, '\'' | '’' | '-' | '.' | '_') {
+                // 단어 안에 흔히 쓰이는 기호(don't, e-mail, 3.14)는 덩어리를 끊지 않습니다.
+                in_run = false;
+            }
+        }
+    }
+
+    for c in prose.chars() {
+        if c == '\n' || c == '\r' {
+            continue;
+        }
+        stats.chars += 1;
+        if !c.is_whitespace() {
+            stats.chars_no_spaces += 1;
+        }
+    }
+
+    stats
+}
+
+/// 마크다운 본문의 단어/어절/글자 수를 계산합니다.
+///
+/// # 예시
+/// ```text
+/// "# 제목\n\n**굵은** [링크](https://example.com) 글" → words 4, chars 9
+/// "日本語の文章"                                      → words 6, eojeols 1
+/// ```
+pub fn analyze_text(content: &str) -> TextStats {
+    TextStats {
+        manuscript_pages: manuscript_pages(&extract_paragraphs(content)),
+        ..count_prose(&extract_prose(content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_only_visible_prose() {
+        let stats = analyze_text("# 제목\n\n**굵은** [링크](https://example.com) 글");
+        assert_eq!((stats.words, stats.chars), (4, 9));
+
+        let content = "본문 `인라인 코드`\n\n```rust\nlet x = 1;\n```\n\n<div>\n숨김\n</div>\n\n![대체 텍스트](a.png) 끝\n";
+        let prose = extract_prose(content);
+        assert_eq!(prose.split_whitespace().collect::<Vec<_>>(), ["본문", "인라인", "코드", "끝"]);
+    }
+
+    #[test]
+    fn counts_cjk_per_character_and_skips_punctuation() {
+        let stats = analyze_text("日本語の文章");
+        assert_eq!((stats.words, stats.eojeols), (6, 1));
+        assert_eq!(analyze_text("don't e-mail 3.14 — ...").words, 3);
+    }
+
+    #[test]
+    fn table_cells_do_not_merge_words() {
+        assert_eq!(analyze_text("| 이름 | 역할 |\n|---|---|\n| 민지 | 주인공 |\n").words, 4);
+    }
+
+    #[test]
+    fn front_matter_needs_a_closing_line() {
+        assert_eq!(analyze_text("---\ntitle: 초안\n---\n본문 한 줄\n").words, 3);
+        // 닫는 `---`가 없으면 front matter가 아니므로 YAML 줄도 본문으로 셉니다.
+        assert_eq!(analyze_text("---\ntitle: 초안\n본문 한 줄\n").words, 5);
+    }
+
+    #[test]
+    fn paragraphs_keep_hard_breaks() {
+        let paragraphs = extract_paragraphs("첫 줄\n이어지는 줄\n\n시  \n다음 행\n\n- 목록\n");
+        assert_eq!(paragraphs, ["첫 줄 이어지는 줄", "시\n다음 행", "목록"]);
+    }
+}